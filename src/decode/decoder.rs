@@ -6,6 +6,14 @@ pub(crate) struct Decoder<'a, 'b: 'a> {
     pub parent: Option<&'a Decoder<'b, 'b>>,
     pub(super) bytes: Bytes,
     pub(super) offset: usize,
+    /// Tolerate the errors, which are reported as warnings by the lenient decoding.
+    pub(super) lenient: bool,
+    /// Decode the records with the class NONE or ANY as [`Unknown`], which is set for [UPDATE]
+    /// messages.
+    ///
+    /// [`Unknown`]: crate::rr::Unknown
+    /// [UPDATE]: https://tools.ietf.org/html/rfc2136#section-2.4
+    pub(super) update: bool,
 }
 
 impl<'a, 'b: 'a> Decoder<'b, 'b> {
@@ -15,6 +23,8 @@ impl<'a, 'b: 'a> Decoder<'b, 'b> {
             parent: Some(self),
             bytes,
             offset: 0,
+            lenient: self.lenient,
+            update: self.update,
         };
         Ok(decoder)
     }
@@ -26,6 +36,8 @@ impl<'a, 'b: 'a> Decoder<'a, 'b> {
             parent: None,
             bytes,
             offset: 0,
+            lenient: false,
+            update: false,
         }
     }
 
@@ -35,6 +47,8 @@ impl<'a, 'b: 'a> Decoder<'a, 'b> {
             parent: None,
             bytes: main.bytes.clone(),
             offset: offset as usize,
+            lenient: main.lenient,
+            update: main.update,
         }
     }
 
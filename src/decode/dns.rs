@@ -1,16 +1,28 @@
 use crate::decode::Decoder;
+use crate::decode::Section;
 use crate::{DecodeError, DecodeResult, Dns, Flags, Opcode, RCode, MAXIMUM_DNS_PACKET_SIZE};
-use std::convert::TryFrom;
+#[cfg(feature = "tsig")]
+use bytes::Bytes;
 
 impl<'a, 'b: 'a> Decoder<'a, 'b> {
-    fn flags(&mut self) -> DecodeResult<Flags> {
+    pub(super) fn flags(&mut self) -> DecodeResult<Flags> {
+        self.flags_tolerant(Err)
+    }
+
+    /// Decode the flags and call `tolerate` for every error, which does not affect the structure
+    /// of the message. If `tolerate` returns the error then the decoding fails, otherwise an
+    /// unknown opcode and RCode are decoded as [`Opcode::Unknown`] and [`RCode::Unknown`].
+    pub(super) fn flags_tolerant<F>(&mut self, mut tolerate: F) -> DecodeResult<Flags>
+    where
+        F: FnMut(DecodeError) -> DecodeResult<()>,
+    {
         let buffer = self.u8()?;
         let qr = (buffer & 0b1000_0000) != 0;
         let opcode = (buffer & 0b0111_1000) >> 3;
-        let opcode = match Opcode::try_from(opcode) {
-            Ok(opcode) => opcode,
-            Err(buffer) => return Err(DecodeError::Opcode(buffer)),
-        };
+        let opcode = Opcode::from(opcode);
+        if let Opcode::Unknown(buffer) = opcode {
+            tolerate(DecodeError::Opcode(buffer))?;
+        }
         let aa = (buffer & 0b0000_0100) != 0;
         let tc = (buffer & 0b0000_0010) != 0;
         let rd = (buffer & 0b0000_0001) != 0;
@@ -18,30 +30,38 @@ impl<'a, 'b: 'a> Decoder<'a, 'b> {
         let ra = (buffer & 0b1000_0000) != 0;
         let z = buffer & 0b0100_0000;
         if z != 0 {
-            return Err(DecodeError::ZNotZeroes(z));
+            tolerate(DecodeError::ZNotZeroes(z))?;
         }
         let ad = (buffer & 0b0010_0000) != 0;
         let cd = (buffer & 0b0001_0000) != 0;
         let rcode = buffer & 0b0000_1111;
-        match RCode::try_from(rcode) {
-            Ok(rcode) => Ok(Flags {
-                qr,
-                opcode,
-                aa,
-                tc,
-                rd,
-                ra,
-                ad,
-                cd,
-                rcode,
-            }),
-            Err(buffer) => Err(DecodeError::RCode(buffer)),
+        let rcode = RCode::from(rcode);
+        if let RCode::Unknown(buffer) = rcode {
+            tolerate(DecodeError::RCode(buffer))?;
         }
+        Ok(Flags {
+            qr,
+            opcode,
+            aa,
+            tc,
+            rd,
+            ra,
+            ad,
+            cd,
+            rcode,
+        })
     }
 }
 
 impl<'a, 'b: 'a> Decoder<'b, 'b> {
     fn dns(&'a mut self) -> DecodeResult<Dns> {
+        let (dns, _) = self.dns_with_last_additional_offset()?;
+        Ok(dns)
+    }
+
+    /// Decode the DNS message and return the offset of the last record in the additional
+    /// section. If the additional section is empty then the offset of the end is returned.
+    fn dns_with_last_additional_offset(&'a mut self) -> DecodeResult<(Dns, usize)> {
         if self.offset != 0 {
             return Err(DecodeError::Offset(self.offset));
         }
@@ -55,26 +75,44 @@ impl<'a, 'b: 'a> Decoder<'b, 'b> {
 
         let id = self.u16()?;
         let flags = self.flags()?;
+        self.update = flags.opcode == Opcode::Update;
         let question_count = self.u16()?;
         let answer_count = self.u16()?;
         let authority_count = self.u16()?;
         let additional_count = self.u16()?;
 
         let mut questions = Vec::with_capacity(question_count as usize);
-        for _ in 0..question_count {
-            questions.push(self.question()?);
+        for index in 0..question_count {
+            let question = self
+                .question()
+                .map_err(|error| error.with_section(Section::Question, index))?;
+            questions.push(question);
         }
         let mut answers = Vec::with_capacity(answer_count as usize);
-        for _ in 0..answer_count {
-            answers.push(self.rr()?);
+        for index in 0..answer_count {
+            let rr = self
+                .rr()
+                .map_err(|error| error.with_section(Section::Answer, index))?;
+            answers.push(rr);
         }
         let mut authorities = Vec::with_capacity(authority_count as usize);
-        for _ in 0..authority_count {
-            authorities.push(self.rr()?);
+        for index in 0..authority_count {
+            let rr = self
+                .rr()
+                .map_err(|error| error.with_section(Section::Authority, index))?;
+            authorities.push(rr);
         }
         let mut additionals = Vec::with_capacity(additional_count as usize);
-        for _ in 0..additional_count {
-            additionals.push(self.rr()?);
+        let mut last_additional_offset = self.offset;
+        for index in 0..additional_count {
+            last_additional_offset = self.offset;
+            let rr = self
+                .rr()
+                .map_err(|error| error.with_section(Section::Additional, index))?;
+            additionals.push(rr);
+        }
+        if additional_count == 0 {
+            last_additional_offset = self.offset;
         }
 
         let is_finished = self.is_finished()?;
@@ -88,7 +126,7 @@ impl<'a, 'b: 'a> Decoder<'b, 'b> {
         };
 
         if is_finished {
-            Ok(dns)
+            Ok((dns, last_additional_offset))
         } else {
             Err(DecodeError::RemainingBytes(self.offset, dns))
         }
@@ -98,3 +136,11 @@ impl<'a, 'b: 'a> Decoder<'b, 'b> {
 impl_decode!(Flags, flags);
 
 impl_decode!(Dns, dns);
+
+#[cfg(feature = "tsig")]
+impl Dns {
+    pub(crate) fn decode_with_last_additional_offset(bytes: Bytes) -> DecodeResult<(Dns, usize)> {
+        let mut decoder = Decoder::main(bytes);
+        decoder.dns_with_last_additional_offset()
+    }
+}
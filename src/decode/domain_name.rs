@@ -1,18 +1,19 @@
 use crate::{
     decode::Decoder, domain_name::DOMAIN_NAME_MAX_RECURSION, DecodeError, DecodeResult, DomainName,
+    Label,
 };
-use std::{collections::HashSet, str::from_utf8};
+use std::{collections::HashSet, convert::TryFrom};
 
 const COMPRESSION_BITS: u8 = 0b1100_0000;
 const COMPRESSION_BITS_REV: u8 = 0b0011_1111;
 
 #[inline]
-const fn is_compressed(length: u8) -> bool {
+pub(super) const fn is_compressed(length: u8) -> bool {
     (length & COMPRESSION_BITS) == COMPRESSION_BITS
 }
 
 #[inline]
-const fn get_offset(length_1: u8, length_2: u8) -> u16 {
+pub(super) const fn get_offset(length_1: u8, length_2: u8) -> u16 {
     (((length_1 & COMPRESSION_BITS_REV) as u16) << 8) | length_2 as u16
 }
 
@@ -51,8 +52,7 @@ impl<'a, 'b: 'a> Decoder<'a, 'b> {
 
     fn domain_name_label(&mut self, domain_name: &mut DomainName, length: u8) -> DecodeResult<()> {
         let buffer = self.read(length as usize)?;
-        let label = from_utf8(buffer.as_ref())?;
-        let label = label.parse()?;
+        let label = Label::try_from(buffer.as_ref())?;
         domain_name.append_label(label)?;
         Ok(())
     }
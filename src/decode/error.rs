@@ -2,6 +2,7 @@ use crate::rr::edns::{CookieError, ExtendedDNSErrorExtraTextError};
 use crate::rr::{AddressError, Class, ISDNError, PSDNAddressError, TagError, Type};
 use crate::{Dns, DomainName, DomainNameError, LabelError};
 use hex::FromHexError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::str::Utf8Error;
 use thiserror::Error;
 
@@ -83,6 +84,12 @@ pub enum DecodeError {
     SSHFPAlgorithm(u8),
     #[error("Could not decode SSHFPType: {0}")]
     SSHFPType(u8),
+    #[error("Could not decode TLSACertificateUsage: {0}")]
+    TLSACertificateUsage(u8),
+    #[error("Could not decode TLSASelector: {0}")]
+    TLSASelector(u8),
+    #[error("Could not decode TLSAMatchingType: {0}")]
+    TLSAMatchingType(u8),
     #[error("Could not decode AlgorithmType: {0}")]
     AlgorithmType(u8),
     #[error("Could not decode DigestType: {0}")]
@@ -101,8 +108,118 @@ pub enum DecodeError {
     PaddingZero(u8),
     #[error("Padding length is too long for u16: {0}")]
     PaddingLength(usize),
+    #[error("TCP keepalive length is not 0 or 2: {0}")]
+    TcpKeepaliveLength(usize),
+    #[error("Expire length is not 0 or 4: {0}")]
+    ExpireLength(usize),
     #[error("Could not decode Tag: {0}")]
     TagError(#[from] TagError),
     #[error("ECH length mismatch. Expected {0} got {1}")]
     ECHLengthMismatch(usize, usize),
+    #[error("The type bit map length is not between 1 and 32: {0}")]
+    TypeBitMapLength(u8),
+    #[error("The type bit map windows are not in increasing order: {0} {1}")]
+    TypeBitMapWindowOrder(u8, u8),
+    #[error("Class is not ANY for TSIG record: {0}")]
+    TSIGClass(u16),
+    #[error("TTL is not zero for TSIG record: {0}")]
+    TSIGTTL(u32),
+    #[error("The NSEC3 next hashed owner name is empty")]
+    NSEC3NextHashedOwnerName,
+    #[error("{1} ({0})")]
+    Context(DecodeErrorContext, Box<DecodeError>),
+}
+
+impl DecodeError {
+    /// Returns the error without the [`DecodeErrorContext`].
+    pub fn inner(&self) -> &DecodeError {
+        match self {
+            DecodeError::Context(_, error) => error,
+            error => error,
+        }
+    }
+
+    /// Returns the error without the [`DecodeErrorContext`].
+    pub fn into_inner(self) -> DecodeError {
+        match self {
+            DecodeError::Context(_, error) => *error,
+            error => error,
+        }
+    }
+
+    /// Returns the context, if the error occurred in a question or a resource record.
+    pub fn context(&self) -> Option<&DecodeErrorContext> {
+        match self {
+            DecodeError::Context(context, _) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Add the offset to the error, if the error does not have a context yet.
+    pub(super) fn with_offset(self, offset: usize) -> DecodeError {
+        match self {
+            DecodeError::Context(..) => self,
+            error => {
+                let context = DecodeErrorContext {
+                    offset,
+                    section: None,
+                    index: None,
+                };
+                DecodeError::Context(context, Box::new(error))
+            }
+        }
+    }
+
+    /// Add the section and the index of the question or resource record to the context.
+    pub(super) fn with_section(self, section: Section, index: u16) -> DecodeError {
+        match self {
+            DecodeError::Context(mut context, error) => {
+                context.section = Some(section);
+                context.index = Some(index as usize);
+                DecodeError::Context(context, error)
+            }
+            error => error,
+        }
+    }
+}
+
+/// A section of a DNS message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Section {
+    Question,
+    Answer,
+    Authority,
+    Additional,
+}
+
+impl Display for Section {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Section::Question => write!(f, "question"),
+            Section::Answer => write!(f, "answer"),
+            Section::Authority => write!(f, "authority"),
+            Section::Additional => write!(f, "additional"),
+        }
+    }
+}
+
+/// The position of a question or a resource record, which could not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeErrorContext {
+    /// The absolute offset in the main buffer, where the decoding failed.
+    pub offset: usize,
+    /// The section of the question or the resource record, if it was decoded as part of a
+    /// message.
+    pub section: Option<Section>,
+    /// The index of the question or the resource record inside of the section.
+    pub index: Option<usize>,
+}
+
+impl Display for DecodeErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let (Some(section), Some(index)) = (self.section, self.index) {
+            write!(f, "{} section index {} ", section, index)?;
+        }
+        write!(f, "offset {}", self.offset)
+    }
 }
@@ -32,6 +32,12 @@ impl<'a, 'b: 'a> Decoder<'a, 'b> {
         self.string(length)
     }
 
+    pub(super) fn vec_with_len(&mut self) -> DecodeResult<Vec<u8>> {
+        let length = self.u8()? as usize;
+        let buffer = self.read(length)?;
+        Ok(buffer.to_vec())
+    }
+
     pub(super) fn string(&mut self, length: usize) -> DecodeResult<String> {
         let buffer = self.read(length)?;
         let string = from_utf8(buffer.as_ref())?;
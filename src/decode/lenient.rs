@@ -0,0 +1,187 @@
+use super::rr::rr_opt_ttl;
+use super::view::{QuestionView, RRView};
+use super::Decoder;
+use crate::question::Question;
+use crate::rr::{Type, RR};
+use crate::{DecodeError, DecodeResult, Dns, Opcode, MAXIMUM_DNS_PACKET_SIZE};
+use bytes::Bytes;
+
+/// The options, which are used by [`Dns::decode_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DecodeOptions {
+    /// Decode as much of the message as possible and report the errors as [`DecodeWarning`].
+    ///
+    /// The following errors are tolerated:
+    /// * A non-zero Z bit, an unknown opcode and an unknown RCode in the header. An unknown
+    ///   opcode and RCode are decoded as [`Opcode::Unknown`] and [`RCode::Unknown`].
+    /// * Non-zero reserved bits in the TTL of an OPT record, which are ignored.
+    /// * Questions and resource records, which cannot be decoded, for example because of an
+    ///   unknown class. They are skipped and their raw bytes are stored in the warning.
+    ///
+    /// If the end of a question or a resource record cannot be determined then the rest of the
+    /// message is skipped.
+    ///
+    /// [`Opcode::Unknown`]: crate::Opcode::Unknown
+    /// [`RCode::Unknown`]: crate::RCode::Unknown
+    pub lenient: bool,
+}
+
+/// An error, which was tolerated by the lenient decoding.
+#[derive(Debug, PartialEq)]
+pub struct DecodeWarning {
+    /// The offset of the header field, the question or the resource record, which caused the
+    /// error.
+    pub offset: usize,
+    pub error: DecodeError,
+    /// The raw bytes, which were skipped. The domain names inside of them can be compressed.
+    pub skipped: Option<Bytes>,
+}
+
+impl<'a, 'b: 'a> Decoder<'b, 'b> {
+    /// Skip the rest of the message and return `false`.
+    fn skip_remaining(&mut self, error: DecodeError, warnings: &mut Vec<DecodeWarning>) -> bool {
+        let offset = self.offset;
+        warnings.push(DecodeWarning {
+            offset,
+            error,
+            skipped: Some(self.bytes.slice(offset..)),
+        });
+        self.offset = self.bytes.len();
+        false
+    }
+
+    /// Decode the questions or skip them, if they cannot be decoded.
+    ///
+    /// Returns `false` if the rest of the message was skipped.
+    fn questions_lenient(
+        &mut self,
+        count: u16,
+        questions: &mut Vec<Question>,
+        warnings: &mut Vec<DecodeWarning>,
+    ) -> bool {
+        for _ in 0..count {
+            let offset = self.offset;
+            let end = match QuestionView::parse(&self.bytes, offset) {
+                Ok((_, end)) => end,
+                Err(error) => return self.skip_remaining(error, warnings),
+            };
+            match self.question() {
+                Ok(question) => questions.push(question),
+                Err(error) => warnings.push(DecodeWarning {
+                    offset,
+                    error: error.into_inner(),
+                    skipped: Some(self.bytes.slice(offset..end)),
+                }),
+            }
+            self.offset = end;
+        }
+        true
+    }
+
+    /// Decode the resource records or skip them, if they cannot be decoded.
+    ///
+    /// Returns `false` if the rest of the message was skipped.
+    fn rrs_lenient(
+        &mut self,
+        count: u16,
+        rrs: &mut Vec<RR>,
+        warnings: &mut Vec<DecodeWarning>,
+    ) -> bool {
+        for _ in 0..count {
+            let offset = self.offset;
+            let (type_code, ttl, end) = match RRView::parse(&self.bytes, offset) {
+                Ok((rr, end)) => (rr.type_code(), rr.ttl(), end),
+                Err(error) => return self.skip_remaining(error, warnings),
+            };
+            if type_code == Type::OPT as u16 {
+                if let Err(error) = rr_opt_ttl(ttl) {
+                    warnings.push(DecodeWarning {
+                        offset,
+                        error,
+                        skipped: None,
+                    });
+                }
+            }
+            match self.rr() {
+                Ok(rr) => rrs.push(rr),
+                Err(error) => warnings.push(DecodeWarning {
+                    offset,
+                    error: error.into_inner(),
+                    skipped: Some(self.bytes.slice(offset..end)),
+                }),
+            }
+            self.offset = end;
+        }
+        true
+    }
+
+    fn dns_lenient(&'a mut self) -> DecodeResult<(Dns, Vec<DecodeWarning>)> {
+        if self.offset != 0 {
+            return Err(DecodeError::Offset(self.offset));
+        }
+
+        let bytes_len = self.bytes.len();
+        if bytes_len < 12 {
+            return Err(DecodeError::NotEnoughBytes(bytes_len, 12));
+        } else if bytes_len > MAXIMUM_DNS_PACKET_SIZE {
+            return Err(DecodeError::DnsPacketTooBig(bytes_len));
+        }
+
+        let mut warnings = Vec::new();
+        let id = self.u16()?;
+        let flags_offset = self.offset;
+        let flags = self.flags_tolerant(|error| {
+            warnings.push(DecodeWarning {
+                offset: flags_offset,
+                error,
+                skipped: None,
+            });
+            Ok(())
+        })?;
+        self.update = flags.opcode == Opcode::Update;
+        let question_count = self.u16()?;
+        let answer_count = self.u16()?;
+        let authority_count = self.u16()?;
+        let additional_count = self.u16()?;
+
+        let mut dns = Dns {
+            id,
+            flags,
+            questions: Vec::new(),
+            answers: Vec::new(),
+            authorities: Vec::new(),
+            additionals: Vec::new(),
+        };
+        let is_complete = self.questions_lenient(question_count, &mut dns.questions, &mut warnings)
+            && self.rrs_lenient(answer_count, &mut dns.answers, &mut warnings)
+            && self.rrs_lenient(authority_count, &mut dns.authorities, &mut warnings)
+            && self.rrs_lenient(additional_count, &mut dns.additionals, &mut warnings);
+        if is_complete && self.offset < bytes_len {
+            self.skip_remaining(
+                DecodeError::TooManyBytes(bytes_len, self.offset),
+                &mut warnings,
+            );
+        }
+
+        Ok((dns, warnings))
+    }
+}
+
+impl Dns {
+    /// Decode the DNS message with the options.
+    ///
+    /// The warnings are always empty, if the lenient mode is disabled. In the lenient mode an
+    /// error is only returned, if the header cannot be decoded.
+    pub fn decode_with_options(
+        bytes: Bytes,
+        options: DecodeOptions,
+    ) -> DecodeResult<(Dns, Vec<DecodeWarning>)> {
+        if !options.lenient {
+            return Ok((Dns::decode(bytes)?, Vec::new()));
+        }
+
+        let mut decoder = Decoder::main(bytes);
+        decoder.lenient = true;
+        decoder.dns_lenient()
+    }
+}
@@ -5,12 +5,15 @@ mod dns;
 mod domain_name;
 mod error;
 mod helpers;
+mod lenient;
 mod question;
 mod rr;
 #[cfg(test)]
 mod tests;
+pub mod view;
 
 use decoder::Decoder;
-pub use error::DecodeError;
+pub use error::{DecodeError, DecodeErrorContext, Section};
+pub use lenient::{DecodeOptions, DecodeWarning};
 
 pub type DecodeResult<T> = std::result::Result<T, DecodeError>;
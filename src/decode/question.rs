@@ -22,7 +22,13 @@ impl<'a, 'b: 'a> Decoder<'a, 'b> {
         }
     }
 
+    /// Decode a question, the errors contain the absolute offset, where the decoding failed.
     pub fn question(&mut self) -> DecodeResult<Question> {
+        self.question_without_context()
+            .map_err(|error| error.with_offset(self.offset))
+    }
+
+    fn question_without_context(&mut self) -> DecodeResult<Question> {
         let domain_name = self.domain_name()?;
         let q_type = self.q_type()?;
         let q_class = self.q_class()?;
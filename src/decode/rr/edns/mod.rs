@@ -1,5 +1,12 @@
+mod rfc_5001;
 mod rfc_6891;
+mod rfc_6975;
+mod rfc_7314;
+mod rfc_7828;
 mod rfc_7830;
 mod rfc_7871;
 mod rfc_7873;
+mod rfc_7901;
 mod rfc_8914;
+
+pub(in crate::decode) use rfc_6891::rr_opt_ttl;
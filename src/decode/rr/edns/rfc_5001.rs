@@ -0,0 +1,12 @@
+use crate::decode::Decoder;
+use crate::rr::edns::NSID;
+use crate::DecodeResult;
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    pub(super) fn rr_edns_nsid(&mut self) -> DecodeResult<NSID> {
+        // The identifier can be empty in a request.
+        let length = self.remaining()?;
+        let nsid = self.read(length)?;
+        Ok(NSID(nsid.to_vec()))
+    }
+}
@@ -5,7 +5,10 @@ use crate::rr::OPT;
 use crate::{DecodeError, DecodeResult};
 use std::convert::TryFrom;
 
-fn rr_opt_ttl(ttl: u32) -> DecodeResult<(u8, u8, bool)> {
+/// The mask of the EDNS flags, which are not reserved.
+const OPT_TTL_FLAGS_MASK: u32 = 0xffff_8000;
+
+pub(in crate::decode) fn rr_opt_ttl(ttl: u32) -> DecodeResult<(u8, u8, bool)> {
     let extend_rcode = ((ttl >> 24) & 0xff) as u8;
     let version = ((ttl >> 16) & 0xff) as u8;
     let buffer = ((ttl >> 8) & 0xff) as u8;
@@ -23,13 +26,31 @@ fn rr_opt_ttl(ttl: u32) -> DecodeResult<(u8, u8, bool)> {
 
 impl<'a, 'b: 'a> Decoder<'b, 'b> {
     fn rr_edns_option(&'a mut self) -> DecodeResult<EDNSOption> {
-        let edns_option_code = self.rr_edns_option_code()?;
+        let code = self.u16()?;
         let edns_option_length = self.u16()?;
         let mut edns_option_data = self.sub(edns_option_length)?;
+        let edns_option_code = match EDNSOptionCode::try_from(code) {
+            Ok(edns_option_code) => edns_option_code,
+            Err(code) => {
+                // The data of an option can be empty, therefore vec() cannot be used.
+                let length = edns_option_data.remaining()?;
+                let data = edns_option_data.read(length)?.to_vec();
+                return Ok(EDNSOption::Unknown { code, data });
+            }
+        };
         let edns_option = match edns_option_code {
+            EDNSOptionCode::NSID => EDNSOption::NSID(edns_option_data.rr_edns_nsid()?),
+            EDNSOptionCode::DAU => EDNSOption::DAU(edns_option_data.rr_edns_dau()?),
+            EDNSOptionCode::DHU => EDNSOption::DHU(edns_option_data.rr_edns_dhu()?),
+            EDNSOptionCode::N3U => EDNSOption::N3U(edns_option_data.rr_edns_n3u()?),
             EDNSOptionCode::ECS => EDNSOption::ECS(edns_option_data.rr_edns_ecs()?),
+            EDNSOptionCode::Expire => EDNSOption::Expire(edns_option_data.rr_edns_expire()?),
             EDNSOptionCode::Cookie => EDNSOption::Cookie(edns_option_data.rr_edns_cookie()?),
+            EDNSOptionCode::TcpKeepalive => {
+                EDNSOption::TcpKeepalive(edns_option_data.rr_edns_tcp_keepalive()?)
+            }
             EDNSOptionCode::Padding => EDNSOption::Padding(edns_option_data.rr_edns_padding()?),
+            EDNSOptionCode::Chain => EDNSOption::Chain(edns_option_data.rr_edns_chain()?),
             EDNSOptionCode::ExtendedDnsError => {
                 EDNSOption::ExtendedDNSErrors(edns_option_data.rr_edns_extended_dns_errors()?)
             }
@@ -43,8 +64,13 @@ impl<'a, 'b: 'a> Decoder<'b, 'b> {
             return Err(DecodeError::OPTDomainName(header.domain_name));
         }
         let requestor_payload_size = header.class;
-        // TODO
-        let (extend_rcode, version, dnssec) = rr_opt_ttl(header.ttl)?;
+        let ttl = if self.get_main().lenient {
+            // The reserved bits are reported as warning by the lenient decoding.
+            header.ttl & OPT_TTL_FLAGS_MASK
+        } else {
+            header.ttl
+        };
+        let (extend_rcode, version, dnssec) = rr_opt_ttl(ttl)?;
         let mut edns_options = Vec::new();
         while !self.is_finished()? {
             edns_options.push(self.rr_edns_option()?);
@@ -59,13 +85,3 @@ impl<'a, 'b: 'a> Decoder<'b, 'b> {
         Ok(opt)
     }
 }
-
-impl<'a, 'b: 'a> Decoder<'a, 'b> {
-    fn rr_edns_option_code(&mut self) -> DecodeResult<EDNSOptionCode> {
-        let buffer = self.u16()?;
-        match EDNSOptionCode::try_from(buffer) {
-            Ok(ends_option_code) => Ok(ends_option_code),
-            Err(buffer) => Err(DecodeError::EDNSOptionCode(buffer)),
-        }
-    }
-}
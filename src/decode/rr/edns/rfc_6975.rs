@@ -0,0 +1,29 @@
+use crate::decode::Decoder;
+use crate::rr::edns::{DAU, DHU, N3U};
+use crate::DecodeResult;
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    pub(super) fn rr_edns_dau(&mut self) -> DecodeResult<DAU> {
+        let mut algorithm_types = Vec::new();
+        while !self.is_finished()? {
+            algorithm_types.push(self.u8()?);
+        }
+        Ok(DAU(algorithm_types))
+    }
+
+    pub(super) fn rr_edns_dhu(&mut self) -> DecodeResult<DHU> {
+        let mut digest_types = Vec::new();
+        while !self.is_finished()? {
+            digest_types.push(self.u8()?);
+        }
+        Ok(DHU(digest_types))
+    }
+
+    pub(super) fn rr_edns_n3u(&mut self) -> DecodeResult<N3U> {
+        let mut hash_algorithms = Vec::new();
+        while !self.is_finished()? {
+            hash_algorithms.push(self.u8()?);
+        }
+        Ok(N3U(hash_algorithms))
+    }
+}
@@ -0,0 +1,15 @@
+use crate::decode::Decoder;
+use crate::{DecodeError, DecodeResult};
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    /// Decode the [Expire] option, which is empty in a request.
+    ///
+    /// [Expire]: https://tools.ietf.org/html/rfc7314#section-2
+    pub(super) fn rr_edns_expire(&mut self) -> DecodeResult<Option<u32>> {
+        match self.remaining()? {
+            0 => Ok(None),
+            4 => Ok(Some(self.u32()?)),
+            length => Err(DecodeError::ExpireLength(length)),
+        }
+    }
+}
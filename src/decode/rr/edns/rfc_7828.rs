@@ -0,0 +1,13 @@
+use crate::decode::Decoder;
+use crate::rr::edns::TcpKeepalive;
+use crate::{DecodeError, DecodeResult};
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    pub(super) fn rr_edns_tcp_keepalive(&mut self) -> DecodeResult<TcpKeepalive> {
+        match self.remaining()? {
+            0 => Ok(TcpKeepalive(None)),
+            2 => Ok(TcpKeepalive(Some(self.u16()?))),
+            length => Err(DecodeError::TcpKeepaliveLength(length)),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use crate::decode::Decoder;
+use crate::{DecodeResult, DomainName};
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    /// Decode the closest trust point of the [CHAIN] option.
+    ///
+    /// [CHAIN]: https://tools.ietf.org/html/rfc7901#section-4
+    pub(super) fn rr_edns_chain(&mut self) -> DecodeResult<DomainName> {
+        self.domain_name()
+    }
+}
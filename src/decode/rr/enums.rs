@@ -1,6 +1,7 @@
 use crate::decode::Decoder;
-use crate::rr::{Class, Type, RR};
+use crate::rr::{Class, Type, Unknown, RR};
 use crate::{DecodeError, DecodeResult, DomainName};
+use bytes::Bytes;
 use std::convert::TryFrom;
 
 pub(super) struct Header {
@@ -25,67 +26,112 @@ impl<'a, 'b: 'a> Decoder<'b, 'b> {
         Ok(r_data)
     }
 
+    /// Decode a resource record, the errors contain the absolute offset, where the decoding
+    /// failed.
     pub fn rr(&'a mut self) -> DecodeResult<RR> {
-        let (type_, header) = self.rr_header()?;
-        let mut r_data = self.rr_data()?;
+        let (type_code, header) = self
+            .rr_header()
+            .map_err(|error| error.with_offset(self.offset))?;
+        let rd_length_offset = self.offset;
+        let mut r_data = self
+            .rr_data()
+            .map_err(|error| error.with_offset(rd_length_offset))?;
+        // The RDATA starts after the RDLENGTH field.
+        let r_data_offset = rd_length_offset + 2;
+        let rr = r_data
+            .rr_rdata(type_code, header)
+            .map_err(|error| error.with_offset(r_data_offset + r_data.offset))?;
+        let r_data_end = r_data_offset + r_data.offset;
+        r_data
+            .finished()
+            .map_err(|error| error.with_offset(r_data_end))?;
+        Ok(rr)
+    }
+}
+
+impl<'b> Decoder<'b, 'b> {
+    fn rr_rdata(&mut self, type_code: u16, header: Header) -> DecodeResult<RR> {
+        let is_update_class =
+            header.class == Class::NONE as u16 || header.class == Class::ANY as u16;
+        let is_meta_type = type_code == Type::OPT as u16 || type_code == Type::TSIG as u16;
+        if self.get_main().update && is_update_class && !is_meta_type {
+            return self.rr_update(type_code, header);
+        }
+
+        let type_ = match Type::try_from(type_code) {
+            Ok(type_) => type_,
+            Err(type_code) => {
+                let unknown = self.rr_unknown(header, type_code)?;
+                return Ok(RR::Unknown(unknown));
+            }
+        };
         let rr = match type_ {
-            Type::A => RR::A(r_data.rr_a(header)?),
-            Type::NS => RR::NS(r_data.rr_ns(header)?),
-            Type::MD => RR::MD(r_data.rr_md(header)?),
-            Type::MF => RR::MF(r_data.rr_mf(header)?),
-            Type::CNAME => RR::CNAME(r_data.rr_cname(header)?),
-            Type::SOA => RR::SOA(r_data.rr_soa(header)?),
-            Type::MB => RR::MB(r_data.rr_mb(header)?),
-            Type::MG => RR::MG(r_data.rr_mg(header)?),
-            Type::MR => RR::MR(r_data.rr_mr(header)?),
-            Type::NULL => RR::NULL(r_data.rr_null(header)?),
-            Type::WKS => RR::WKS(r_data.rr_wks(header)?),
-            Type::PTR => RR::PTR(r_data.rr_ptr(header)?),
-            Type::HINFO => RR::HINFO(r_data.rr_hinfo(header)?),
-            Type::MINFO => RR::MINFO(r_data.rr_minfo(header)?),
-            Type::MX => RR::MX(r_data.rr_mx(header)?),
-            Type::TXT => RR::TXT(r_data.rr_txt(header)?),
-            Type::RP => RR::RP(r_data.rr_rp(header)?),
-            Type::AFSDB => RR::AFSDB(r_data.rr_afsdb(header)?),
-            Type::X25 => RR::X25(r_data.rr_x25(header)?),
-            Type::ISDN => RR::ISDN(r_data.rr_isdn(header)?),
-            Type::RT => RR::RT(r_data.rr_rt(header)?),
-            Type::NSAP => RR::NSAP(r_data.rr_nsap(header)?),
-            Type::GPOS => RR::GPOS(r_data.rr_gpos(header)?),
-            Type::LOC => RR::LOC(r_data.rr_loc(header)?),
-            Type::PX => RR::PX(r_data.rr_px(header)?),
-            Type::KX => RR::KX(r_data.rr_kx(header)?),
-            Type::SRV => RR::SRV(r_data.rr_srv(header)?),
-            Type::AAAA => RR::AAAA(r_data.rr_aaaa(header)?),
-            Type::SSHFP => RR::SSHFP(r_data.rr_sshfp(header)?),
-            Type::DNAME => RR::DNAME(r_data.rr_dname(header)?),
-            Type::OPT => RR::OPT(r_data.rr_opt(header)?),
-            Type::APL => RR::APL(r_data.rr_apl(header)?),
-            Type::NID => RR::NID(r_data.rr_nid(header)?),
-            Type::L32 => RR::L32(r_data.rr_l32(header)?),
-            Type::L64 => RR::L64(r_data.rr_l64(header)?),
-            Type::LP => RR::LP(r_data.rr_lp(header)?),
-            Type::EUI48 => RR::EUI48(r_data.rr_eui48(header)?),
-            Type::EUI64 => RR::EUI64(r_data.rr_eui64(header)?),
-            Type::URI => RR::URI(r_data.rr_uri(header)?),
-            Type::EID => RR::EID(r_data.rr_eid(header)?),
-            Type::NIMLOC => RR::NIMLOC(r_data.rr_nimloc(header)?),
-            Type::DNSKEY => RR::DNSKEY(r_data.rr_dnskey(header)?),
-            Type::DS => RR::DS(r_data.rr_ds(header)?),
-            Type::CAA => RR::CAA(r_data.rr_caa(header)?),
-            Type::SVCB => RR::SVCB(r_data.rr_service_binding(header, false)?),
-            Type::HTTPS => RR::HTTPS(r_data.rr_service_binding(header, true)?),
-            type_ => return Err(DecodeError::NotYetImplemented(type_)),
+            Type::A => RR::A(self.rr_a(header)?),
+            Type::NS => RR::NS(self.rr_ns(header)?),
+            Type::MD => RR::MD(self.rr_md(header)?),
+            Type::MF => RR::MF(self.rr_mf(header)?),
+            Type::CNAME => RR::CNAME(self.rr_cname(header)?),
+            Type::SOA => RR::SOA(self.rr_soa(header)?),
+            Type::MB => RR::MB(self.rr_mb(header)?),
+            Type::MG => RR::MG(self.rr_mg(header)?),
+            Type::MR => RR::MR(self.rr_mr(header)?),
+            Type::NULL => RR::NULL(self.rr_null(header)?),
+            Type::WKS => RR::WKS(self.rr_wks(header)?),
+            Type::PTR => RR::PTR(self.rr_ptr(header)?),
+            Type::HINFO => RR::HINFO(self.rr_hinfo(header)?),
+            Type::MINFO => RR::MINFO(self.rr_minfo(header)?),
+            Type::MX => RR::MX(self.rr_mx(header)?),
+            Type::TXT => RR::TXT(self.rr_txt(header)?),
+            Type::RP => RR::RP(self.rr_rp(header)?),
+            Type::AFSDB => RR::AFSDB(self.rr_afsdb(header)?),
+            Type::X25 => RR::X25(self.rr_x25(header)?),
+            Type::ISDN => RR::ISDN(self.rr_isdn(header)?),
+            Type::RT => RR::RT(self.rr_rt(header)?),
+            Type::NSAP => RR::NSAP(self.rr_nsap(header)?),
+            Type::GPOS => RR::GPOS(self.rr_gpos(header)?),
+            Type::LOC => RR::LOC(self.rr_loc(header)?),
+            Type::PX => RR::PX(self.rr_px(header)?),
+            Type::KX => RR::KX(self.rr_kx(header)?),
+            Type::SRV => RR::SRV(self.rr_srv(header)?),
+            Type::AAAA => RR::AAAA(self.rr_aaaa(header)?),
+            Type::SSHFP => RR::SSHFP(self.rr_sshfp(header)?),
+            Type::DNAME => RR::DNAME(self.rr_dname(header)?),
+            Type::OPT => RR::OPT(self.rr_opt(header)?),
+            Type::APL => RR::APL(self.rr_apl(header)?),
+            Type::NID => RR::NID(self.rr_nid(header)?),
+            Type::L32 => RR::L32(self.rr_l32(header)?),
+            Type::L64 => RR::L64(self.rr_l64(header)?),
+            Type::LP => RR::LP(self.rr_lp(header)?),
+            Type::EUI48 => RR::EUI48(self.rr_eui48(header)?),
+            Type::EUI64 => RR::EUI64(self.rr_eui64(header)?),
+            Type::URI => RR::URI(self.rr_uri(header)?),
+            Type::EID => RR::EID(self.rr_eid(header)?),
+            Type::NIMLOC => RR::NIMLOC(self.rr_nimloc(header)?),
+            Type::DNSKEY => RR::DNSKEY(self.rr_dnskey(header)?),
+            Type::DS => RR::DS(self.rr_ds(header)?),
+            Type::CAA => RR::CAA(self.rr_caa(header)?),
+            Type::SVCB => RR::SVCB(self.rr_service_binding(header, false)?),
+            Type::HTTPS => RR::HTTPS(self.rr_service_binding(header, true)?),
+            Type::RRSIG => RR::RRSIG(self.rr_rrsig(header)?),
+            Type::NSEC => RR::NSEC(self.rr_nsec(header)?),
+            Type::NSEC3 => RR::NSEC3(self.rr_nsec3(header)?),
+            Type::NSEC3PARAM => RR::NSEC3PARAM(self.rr_nsec3param(header)?),
+            Type::TLSA => RR::TLSA(self.rr_tlsa(header)?),
+            Type::SMIMEA => RR::SMIMEA(self.rr_smimea(header)?),
+            Type::NAPTR => RR::NAPTR(self.rr_naptr(header)?),
+            Type::CDS => RR::CDS(self.rr_ds(header)?),
+            Type::CDNSKEY => RR::CDNSKEY(self.rr_dnskey(header)?),
+            Type::TSIG => RR::TSIG(self.rr_tsig(header)?),
+            _ => RR::Unknown(self.rr_unknown(header, type_code)?),
         };
-        r_data.finished()?;
         Ok(rr)
     }
 }
 
 impl<'a, 'b: 'a> Decoder<'a, 'b> {
-    fn rr_header(&mut self) -> DecodeResult<(Type, Header)> {
+    fn rr_header(&mut self) -> DecodeResult<(u16, Header)> {
         let domain_name = self.domain_name()?;
-        let type_ = self.rr_type()?;
+        let type_code = self.u16()?;
         let class = self.u16()?;
         let ttl = self.u32()?;
         let header = Header {
@@ -93,7 +139,7 @@ impl<'a, 'b: 'a> Decoder<'a, 'b> {
             class,
             ttl,
         };
-        Ok((type_, header))
+        Ok((type_code, header))
     }
 
     pub fn rr_class(&mut self) -> DecodeResult<Class> {
@@ -118,3 +164,23 @@ impl_decode!(Class, rr_class);
 impl_decode!(Type, rr_type);
 
 impl_decode!(RR, rr);
+
+impl Unknown {
+    /// Decode the RDATA, which must not contain compressed domain names, as the resource record
+    /// of the type code with the given class.
+    ///
+    /// The records with the class NONE or ANY are kept as [`Unknown`] like in UPDATE messages,
+    /// because these classes are only used there.
+    pub(crate) fn decode_rdata(&self, class: Class) -> DecodeResult<RR> {
+        let mut decoder = Decoder::main(Bytes::copy_from_slice(&self.rdata));
+        decoder.update = true;
+        let header = Header {
+            domain_name: self.domain_name.clone(),
+            class: class as u16,
+            ttl: self.ttl,
+        };
+        let rr = decoder.rr_rdata(self.type_code, header)?;
+        decoder.finished()?;
+        Ok(rr)
+    }
+}
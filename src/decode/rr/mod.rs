@@ -8,21 +8,29 @@ mod rfc_1183;
 mod rfc_1706;
 mod rfc_1712;
 mod rfc_1876;
+mod rfc_2136;
 mod rfc_2163;
 mod rfc_2230;
 mod rfc_2782;
 mod rfc_3123;
+mod rfc_3403;
 mod rfc_3596;
+mod rfc_3597;
 mod rfc_3658;
 mod rfc_4034;
+mod rfc_5155;
 mod rfc_6672;
+mod rfc_6698;
 mod rfc_6742;
 mod rfc_7043;
 mod rfc_7553;
+mod rfc_8162;
 mod rfc_8659;
+mod rfc_8945;
 mod subtypes;
 #[cfg(test)]
 mod tests;
 mod unknown;
 
+pub(super) use edns::rr_opt_ttl;
 use enums::Header;
@@ -0,0 +1,101 @@
+use super::Header;
+use crate::decode::Decoder;
+use crate::rr::{Type, Unknown, RR};
+use crate::DecodeResult;
+use std::convert::TryFrom;
+
+/// A field of the RDATA, which precedes the last domain name, that can be compressed.
+enum Field {
+    Octets(usize),
+    CharacterString,
+    DomainName,
+}
+
+/// Returns the fields of the RDATA of the type up to the last domain name, which can be
+/// compressed. The remaining RDATA does not contain a compressed domain name.
+fn compressed_fields(type_: Type) -> &'static [Field] {
+    match type_ {
+        Type::NS
+        | Type::MD
+        | Type::MF
+        | Type::CNAME
+        | Type::MB
+        | Type::MG
+        | Type::MR
+        | Type::PTR
+        | Type::DNAME
+        | Type::NSEC => &[Field::DomainName],
+        Type::SOA | Type::MINFO | Type::RP => &[Field::DomainName, Field::DomainName],
+        Type::MX | Type::AFSDB | Type::RT | Type::KX | Type::LP | Type::SVCB | Type::HTTPS => {
+            &[Field::Octets(2), Field::DomainName]
+        }
+        Type::PX => &[Field::Octets(2), Field::DomainName, Field::DomainName],
+        Type::SRV => &[Field::Octets(6), Field::DomainName],
+        Type::NAPTR => &[
+            Field::Octets(4),
+            Field::CharacterString,
+            Field::CharacterString,
+            Field::CharacterString,
+            Field::DomainName,
+        ],
+        Type::RRSIG => &[Field::Octets(18), Field::DomainName],
+        _ => &[],
+    }
+}
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    /// Read the domain name and append it without compression to the RDATA.
+    fn rr_update_domain_name(&mut self, rdata: &mut Vec<u8>) -> DecodeResult<()> {
+        let domain_name = self.domain_name()?;
+        for label in domain_name.0.iter() {
+            rdata.push(label.len() as u8);
+            rdata.extend_from_slice(label.as_bytes());
+        }
+        rdata.push(0);
+        Ok(())
+    }
+
+    /// Read the RDATA and decompress the domain names of the types, which are decoded with
+    /// compression.
+    fn rr_update_rdata(&mut self, type_code: u16) -> DecodeResult<Vec<u8>> {
+        let mut rdata = Vec::new();
+        if self.is_finished()? {
+            return Ok(rdata);
+        }
+        if let Ok(type_) = Type::try_from(type_code) {
+            for field in compressed_fields(type_) {
+                match field {
+                    Field::Octets(length) => rdata.extend_from_slice(&self.read(*length)?),
+                    Field::CharacterString => {
+                        let length = self.u8()?;
+                        rdata.push(length);
+                        rdata.extend_from_slice(&self.read(length as usize)?);
+                    }
+                    Field::DomainName => self.rr_update_domain_name(&mut rdata)?,
+                }
+            }
+        }
+        rdata.extend_from_slice(&self.read(self.remaining()?)?);
+        Ok(rdata)
+    }
+
+    /// Decode a resource record with the class NONE or ANY, which is only used in [UPDATE]
+    /// messages.
+    ///
+    /// These records are decoded as [`Unknown`], because the RDATA is empty or belongs to a record
+    /// in a different class. The RDATA is stored without compression.
+    ///
+    /// [UPDATE]: https://tools.ietf.org/html/rfc2136#section-2.4
+    pub(super) fn rr_update(&mut self, type_code: u16, header: Header) -> DecodeResult<RR> {
+        let class = header.get_class()?;
+        let rdata = self.rr_update_rdata(type_code)?;
+        let unknown = Unknown {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class,
+            type_code,
+            rdata,
+        };
+        Ok(RR::Unknown(unknown))
+    }
+}
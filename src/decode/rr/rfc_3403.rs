@@ -0,0 +1,28 @@
+use super::Header;
+use crate::decode::Decoder;
+use crate::rr::NAPTR;
+use crate::DecodeResult;
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    pub(super) fn rr_naptr(&mut self, header: Header) -> DecodeResult<NAPTR> {
+        let class = header.get_class()?;
+        let order = self.u16()?;
+        let preference = self.u16()?;
+        let flags = self.string_with_len()?;
+        let services = self.string_with_len()?;
+        let regexp = self.string_with_len()?;
+        let replacement = self.domain_name()?;
+        let naptr = NAPTR {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class,
+            order,
+            preference,
+            flags,
+            services,
+            regexp,
+            replacement,
+        };
+        Ok(naptr)
+    }
+}
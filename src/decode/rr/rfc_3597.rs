@@ -0,0 +1,19 @@
+use super::Header;
+use crate::decode::Decoder;
+use crate::rr::Unknown;
+use crate::DecodeResult;
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    pub(super) fn rr_unknown(&mut self, header: Header, type_code: u16) -> DecodeResult<Unknown> {
+        let class = header.get_class()?;
+        let rdata = self.read(self.remaining()?)?.to_vec();
+        let unknown = Unknown {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class,
+            type_code,
+            rdata,
+        };
+        Ok(unknown)
+    }
+}
@@ -1,13 +1,14 @@
 use super::Header;
 use crate::rr::{
-    AlgorithmType, DigestType, DNSKEY, DNSKEY_ZERO_MASK, DS, SECURE_ENTRY_POINT_FLAG, ZONE_KEY_FLAG,
+    AlgorithmType, DigestType, DNSKEY, DNSKEY_ZERO_MASK, DS, NSEC, RRSIG, SECURE_ENTRY_POINT_FLAG,
+    ZONE_KEY_FLAG,
 };
 use crate::DecodeResult;
 use crate::{decode::Decoder, DecodeError};
 use std::convert::TryFrom;
 
 impl<'a, 'b: 'a> Decoder<'a, 'b> {
-    fn rr_algorithm_type(&mut self) -> DecodeResult<AlgorithmType> {
+    pub(super) fn rr_algorithm_type(&mut self) -> DecodeResult<AlgorithmType> {
         let buffer = self.u8()?;
         match AlgorithmType::try_from(buffer) {
             Ok(algorithm_type) => Ok(algorithm_type),
@@ -15,7 +16,7 @@ impl<'a, 'b: 'a> Decoder<'a, 'b> {
         }
     }
 
-    fn rr_digest_type(&mut self) -> DecodeResult<DigestType> {
+    pub(super) fn rr_digest_type(&mut self) -> DecodeResult<DigestType> {
         let buffer = self.u8()?;
         match DigestType::try_from(buffer) {
             Ok(digest_type) => Ok(digest_type),
@@ -66,4 +67,46 @@ impl<'a, 'b: 'a> Decoder<'a, 'b> {
         };
         Ok(ds)
     }
+
+    pub(super) fn rr_rrsig(&mut self, header: Header) -> DecodeResult<RRSIG> {
+        let class = header.get_class()?;
+        let type_covered = self.u16()?;
+        let algorithm_type = self.u8()?;
+        let labels = self.u8()?;
+        let original_ttl = self.u32()?;
+        let signature_expiration = self.u32()?;
+        let signature_inception = self.u32()?;
+        let key_tag = self.u16()?;
+        let signer_name = self.domain_name()?;
+        let signature = self.vec()?;
+        let rrsig = RRSIG {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class,
+            type_covered,
+            algorithm_type,
+            labels,
+            original_ttl,
+            signature_expiration,
+            signature_inception,
+            key_tag,
+            signer_name,
+            signature,
+        };
+        Ok(rrsig)
+    }
+
+    pub(super) fn rr_nsec(&mut self, header: Header) -> DecodeResult<NSEC> {
+        let class = header.get_class()?;
+        let next_domain_name = self.domain_name()?;
+        let types = self.rr_type_bit_map()?;
+        let nsec = NSEC {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class,
+            next_domain_name,
+            types,
+        };
+        Ok(nsec)
+    }
 }
@@ -0,0 +1,48 @@
+use super::Header;
+use crate::rr::{NSEC3, NSEC3PARAM};
+use crate::{decode::Decoder, DecodeError, DecodeResult};
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    pub(super) fn rr_nsec3(&mut self, header: Header) -> DecodeResult<NSEC3> {
+        let class = header.get_class()?;
+        let hash_algorithm = self.u8()?;
+        let flags = self.u8()?;
+        let iterations = self.u16()?;
+        let salt = self.vec_with_len()?;
+        let next_hashed_owner_name = self.vec_with_len()?;
+        if next_hashed_owner_name.is_empty() {
+            return Err(DecodeError::NSEC3NextHashedOwnerName);
+        }
+        let types = self.rr_type_bit_map()?;
+        let nsec3 = NSEC3 {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class,
+            hash_algorithm,
+            flags,
+            iterations,
+            salt,
+            next_hashed_owner_name,
+            types,
+        };
+        Ok(nsec3)
+    }
+
+    pub(super) fn rr_nsec3param(&mut self, header: Header) -> DecodeResult<NSEC3PARAM> {
+        let class = header.get_class()?;
+        let hash_algorithm = self.u8()?;
+        let flags = self.u8()?;
+        let iterations = self.u16()?;
+        let salt = self.vec_with_len()?;
+        let nsec3param = NSEC3PARAM {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class,
+            hash_algorithm,
+            flags,
+            iterations,
+            salt,
+        };
+        Ok(nsec3param)
+    }
+}
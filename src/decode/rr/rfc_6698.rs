@@ -0,0 +1,49 @@
+use super::Header;
+use crate::decode::Decoder;
+use crate::rr::{TLSACertificateUsage, TLSAMatchingType, TLSASelector, TLSA};
+use crate::{DecodeError, DecodeResult};
+use std::convert::TryFrom;
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    pub(super) fn rr_tlsa_certificate_usage(&mut self) -> DecodeResult<TLSACertificateUsage> {
+        let buffer = self.u8()?;
+        match TLSACertificateUsage::try_from(buffer) {
+            Ok(certificate_usage) => Ok(certificate_usage),
+            Err(buffer) => Err(DecodeError::TLSACertificateUsage(buffer)),
+        }
+    }
+
+    pub(super) fn rr_tlsa_selector(&mut self) -> DecodeResult<TLSASelector> {
+        let buffer = self.u8()?;
+        match TLSASelector::try_from(buffer) {
+            Ok(selector) => Ok(selector),
+            Err(buffer) => Err(DecodeError::TLSASelector(buffer)),
+        }
+    }
+
+    pub(super) fn rr_tlsa_matching_type(&mut self) -> DecodeResult<TLSAMatchingType> {
+        let buffer = self.u8()?;
+        match TLSAMatchingType::try_from(buffer) {
+            Ok(matching_type) => Ok(matching_type),
+            Err(buffer) => Err(DecodeError::TLSAMatchingType(buffer)),
+        }
+    }
+
+    pub(super) fn rr_tlsa(&mut self, header: Header) -> DecodeResult<TLSA> {
+        let class = header.get_class()?;
+        let certificate_usage = self.rr_tlsa_certificate_usage()?;
+        let selector = self.rr_tlsa_selector()?;
+        let matching_type = self.rr_tlsa_matching_type()?;
+        let certificate_association_data = self.vec()?;
+        let tlsa = TLSA {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class,
+            certificate_usage,
+            selector,
+            matching_type,
+            certificate_association_data,
+        };
+        Ok(tlsa)
+    }
+}
@@ -0,0 +1,24 @@
+use super::Header;
+use crate::decode::Decoder;
+use crate::rr::SMIMEA;
+use crate::DecodeResult;
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    pub(super) fn rr_smimea(&mut self, header: Header) -> DecodeResult<SMIMEA> {
+        let class = header.get_class()?;
+        let certificate_usage = self.rr_tlsa_certificate_usage()?;
+        let selector = self.rr_tlsa_selector()?;
+        let matching_type = self.rr_tlsa_matching_type()?;
+        let certificate_association_data = self.vec()?;
+        let smimea = SMIMEA {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class,
+            certificate_usage,
+            selector,
+            matching_type,
+            certificate_association_data,
+        };
+        Ok(smimea)
+    }
+}
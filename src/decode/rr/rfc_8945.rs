@@ -0,0 +1,39 @@
+use super::Header;
+use crate::decode::Decoder;
+use crate::rr::{TSIG, TSIG_CLASS};
+use crate::{DecodeError, DecodeResult};
+
+impl<'a, 'b: 'a> Decoder<'a, 'b> {
+    fn rr_tsig_vec(&mut self) -> DecodeResult<Vec<u8>> {
+        let length = self.u16()?;
+        let buffer = self.read(length as usize)?;
+        Ok(buffer.to_vec())
+    }
+
+    pub(super) fn rr_tsig(&mut self, header: Header) -> DecodeResult<TSIG> {
+        if header.class != TSIG_CLASS {
+            return Err(DecodeError::TSIGClass(header.class));
+        }
+        if header.ttl != 0 {
+            return Err(DecodeError::TSIGTTL(header.ttl));
+        }
+        let algorithm_name = self.domain_name()?;
+        let time_signed = ((self.u16()? as u64) << 32) | self.u32()? as u64;
+        let fudge = self.u16()?;
+        let mac = self.rr_tsig_vec()?;
+        let original_id = self.u16()?;
+        let error = self.u16()?;
+        let other_data = self.rr_tsig_vec()?;
+        let tsig = TSIG {
+            domain_name: header.domain_name,
+            algorithm_name,
+            time_signed,
+            fudge,
+            mac,
+            original_id,
+            error,
+            other_data,
+        };
+        Ok(tsig)
+    }
+}
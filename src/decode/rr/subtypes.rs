@@ -1,5 +1,5 @@
 use crate::decode::Decoder;
-use crate::rr::{Address, AddressFamilyNumber};
+use crate::rr::{Address, AddressFamilyNumber, TypeBitMap};
 use crate::{DecodeError, DecodeResult};
 use std::convert::TryFrom;
 use std::mem::size_of;
@@ -51,4 +51,43 @@ impl<'a, 'b: 'a> Decoder<'a, 'b> {
             AddressFamilyNumber::Ipv6 => self.rr_address_ipv6(),
         }
     }
+
+    fn rr_type_bit_map_window_block(
+        &mut self,
+        window: u8,
+        types: &mut TypeBitMap,
+    ) -> DecodeResult<()> {
+        let length = self.u8()?;
+        if length == 0 || 32 < length {
+            return Err(DecodeError::TypeBitMapLength(length));
+        }
+        let bitmap = self.read(length as usize)?;
+        for (index, octet) in bitmap.iter().enumerate() {
+            for bit in 0..8 {
+                if octet & (0b1000_0000 >> bit) != 0 {
+                    types.insert_code((window as u16) << 8 | (index as u16) << 3 | bit);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode the [type bit map] until the end of the RDATA.
+    ///
+    /// [type bit map]: https://tools.ietf.org/html/rfc4034#section-4.1.2
+    pub(super) fn rr_type_bit_map(&mut self) -> DecodeResult<TypeBitMap> {
+        let mut types = TypeBitMap::new();
+        let mut previous_window = None;
+        while !self.is_finished()? {
+            let window = self.u8()?;
+            if let Some(previous_window) = previous_window {
+                if window <= previous_window {
+                    return Err(DecodeError::TypeBitMapWindowOrder(previous_window, window));
+                }
+            }
+            previous_window = Some(window);
+            self.rr_type_bit_map_window_block(window, &mut types)?;
+        }
+        Ok(types)
+    }
 }
@@ -0,0 +1,495 @@
+//! This module contains a borrowed view of a DNS message, which decodes the message lazily.
+//!
+//! [`DnsView::decode`] only checks the structure of the message: the header, the domain names of
+//! the questions and the resource records and the RDATA lengths. Nothing is allocated, the
+//! domain names are iterated over the original bytes and the compression pointers are followed.
+//! The questions and the resource records can be converted into the owned structs if needed.
+//!
+//! # Example
+//! ```rust
+//! use bytes::Bytes;
+//! use dns_message_parser::rr::Type;
+//! use dns_message_parser::view::DnsView;
+//!
+//! let msg = b"\xdb\x1c\x85\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+//! \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x0a\x00\
+//! \x00\x0a";
+//! let bytes = Bytes::copy_from_slice(&msg[..]);
+//! let dns = DnsView::decode(&bytes).unwrap();
+//!
+//! for rr in dns.answers() {
+//!     if rr.type_code() == Type::A as u16 {
+//!         // Prints "example.org. [10, 0, 0, 10]"
+//!         println!("{} {:?}", rr.domain_name(), rr.rdata());
+//!     }
+//! }
+//!
+//! // Decode the owned DNS message only if it is needed
+//! let dns = dns.to_dns().unwrap();
+//! println!("{}", dns);
+//! ```
+use super::domain_name::{get_offset, is_compressed};
+use super::Decoder;
+use crate::domain_name::{DOMAIN_NAME_MAX_LENGTH, DOMAIN_NAME_MAX_RECURSION};
+use crate::label::{fmt_label, LABEL_MAX_LENGTH};
+use crate::question::Question;
+use crate::rr::RR;
+use crate::{
+    DecodeError, DecodeResult, Dns, DomainName, DomainNameError, Flags, Label, LabelError, Opcode,
+    MAXIMUM_DNS_PACKET_SIZE,
+};
+use bytes::Bytes;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+fn u16_at(msg: &[u8], offset: usize) -> DecodeResult<u16> {
+    match msg.get(offset..offset + 2) {
+        Some(buffer) => Ok(u16::from_be_bytes([buffer[0], buffer[1]])),
+        None => Err(DecodeError::NotEnoughBytes(msg.len(), offset + 2)),
+    }
+}
+
+fn u32_at(msg: &[u8], offset: usize) -> DecodeResult<u32> {
+    match msg.get(offset..offset + 4) {
+        Some(buffer) => Ok(u32::from_be_bytes([
+            buffer[0], buffer[1], buffer[2], buffer[3],
+        ])),
+        None => Err(DecodeError::NotEnoughBytes(msg.len(), offset + 4)),
+    }
+}
+
+/// Returns `true` if the opcode in the header of the message is UPDATE.
+fn is_update(msg: &[u8]) -> bool {
+    match msg.get(2) {
+        Some(buffer) => Opcode::from((buffer & 0b0111_1000) >> 3) == Opcode::Update,
+        None => false,
+    }
+}
+
+/// Check the domain name, which starts at the offset, and return the offset after it.
+///
+/// The compression pointers are followed, so the same limits apply as for [`DomainName`].
+fn skip_domain_name(msg: &[u8], mut offset: usize) -> DecodeResult<usize> {
+    let mut end = None;
+    let mut domain_name_length = 0;
+    let mut recursions = 0;
+    loop {
+        let length = match msg.get(offset) {
+            Some(length) => *length,
+            None => return Err(DecodeError::NotEnoughBytes(msg.len(), offset + 1)),
+        };
+        if is_compressed(length) {
+            let pointer = match msg.get(offset + 1) {
+                Some(pointer) => *pointer,
+                None => return Err(DecodeError::NotEnoughBytes(msg.len(), offset + 2)),
+            };
+            end.get_or_insert(offset + 2);
+            recursions += 1;
+            if recursions > DOMAIN_NAME_MAX_RECURSION {
+                return Err(DecodeError::MaxRecursion(recursions));
+            }
+            offset = get_offset(length, pointer) as usize;
+        } else if length == 0 {
+            return Ok(end.unwrap_or(offset + 1));
+        } else {
+            let length = length as usize;
+            if LABEL_MAX_LENGTH <= length {
+                return Err(LabelError::Length(length).into());
+            }
+            offset += length + 1;
+            if offset > msg.len() {
+                return Err(DecodeError::NotEnoughBytes(msg.len(), offset));
+            }
+            domain_name_length += length + 1;
+            if DOMAIN_NAME_MAX_LENGTH <= domain_name_length {
+                return Err(DomainNameError::DomainNameLength(domain_name_length).into());
+            }
+        }
+    }
+}
+
+/// A borrowed view of a domain name inside of a DNS message.
+#[derive(Debug, Clone, Copy)]
+pub struct NameView<'a> {
+    msg: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> NameView<'a> {
+    /// Returns an iterator over the raw bytes of the labels, the compression pointers are
+    /// followed.
+    pub fn labels(&self) -> LabelIter<'a> {
+        LabelIter {
+            msg: self.msg,
+            offset: self.offset,
+            recursions: 0,
+        }
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.labels().next().is_none()
+    }
+
+    /// Convert the view into an owned [`DomainName`].
+    pub fn to_domain_name(&self) -> DecodeResult<DomainName> {
+        let mut domain_name = DomainName::default();
+        for label in self.labels() {
+            domain_name.append_label(Label::try_from(label)?)?;
+        }
+        Ok(domain_name)
+    }
+}
+
+impl<'a> PartialEq<DomainName> for NameView<'a> {
+    fn eq(&self, other: &DomainName) -> bool {
+        let mut labels = self.labels();
+        for label in other.0.iter() {
+            match labels.next() {
+                Some(bytes) if label.0.eq_ignore_ascii_case(bytes) => {}
+                _ => return false,
+            }
+        }
+        labels.next().is_none()
+    }
+}
+
+impl<'a> Display for NameView<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.is_root() {
+            return write!(f, ".");
+        }
+        for label in self.labels() {
+            fmt_label(f, label)?;
+            write!(f, ".")?;
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the labels of a [`NameView`].
+#[derive(Debug, Clone)]
+pub struct LabelIter<'a> {
+    msg: &'a [u8],
+    offset: usize,
+    recursions: usize,
+}
+
+impl<'a> Iterator for LabelIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The domain name was checked by skip_domain_name, therefore the errors are not reported.
+        loop {
+            let length = *self.msg.get(self.offset)?;
+            if is_compressed(length) {
+                self.recursions += 1;
+                if self.recursions > DOMAIN_NAME_MAX_RECURSION {
+                    return None;
+                }
+                let pointer = *self.msg.get(self.offset + 1)?;
+                self.offset = get_offset(length, pointer) as usize;
+            } else if length == 0 {
+                return None;
+            } else {
+                let start = self.offset + 1;
+                let label = self.msg.get(start..start + length as usize)?;
+                self.offset = start + length as usize;
+                return Some(label);
+            }
+        }
+    }
+}
+
+/// A borrowed view of a question.
+#[derive(Debug, Clone, Copy)]
+pub struct QuestionView<'a> {
+    bytes: &'a Bytes,
+    offset: usize,
+    q_type_code: u16,
+    q_class_code: u16,
+}
+
+impl<'a> QuestionView<'a> {
+    /// Parse the question, which starts at the offset, and return the offset after it.
+    pub(super) fn parse(
+        bytes: &'a Bytes,
+        offset: usize,
+    ) -> DecodeResult<(QuestionView<'a>, usize)> {
+        let msg = bytes.as_ref();
+        let domain_name_end = skip_domain_name(msg, offset)?;
+        let q_type_code = u16_at(msg, domain_name_end)?;
+        let q_class_code = u16_at(msg, domain_name_end + 2)?;
+        let question = QuestionView {
+            bytes,
+            offset,
+            q_type_code,
+            q_class_code,
+        };
+        Ok((question, domain_name_end + 4))
+    }
+
+    pub fn domain_name(&self) -> NameView<'a> {
+        NameView {
+            msg: self.bytes.as_ref(),
+            offset: self.offset,
+        }
+    }
+
+    pub fn q_type_code(&self) -> u16 {
+        self.q_type_code
+    }
+
+    pub fn q_class_code(&self) -> u16 {
+        self.q_class_code
+    }
+
+    /// Decode the owned [`Question`].
+    pub fn to_question(&self) -> DecodeResult<Question> {
+        let mut decoder = Decoder::main(self.bytes.clone());
+        decoder.offset = self.offset;
+        decoder.question()
+    }
+}
+
+/// A borrowed view of a resource record.
+#[derive(Debug, Clone, Copy)]
+pub struct RRView<'a> {
+    bytes: &'a Bytes,
+    offset: usize,
+    type_code: u16,
+    class_code: u16,
+    ttl: u32,
+    rdata_offset: usize,
+    rdata_length: usize,
+}
+
+impl<'a> RRView<'a> {
+    /// Parse the resource record, which starts at the offset, and return the offset after it.
+    pub(super) fn parse(bytes: &'a Bytes, offset: usize) -> DecodeResult<(RRView<'a>, usize)> {
+        let msg = bytes.as_ref();
+        let domain_name_end = skip_domain_name(msg, offset)?;
+        let type_code = u16_at(msg, domain_name_end)?;
+        let class_code = u16_at(msg, domain_name_end + 2)?;
+        let ttl = u32_at(msg, domain_name_end + 4)?;
+        let rdata_length = u16_at(msg, domain_name_end + 8)? as usize;
+        let rdata_offset = domain_name_end + 10;
+        let end = rdata_offset + rdata_length;
+        if end > msg.len() {
+            return Err(DecodeError::NotEnoughBytes(msg.len(), end));
+        }
+        let rr = RRView {
+            bytes,
+            offset,
+            type_code,
+            class_code,
+            ttl,
+            rdata_offset,
+            rdata_length,
+        };
+        Ok((rr, end))
+    }
+
+    pub fn domain_name(&self) -> NameView<'a> {
+        NameView {
+            msg: self.bytes.as_ref(),
+            offset: self.offset,
+        }
+    }
+
+    pub fn type_code(&self) -> u16 {
+        self.type_code
+    }
+
+    pub fn class_code(&self) -> u16 {
+        self.class_code
+    }
+
+    /// Returns the raw TTL field, which contains the extended RCODE and flags in OPT records.
+    pub fn ttl(&self) -> u32 {
+        self.ttl
+    }
+
+    /// Returns the raw RDATA, the domain names inside of the RDATA can be compressed.
+    pub fn rdata(&self) -> &'a [u8] {
+        &self.bytes.as_ref()[self.rdata_offset..self.rdata_offset + self.rdata_length]
+    }
+
+    /// Decode the owned [`RR`].
+    pub fn to_rr(&self) -> DecodeResult<RR> {
+        let mut decoder = Decoder::main(self.bytes.clone());
+        decoder.offset = self.offset;
+        decoder.update = is_update(self.bytes.as_ref());
+        decoder.rr()
+    }
+}
+
+/// An iterator over the questions of a [`DnsView`].
+#[derive(Debug, Clone)]
+pub struct QuestionViewIter<'a> {
+    bytes: &'a Bytes,
+    offset: usize,
+    remaining: u16,
+}
+
+impl<'a> Iterator for QuestionViewIter<'a> {
+    type Item = QuestionView<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // The questions were checked by DnsView::decode, therefore the errors are not reported.
+        let (question, end) = QuestionView::parse(self.bytes, self.offset).ok()?;
+        self.offset = end;
+        self.remaining -= 1;
+        Some(question)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
+}
+
+impl<'a> ExactSizeIterator for QuestionViewIter<'a> {}
+
+/// An iterator over the resource records of a section of a [`DnsView`].
+#[derive(Debug, Clone)]
+pub struct RRViewIter<'a> {
+    bytes: &'a Bytes,
+    offset: usize,
+    remaining: u16,
+}
+
+impl<'a> Iterator for RRViewIter<'a> {
+    type Item = RRView<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // The resource records were checked by DnsView::decode, therefore the errors are not
+        // reported.
+        let (rr, end) = RRView::parse(self.bytes, self.offset).ok()?;
+        self.offset = end;
+        self.remaining -= 1;
+        Some(rr)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
+}
+
+impl<'a> ExactSizeIterator for RRViewIter<'a> {}
+
+/// The offset of the first entry and the number of entries of a section.
+#[derive(Debug, Clone, Copy)]
+struct SectionRange {
+    offset: usize,
+    count: u16,
+}
+
+/// A borrowed view of a DNS message.
+#[derive(Debug, Clone)]
+pub struct DnsView<'a> {
+    bytes: &'a Bytes,
+    id: u16,
+    flags: Flags,
+    questions: SectionRange,
+    answers: SectionRange,
+    authorities: SectionRange,
+    additionals: SectionRange,
+}
+
+impl<'a> DnsView<'a> {
+    /// Check the structure of the DNS message and create a view of it.
+    pub fn decode(bytes: &'a Bytes) -> DecodeResult<DnsView<'a>> {
+        let bytes_len = bytes.len();
+        if bytes_len < 12 {
+            return Err(DecodeError::NotEnoughBytes(bytes_len, 12));
+        } else if bytes_len > MAXIMUM_DNS_PACKET_SIZE {
+            return Err(DecodeError::DnsPacketTooBig(bytes_len));
+        }
+
+        let mut decoder = Decoder::main(bytes.clone());
+        let id = decoder.u16()?;
+        let flags = decoder.flags()?;
+        let question_count = decoder.u16()?;
+        let answer_count = decoder.u16()?;
+        let authority_count = decoder.u16()?;
+        let additional_count = decoder.u16()?;
+
+        let mut offset = decoder.offset;
+        let questions = SectionRange {
+            offset,
+            count: question_count,
+        };
+        for _ in 0..question_count {
+            offset = QuestionView::parse(bytes, offset)?.1;
+        }
+        let mut rr_section = |count: u16| -> DecodeResult<SectionRange> {
+            let section = SectionRange { offset, count };
+            for _ in 0..count {
+                offset = RRView::parse(bytes, offset)?.1;
+            }
+            Ok(section)
+        };
+        let answers = rr_section(answer_count)?;
+        let authorities = rr_section(authority_count)?;
+        let additionals = rr_section(additional_count)?;
+        if offset != bytes_len {
+            return Err(DecodeError::TooManyBytes(bytes_len, offset));
+        }
+
+        Ok(DnsView {
+            bytes,
+            id,
+            flags,
+            questions,
+            answers,
+            authorities,
+            additionals,
+        })
+    }
+
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    pub fn flags(&self) -> &Flags {
+        &self.flags
+    }
+
+    pub fn questions(&self) -> QuestionViewIter<'a> {
+        QuestionViewIter {
+            bytes: self.bytes,
+            offset: self.questions.offset,
+            remaining: self.questions.count,
+        }
+    }
+
+    fn rr_iter(&self, section: SectionRange) -> RRViewIter<'a> {
+        RRViewIter {
+            bytes: self.bytes,
+            offset: section.offset,
+            remaining: section.count,
+        }
+    }
+
+    pub fn answers(&self) -> RRViewIter<'a> {
+        self.rr_iter(self.answers)
+    }
+
+    pub fn authorities(&self) -> RRViewIter<'a> {
+        self.rr_iter(self.authorities)
+    }
+
+    pub fn additionals(&self) -> RRViewIter<'a> {
+        self.rr_iter(self.additionals)
+    }
+
+    /// Decode the owned [`Dns`] message.
+    pub fn to_dns(&self) -> DecodeResult<Dns> {
+        Dns::decode(self.bytes.clone())
+    }
+}
@@ -1,8 +1,15 @@
 use crate::question::Question;
-use crate::rr::RR;
+use crate::rr::{OPT, RR};
 use crate::{Opcode, RCode};
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+/// The requestor's UDP payload size of an OPT record, which is created by [`Dns::set_rcode`].
+pub const EDNS_DEFAULT_PAYLOAD_SIZE: u16 = 1232;
+
+/// The mask of the lower 4 bits of the RCODE, which are stored in the header.
+const RCODE_HEADER_MASK: u8 = 0b0000_1111;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Flags {
     pub qr: bool,
@@ -22,7 +29,7 @@ impl Display for Flags {
             write!(f, "qr ")?;
         }
 
-        write!(f, "{:?} ", self.opcode)?;
+        write!(f, "{} ", self.opcode)?;
 
         if self.aa {
             write!(f, "aa ")?;
@@ -48,7 +55,7 @@ impl Display for Flags {
             write!(f, "cd ")?;
         }
 
-        write!(f, "{:?}", self.rcode)
+        write!(f, "{}", self.rcode)
     }
 }
 
@@ -67,6 +74,77 @@ impl Dns {
     pub const fn is_response(&self) -> bool {
         self.flags.qr
     }
+
+    /// Returns the [extended RCODE], which consists of the 4 bits of the header and the upper 8
+    /// bits of the OPT record.
+    ///
+    /// If `flags.rcode` is already an extended RCODE then it is returned. If the RCODE is unknown
+    /// then its value is returned as error.
+    ///
+    /// [extended RCODE]: https://tools.ietf.org/html/rfc6891#section-6.1.3
+    pub fn rcode(&self) -> Result<RCode, u16> {
+        let header_rcode = u8::from(self.flags.rcode);
+        if header_rcode > RCODE_HEADER_MASK {
+            return Ok(self.flags.rcode);
+        }
+
+        let extend_rcode = self
+            .additionals
+            .iter()
+            .find_map(|rr| match rr {
+                RR::OPT(opt) => Some(opt.extend_rcode),
+                _ => None,
+            })
+            .unwrap_or(0);
+        let rcode = ((extend_rcode as u16) << 4) | header_rcode as u16;
+        match u8::try_from(rcode) {
+            Ok(buffer) => match RCode::from(buffer) {
+                RCode::Unknown(_) => Err(rcode),
+                extended_rcode => Ok(extended_rcode),
+            },
+            Err(_) => Err(rcode),
+        }
+    }
+
+    /// Set the [extended RCODE].
+    ///
+    /// The lower 4 bits are stored in the header and the upper 8 bits in the OPT record. If the
+    /// upper bits are not zero and there is no OPT record then an OPT record is added.
+    ///
+    /// [extended RCODE]: https://tools.ietf.org/html/rfc6891#section-6.1.3
+    pub fn set_rcode(&mut self, rcode: RCode) {
+        let rcode = u8::from(rcode);
+        self.flags.rcode = RCode::from(rcode & RCODE_HEADER_MASK);
+        set_extend_rcode(&mut self.additionals, rcode >> 4);
+    }
+}
+
+/// Set the upper 8 bits of the RCODE in the first OPT record. If there is no OPT record and the
+/// bits are not zero then an OPT record is added before a TSIG record, which must be the last
+/// record (RFC 8945 Section 5.1).
+pub(crate) fn set_extend_rcode(additionals: &mut Vec<RR>, extend_rcode: u8) {
+    let opt = additionals.iter_mut().find_map(|rr| match rr {
+        RR::OPT(opt) => Some(opt),
+        _ => None,
+    });
+    match opt {
+        Some(opt) => opt.extend_rcode = extend_rcode,
+        None if extend_rcode != 0 => {
+            let index = match additionals.last() {
+                Some(RR::TSIG(_)) => additionals.len() - 1,
+                _ => additionals.len(),
+            };
+            let opt = RR::OPT(OPT {
+                requestor_payload_size: EDNS_DEFAULT_PAYLOAD_SIZE,
+                extend_rcode,
+                version: 0,
+                dnssec: false,
+                edns_options: Vec::new(),
+            });
+            additionals.insert(index, opt);
+        }
+        None => {}
+    }
 }
 
 #[inline]
@@ -0,0 +1,350 @@
+//! Helpers for [DNSSEC], which derive a DS resource record from a DNSKEY resource record and
+//! verify the RRSIG of an RRset, see [`verify_rrsig`].
+//!
+//! The key tag of a DNSKEY resource record ([`DNSKEY::key_tag`]) and the canonical order of an
+//! RRset ([`sort_rrset`]) do not need the feature `dnssec`.
+//!
+//! # Example
+//! ```
+//! # use dns_message_parser::rr::{DigestType, RR};
+//! let rr: RR = "dskey.example.com. 86400 IN DNSKEY 256 3 5 \
+//!     AQOeiiR0GOMYkDshWoSKz9XzfwJr1AYtsmx3TGkJaNXVbfi/2pHm822aJ5iI9BMzNXxeYCmZDRD99WYwYqUSdjMm\
+//!     mAphXdvxegXd/M5+X7OrzKBaMbCVdFLUUh6DhweJBjEVv5f2wwjM9XzcnOf+EPbtG9DMBmADjFDc2w/rljwvFw=="
+//!     .parse()
+//!     .unwrap();
+//! let dnskey = match rr {
+//!     RR::DNSKEY(dnskey) => dnskey,
+//!     _ => unreachable!(),
+//! };
+//! assert_eq!(dnskey.key_tag(), 60485);
+//! let ds = dnskey.to_ds(DigestType::Sha1).unwrap();
+//! assert_eq!(ds.key_tag, 60485);
+//! ```
+//!
+//! [DNSSEC]: https://tools.ietf.org/html/rfc4033
+pub use crate::rr::sort_rrset;
+use crate::rr::{AlgorithmType, DigestType, DNSKEY, DS, RR, RRSIG};
+use crate::{DomainName, EncodeError};
+use bytes::BytesMut;
+use ring::digest::{digest, SHA1_FOR_LEGACY_USE_ONLY, SHA256, SHA384};
+use ring::signature::{
+    RsaPublicKeyComponents, UnparsedPublicKey, VerificationAlgorithm, ECDSA_P256_SHA256_FIXED,
+    ECDSA_P384_SHA384_FIXED, ED25519, RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
+};
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum DNSSECError {
+    #[error("Could not encode the resource record: {0}")]
+    EncodeError(#[from] EncodeError),
+    #[error("The digest type is not supported: {0}")]
+    DigestType(DigestType),
+    #[error("The RRset is empty")]
+    RRsetEmpty,
+    #[error("The resource record is not covered by the RRSIG: {0}")]
+    RRsetCovered(Box<RR>),
+    #[error("The signer's name {0} does not match the owner name of the DNSKEY {1}")]
+    SignerName(DomainName, DomainName),
+    #[error("The owner name {0} is not in the zone of the signer's name {1}")]
+    SignerZone(DomainName, DomainName),
+    #[error("The algorithm of the RRSIG {0} does not match the algorithm of the DNSKEY {1}")]
+    AlgorithmTypeMismatch(u8, u8),
+    #[error("The algorithm is not supported: {0}")]
+    AlgorithmType(AlgorithmType),
+    #[error("The key tag of the RRSIG {0} does not match the key tag of the DNSKEY {1}")]
+    KeyTag(u16, u16),
+    #[error("The DNSKEY is not a zone key")]
+    ZoneKey,
+    #[error("The signature is not valid before {0}: {1}")]
+    SignatureInception(u32, u32),
+    #[error("The signature is not valid after {0}: {1}")]
+    SignatureExpiration(u32, u32),
+    #[error("The labels field {0} is greater than the number of labels of the owner name {1}")]
+    Labels(u8, usize),
+    #[error("The public key is malformed")]
+    PublicKey,
+    #[error("The signature is not valid")]
+    Signature,
+}
+
+pub type DNSSECResult<T> = Result<T, DNSSECError>;
+
+impl DNSKEY {
+    /// Derive the DS resource record with the digest type, see [RFC 4034 Section 5.1.4].
+    ///
+    /// The digest types SHA-1, SHA-256 and SHA-384 are supported.
+    ///
+    /// [RFC 4034 Section 5.1.4]: https://tools.ietf.org/html/rfc4034#section-5.1.4
+    pub fn to_ds(&self, digest_type: DigestType) -> DNSSECResult<DS> {
+        let data = self.encode_ds_data()?;
+        let algorithm = match digest_type {
+            DigestType::Sha1 => &SHA1_FOR_LEGACY_USE_ONLY,
+            DigestType::Sha256 => &SHA256,
+            DigestType::Sha384 => &SHA384,
+            digest_type => return Err(DNSSECError::DigestType(digest_type)),
+        };
+        let digest = digest(algorithm, &data).as_ref().to_vec();
+        let ds = DS {
+            domain_name: self.domain_name.clone(),
+            ttl: self.ttl,
+            class: self.class,
+            key_tag: self.key_tag(),
+            algorithm_type: self.algorithm_type,
+            digest_type,
+            digest,
+        };
+        Ok(ds)
+    }
+}
+
+/// Returns `true` if the timestamp `t_1` is before the timestamp `t_2` in the
+/// [serial number arithmetic].
+///
+/// [serial number arithmetic]: https://tools.ietf.org/html/rfc1982
+fn is_before(t_1: u32, t_2: u32) -> bool {
+    (t_2.wrapping_sub(t_1) as i32) > 0
+}
+
+fn check_rrset<'a>(rrset: &'a [RR], rrsig: &RRSIG) -> DNSSECResult<&'a DomainName> {
+    let domain_name = match rrset.first().and_then(|rr| rr.get_domain_name()) {
+        Some(domain_name) => domain_name,
+        None => return Err(DNSSECError::RRsetEmpty),
+    };
+    for rr in rrset {
+        let is_covered = rr.get_domain_name() == Some(&rrsig.domain_name)
+            && rr.get_class() == Some(rrsig.class)
+            && rr.get_type_code() == rrsig.type_covered;
+        if !is_covered {
+            return Err(DNSSECError::RRsetCovered(Box::new(rr.clone())));
+        }
+    }
+    Ok(domain_name)
+}
+
+fn check_dnskey(domain_name: &DomainName, rrsig: &RRSIG, dnskey: &DNSKEY) -> DNSSECResult<()> {
+    if rrsig.signer_name != dnskey.domain_name {
+        return Err(DNSSECError::SignerName(
+            rrsig.signer_name.clone(),
+            dnskey.domain_name.clone(),
+        ));
+    }
+    if !domain_name.0.ends_with(&rrsig.signer_name.0) {
+        return Err(DNSSECError::SignerZone(
+            domain_name.clone(),
+            rrsig.signer_name.clone(),
+        ));
+    }
+    if rrsig.algorithm_type != dnskey.algorithm_type as u8 {
+        return Err(DNSSECError::AlgorithmTypeMismatch(
+            rrsig.algorithm_type,
+            dnskey.algorithm_type as u8,
+        ));
+    }
+    let key_tag = dnskey.key_tag();
+    if rrsig.key_tag != key_tag {
+        return Err(DNSSECError::KeyTag(rrsig.key_tag, key_tag));
+    }
+    if !dnskey.zone_key_flag {
+        return Err(DNSSECError::ZoneKey);
+    }
+    Ok(())
+}
+
+fn check_validity_period(rrsig: &RRSIG, now: u64) -> DNSSECResult<()> {
+    // The timestamps are compared in the serial number arithmetic, see RFC 4034 Section 3.1.5.
+    let now = now as u32;
+    if is_before(now, rrsig.signature_inception) {
+        return Err(DNSSECError::SignatureInception(
+            rrsig.signature_inception,
+            now,
+        ));
+    }
+    if is_before(rrsig.signature_expiration, now) {
+        return Err(DNSSECError::SignatureExpiration(
+            rrsig.signature_expiration,
+            now,
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the TTL of the authenticated RRset, which is the minimum of the TTLs of the RRset and
+/// the RRSIG, the original TTL and the remaining validity period of the signature, see
+/// [RFC 4035 Section 5.3.3].
+///
+/// [RFC 4035 Section 5.3.3]: https://tools.ietf.org/html/rfc4035#section-5.3.3
+fn capped_ttl(rrset: &[RR], rrsig: &RRSIG, now: u64) -> u32 {
+    // The validity period was already checked.
+    let remaining = rrsig.signature_expiration.wrapping_sub(now as u32);
+    rrset
+        .iter()
+        .filter_map(|rr| rr.get_ttl())
+        .fold(rrsig.ttl.min(rrsig.original_ttl).min(remaining), u32::min)
+}
+
+/// Returns the owner name, which was used to create the signature. If the RRset was synthesized
+/// from a wildcard, then this is the wildcard owner name, see [RFC 4035 Section 5.3.2].
+///
+/// [RFC 4035 Section 5.3.2]: https://tools.ietf.org/html/rfc4035#section-5.3.2
+fn signed_domain_name(domain_name: &DomainName, labels: u8) -> DNSSECResult<DomainName> {
+    let mut domain_name_labels = domain_name.0.as_slice();
+    let domain_name_labels_count = match domain_name_labels.first() {
+        Some(label) if label == &"*" => domain_name_labels.len() - 1,
+        _ => domain_name_labels.len(),
+    };
+    let labels = labels as usize;
+    if labels > domain_name_labels_count {
+        return Err(DNSSECError::Labels(labels as u8, domain_name_labels_count));
+    }
+    if labels == domain_name_labels.len() {
+        return Ok(domain_name.clone());
+    }
+
+    domain_name_labels = &domain_name_labels[domain_name_labels.len() - labels..];
+    let mut wildcard_domain_name = DomainName::default();
+    // The wildcard label cannot exceed the maximum length, because it replaces at least one label.
+    let _ = wildcard_domain_name.append_label("*".parse().unwrap());
+    for label in domain_name_labels {
+        let _ = wildcard_domain_name.append_label(label.clone());
+    }
+    Ok(wildcard_domain_name)
+}
+
+/// Returns the data, which is signed by the RRSIG, see [RFC 4034 Section 3.1.8.1].
+///
+/// [RFC 4034 Section 3.1.8.1]: https://tools.ietf.org/html/rfc4034#section-3.1.8.1
+fn signed_data(rrset: &[RR], rrsig: &RRSIG, domain_name: &DomainName) -> DNSSECResult<BytesMut> {
+    let mut rdatas = Vec::with_capacity(rrset.len());
+    for rr in rrset {
+        rdatas.push(rr.encode_canonical_rdata()?);
+    }
+    rdatas.sort();
+    rdatas.dedup();
+
+    let domain_name = domain_name.encode_canonical()?;
+    let mut data = rrsig.encode_signed_rdata()?;
+    for rdata in rdatas {
+        data.extend_from_slice(&domain_name);
+        data.extend_from_slice(&rrsig.type_covered.to_be_bytes());
+        data.extend_from_slice(&(rrsig.class as u16).to_be_bytes());
+        data.extend_from_slice(&rrsig.original_ttl.to_be_bytes());
+        // The length of the RDATA was already checked by the encoder.
+        data.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        data.extend_from_slice(&rdata);
+    }
+    Ok(data)
+}
+
+/// Parse the RSA public key, which is encoded as described in [RFC 3110 Section 2], into the
+/// exponent and the modulus.
+///
+/// [RFC 3110 Section 2]: https://tools.ietf.org/html/rfc3110#section-2
+fn rsa_public_key(public_key: &[u8]) -> DNSSECResult<(&[u8], &[u8])> {
+    let (exponent_length, public_key) = match public_key {
+        [0, b_1, b_2, public_key @ ..] => (((*b_1 as usize) << 8) | *b_2 as usize, public_key),
+        [b, public_key @ ..] if *b != 0 => (*b as usize, public_key),
+        _ => return Err(DNSSECError::PublicKey),
+    };
+    if exponent_length == 0 || public_key.len() <= exponent_length {
+        return Err(DNSSECError::PublicKey);
+    }
+    Ok(public_key.split_at(exponent_length))
+}
+
+fn verify_unparsed(
+    algorithm: &'static dyn VerificationAlgorithm,
+    public_key: &[u8],
+    data: &[u8],
+    signature: &[u8],
+) -> DNSSECResult<()> {
+    UnparsedPublicKey::new(algorithm, public_key)
+        .verify(data, signature)
+        .map_err(|_| DNSSECError::Signature)
+}
+
+/// Prepend the uncompressed point marker to the ECDSA public key, which contains only the
+/// coordinates, see [RFC 6605 Section 4].
+///
+/// [RFC 6605 Section 4]: https://tools.ietf.org/html/rfc6605#section-4
+fn ecdsa_public_key(public_key: &[u8], length: usize) -> DNSSECResult<Vec<u8>> {
+    if public_key.len() != length {
+        return Err(DNSSECError::PublicKey);
+    }
+    let mut ecdsa_public_key = Vec::with_capacity(length + 1);
+    ecdsa_public_key.push(0x04);
+    ecdsa_public_key.extend_from_slice(public_key);
+    Ok(ecdsa_public_key)
+}
+
+fn verify_signature(
+    algorithm_type: AlgorithmType,
+    public_key: &[u8],
+    data: &[u8],
+    signature: &[u8],
+) -> DNSSECResult<()> {
+    match algorithm_type {
+        AlgorithmType::RsaSha256 => {
+            let (e, n) = rsa_public_key(public_key)?;
+            RsaPublicKeyComponents { n, e }
+                .verify(
+                    &RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
+                    data,
+                    signature,
+                )
+                .map_err(|_| DNSSECError::Signature)
+        }
+        AlgorithmType::EcDsaP256 => {
+            let public_key = ecdsa_public_key(public_key, 64)?;
+            verify_unparsed(&ECDSA_P256_SHA256_FIXED, &public_key, data, signature)
+        }
+        AlgorithmType::EcDsaP386 => {
+            let public_key = ecdsa_public_key(public_key, 96)?;
+            verify_unparsed(&ECDSA_P384_SHA384_FIXED, &public_key, data, signature)
+        }
+        AlgorithmType::Ed25519 => {
+            if public_key.len() != 32 {
+                return Err(DNSSECError::PublicKey);
+            }
+            verify_unparsed(&ED25519, public_key, data, signature)
+        }
+        algorithm_type => Err(DNSSECError::AlgorithmType(algorithm_type)),
+    }
+}
+
+/// Verify the RRSIG of the RRset with the DNSKEY at the time `now` in seconds since the UNIX
+/// epoch, see [RFC 4035 Section 5.3].
+///
+/// The following checks are done:
+/// * all resource records of the RRset have the owner name, the class and the type, which are
+///   covered by the RRSIG,
+/// * the signer's name is the owner name of the DNSKEY and the owner name is in its zone,
+/// * the algorithm and the key tag match the DNSKEY, which must be a zone key,
+/// * `now` is within the validity period of the signature,
+/// * the labels field is not greater than the number of labels of the owner name, and if it is
+///   less, then the signature is verified over the wildcard owner name,
+/// * the signature is valid.
+///
+/// On success, the TTL is returned, which the caller must use for the RRset and the RRSIG. It is
+/// not greater than the TTLs of the RRset and the RRSIG, the original TTL and the remaining
+/// validity period of the signature, see [RFC 4035 Section 5.3.3].
+///
+/// The algorithms RSA/SHA-256, ECDSA P-256 with SHA-256, ECDSA P-384 with SHA-384 and Ed25519 are
+/// supported.
+///
+/// [RFC 4035 Section 5.3]: https://tools.ietf.org/html/rfc4035#section-5.3
+/// [RFC 4035 Section 5.3.3]: https://tools.ietf.org/html/rfc4035#section-5.3.3
+pub fn verify_rrsig(rrset: &[RR], rrsig: &RRSIG, dnskey: &DNSKEY, now: u64) -> DNSSECResult<u32> {
+    let domain_name = check_rrset(rrset, rrsig)?;
+    check_dnskey(domain_name, rrsig, dnskey)?;
+    check_validity_period(rrsig, now)?;
+    let domain_name = signed_domain_name(domain_name, rrsig.labels)?;
+    let data = signed_data(rrset, rrsig, &domain_name)?;
+    // The algorithm of the RRSIG was checked against the algorithm of the DNSKEY.
+    verify_signature(
+        dnskey.algorithm_type,
+        &dnskey.public_key,
+        &data,
+        &rrsig.signature,
+    )?;
+    Ok(capped_ttl(rrset, rrsig, now))
+}
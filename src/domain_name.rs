@@ -1,5 +1,6 @@
 use crate::{Label, LabelError};
 use std::{
+    cmp::Ordering,
     fmt::{Display, Formatter, Result as FmtResult},
     hash::Hash,
     str::FromStr,
@@ -75,20 +76,79 @@ impl DomainName {
     pub fn is_root(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Compare the domain names in the [canonical order].
+    ///
+    /// The labels are compared from the rightmost label as lowercase octet strings and a domain
+    /// name sorts before all of its subdomains.
+    ///
+    /// # Example
+    /// ```
+    /// # use dns_message_parser::DomainName;
+    /// # use std::cmp::Ordering;
+    /// let domain_name_1: DomainName = "z.example".parse().unwrap();
+    /// let domain_name_2: DomainName = "a.Z.example".parse().unwrap();
+    /// assert_eq!(domain_name_1.canonical_cmp(&domain_name_2), Ordering::Less);
+    /// ```
+    ///
+    /// [canonical order]: https://tools.ietf.org/html/rfc4034#section-6.1
+    pub fn canonical_cmp(&self, other: &DomainName) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl Ord for DomainName {
+    /// The domain names are ordered in the canonical order, see [`DomainName::canonical_cmp`].
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.canonical_cmp(other)
+    }
+}
+
+impl PartialOrd for DomainName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Split the text of a domain name at the dots, which are not escaped by a backslash.
+fn split_labels(string: &str) -> Vec<&str> {
+    let mut labels = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, b) in string.bytes().enumerate() {
+        if escaped {
+            escaped = false;
+        } else if b == b'\\' {
+            escaped = true;
+        } else if b == b'.' {
+            labels.push(&string[start..index]);
+            start = index + 1;
+        }
+    }
+    labels.push(&string[start..]);
+    labels
 }
 
 impl FromStr for DomainName {
     type Err = DomainNameError;
 
+    /// Parse the presentation format of a domain name.
+    ///
+    /// The labels are separated by dots, which are not escaped. The escape sequences `\X` and
+    /// `\DDD` of [RFC 1035 Section 5.1] are resolved.
+    ///
+    /// [RFC 1035 Section 5.1]: https://tools.ietf.org/html/rfc1035#section-5.1
     fn from_str(string: &str) -> Result<Self, <Self as FromStr>::Err> {
-        let string_relativ = if let Some(string_relativ) = string.strip_suffix('.') {
-            string_relativ
-        } else {
-            string
-        };
-
         let mut domain_name = DomainName::default();
-        for label in string_relativ.split('.') {
+        if string == "." {
+            return Ok(domain_name);
+        }
+
+        let mut labels = split_labels(string);
+        if labels.len() > 1 && labels.last() == Some(&"") {
+            labels.pop();
+        }
+        for label in labels {
             let label = label.parse()?;
             domain_name.append_label(label)?;
         }
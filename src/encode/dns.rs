@@ -1,5 +1,83 @@
+use crate::dns::set_extend_rcode;
 use crate::encode::Encoder;
-use crate::{Dns, EncodeResult, Flags};
+use crate::rr::{Class, RR};
+use crate::{Dns, DomainName, EncodeError, EncodeResult, Flags};
+use bytes::BytesMut;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// The resource records, which were omitted by [`Dns::encode_with_limit`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Omitted {
+    pub answers: Vec<RR>,
+    pub authorities: Vec<RR>,
+    pub additionals: Vec<RR>,
+}
+
+impl Omitted {
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty() && self.authorities.is_empty() && self.additionals.is_empty()
+    }
+}
+
+/// Returns the type, which is covered by the RRSIG record.
+fn rrsig_type_covered(rr: &RR) -> Option<u16> {
+    match rr {
+        RR::RRSIG(rrsig) => Some(rrsig.type_covered),
+        _ => None,
+    }
+}
+
+/// The resource records of an RRset have the same domain name, class and type. An RRSIG record
+/// belongs to the RRset, which it covers.
+fn rrset_key(rr: &RR) -> (Option<&DomainName>, Option<Class>, u16) {
+    let type_code = rrsig_type_covered(rr).unwrap_or_else(|| rr.get_type_code());
+    (rr.get_domain_name(), rr.get_class(), type_code)
+}
+
+/// Returns the index of the RRset of each resource record, the RRsets are numbered in the order
+/// of their first record.
+fn rrset_indices(rrs: &[RR]) -> Vec<usize> {
+    let mut keys = Vec::new();
+    rrs.iter()
+        .map(|rr| {
+            let key = rrset_key(rr);
+            match keys.iter().position(|k| *k == key) {
+                Some(index) => index,
+                None => {
+                    keys.push(key);
+                    keys.len() - 1
+                }
+            }
+        })
+        .collect()
+}
+
+#[inline]
+fn is_opt(rr: &RR) -> bool {
+    matches!(rr, RR::OPT(_))
+}
+
+/// Returns the additional records, where the upper bits of an extended RCODE in the flags are
+/// stored in the OPT record.
+fn additionals(dns: &Dns) -> Cow<'_, [RR]> {
+    let extend_rcode = u8::from(dns.flags.rcode) >> 4;
+    if extend_rcode == 0 {
+        return Cow::Borrowed(&dns.additionals);
+    }
+
+    let mut additionals = dns.additionals.clone();
+    set_extend_rcode(&mut additionals, extend_rcode);
+    Cow::Owned(additionals)
+}
+
+/// The state of the encoding with a limit.
+struct Limit {
+    max: usize,
+    /// The size of the OPT records, which are not encoded yet.
+    reserved: usize,
+    truncated: bool,
+}
 
 impl Encoder {
     pub(super) fn flags(&mut self, flags: &Flags) {
@@ -7,8 +85,8 @@ impl Encoder {
         if flags.qr {
             buffer |= 0b1000_0000;
         }
-        let opcode = flags.opcode as u8;
-        buffer |= opcode << 3;
+        let opcode = u8::from(flags.opcode);
+        buffer |= (opcode & 0b0000_1111) << 3;
         if flags.aa {
             buffer |= 0b0000_0100;
         }
@@ -30,18 +108,20 @@ impl Encoder {
         if flags.cd {
             buffer |= 0b0001_0000;
         }
-        let rcode = flags.rcode as u8;
+        // The upper bits of an extended RCODE are stored in the OPT record.
+        let rcode = u8::from(flags.rcode) & 0b0000_1111;
         buffer |= rcode;
         self.u8(buffer);
     }
 
     pub(super) fn dns(&mut self, dns: &Dns) -> EncodeResult<()> {
+        let additionals = additionals(dns);
         self.u16(dns.id);
         self.flags(&dns.flags);
         self.u16(dns.questions.len() as u16);
         self.u16(dns.answers.len() as u16);
         self.u16(dns.authorities.len() as u16);
-        self.u16(dns.additionals.len() as u16);
+        self.u16(additionals.len() as u16);
 
         for question in &dns.questions {
             self.question(question)?;
@@ -55,7 +135,7 @@ impl Encoder {
             self.rr(authority)?;
         }
 
-        for additional in &dns.additionals {
+        for additional in additionals.iter() {
             self.rr(additional)?;
         }
 
@@ -63,6 +143,165 @@ impl Encoder {
     }
 }
 
+/// The encoder state at the beginning of a section.
+struct Checkpoint {
+    length: usize,
+    domain_name_index: HashMap<DomainName, (u16, usize)>,
+}
+
+impl Encoder {
+    /// Encode the resource records of the first `rrset_count` RRsets and the OPT records in their
+    /// original order, starting at the checkpoint.
+    fn rrsets_prefix(
+        &mut self,
+        checkpoint: &Checkpoint,
+        rrs: &[RR],
+        indices: &[usize],
+        rrset_count: usize,
+    ) -> EncodeResult<()> {
+        self.bytes.truncate(checkpoint.length);
+        self.domain_name_index = checkpoint.domain_name_index.clone();
+        for (rr, index) in rrs.iter().zip(indices) {
+            if *index < rrset_count || is_opt(rr) {
+                self.rr(rr)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Encode the RRsets of a section as long as the limit is not exceeded. The OPT records are
+    /// always encoded and the resource records keep their order.
+    ///
+    /// Returns the number of encoded resource records and adds the omitted resource records.
+    fn rrsets_with_limit(
+        &mut self,
+        rrs: &[RR],
+        limit: &mut Limit,
+        omitted: &mut Vec<RR>,
+    ) -> EncodeResult<u16> {
+        let mut opt_length = 0;
+        for rr in rrs.iter().filter(|rr| is_opt(rr)) {
+            opt_length += rr.encode()?.len();
+        }
+        let reserved = limit.reserved.saturating_sub(opt_length);
+        let indices = rrset_indices(rrs);
+        let checkpoint = Checkpoint {
+            length: self.bytes.len(),
+            domain_name_index: self.domain_name_index.clone(),
+        };
+
+        let mut rrset_count = if limit.truncated {
+            0
+        } else {
+            indices.iter().max().map_or(0, |index| index + 1)
+        };
+        self.rrsets_prefix(&checkpoint, rrs, &indices, rrset_count)?;
+        if self.bytes.len() + reserved > limit.max {
+            // Search the first RRset, which does not fit. The OPT records always fit, because
+            // they are reserved.
+            limit.truncated = true;
+            let mut fits = 0;
+            while rrset_count - fits > 1 {
+                let middle = (fits + rrset_count) / 2;
+                self.rrsets_prefix(&checkpoint, rrs, &indices, middle)?;
+                if self.bytes.len() + reserved > limit.max {
+                    rrset_count = middle;
+                } else {
+                    fits = middle;
+                }
+            }
+            rrset_count = fits;
+            self.rrsets_prefix(&checkpoint, rrs, &indices, rrset_count)?;
+        }
+        limit.reserved = reserved;
+
+        let mut count = 0;
+        for (rr, index) in rrs.iter().zip(indices) {
+            if index < rrset_count || is_opt(rr) {
+                count += 1;
+            } else {
+                omitted.push(rr.clone());
+            }
+        }
+        Ok(count)
+    }
+
+    fn dns_with_limit(&mut self, dns: &Dns, max: usize) -> EncodeResult<Omitted> {
+        let additionals = additionals(dns);
+        let mut limit = Limit {
+            max,
+            reserved: 0,
+            truncated: false,
+        };
+        for rr in additionals.iter() {
+            if let RR::OPT(_) = rr {
+                limit.reserved += rr.encode()?.len();
+            }
+        }
+
+        self.u16(dns.id);
+        self.flags(&dns.flags);
+        self.u16(dns.questions.len() as u16);
+        // The counts of the resource records are set after the encoding.
+        self.u16(0);
+        self.u16(0);
+        self.u16(0);
+
+        for question in &dns.questions {
+            self.question(question)?;
+        }
+        let length = self.bytes.len() + limit.reserved;
+        if length > max {
+            return Err(EncodeError::Limit(length, max));
+        }
+
+        let mut omitted = Omitted::default();
+        let answer_count =
+            self.rrsets_with_limit(&dns.answers, &mut limit, &mut omitted.answers)?;
+        let authority_count =
+            self.rrsets_with_limit(&dns.authorities, &mut limit, &mut omitted.authorities)?;
+        let additional_count =
+            self.rrsets_with_limit(&additionals, &mut limit, &mut omitted.additionals)?;
+        self.set_u16(answer_count, 6)?;
+        self.set_u16(authority_count, 8)?;
+        self.set_u16(additional_count, 10)?;
+
+        // The MAC of a TSIG record covers the whole message, see RFC 8945 Section 5.3.
+        if !omitted.is_empty() && matches!(additionals.last(), Some(RR::TSIG(_))) {
+            return Err(EncodeError::LimitSigned(max));
+        }
+
+        // Only the omission of additional data does not require the TC bit, see RFC 2181
+        // Section 9.
+        if !omitted.answers.is_empty() || !omitted.authorities.is_empty() {
+            let mut flags = dns.flags.clone();
+            flags.tc = true;
+            let flags = flags.encode();
+            self.bytes[2..4].copy_from_slice(&flags);
+        }
+        Ok(omitted)
+    }
+}
+
+impl Dns {
+    /// Encode the message, but omit whole RRsets if the message would be bigger than `max` bytes,
+    /// for example the maximum UDP payload size.
+    ///
+    /// The RRsets are added in the order of the sections and the order of their first record. An
+    /// RRSIG record belongs to the RRset, which it covers. If an RRset does not fit then it and all
+    /// following RRsets are omitted, except of the OPT record, which is always kept. The encoded
+    /// records keep their order. If answers or authorities are omitted then the TC bit is set. The
+    /// omitted records are returned.
+    ///
+    /// A message, which is signed with a TSIG record, is not truncated, because the signature
+    /// would be invalid. Instead an error is returned.
+    pub fn encode_with_limit(&self, max: usize) -> EncodeResult<(BytesMut, Omitted)> {
+        let mut encoder = Encoder::default();
+        let omitted = encoder.dns_with_limit(self, max)?;
+        Ok((encoder.bytes, omitted))
+    }
+}
+
 impl_encode_without_result!(Flags, flags);
 
 impl_encode!(Dns, dns);
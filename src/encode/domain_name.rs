@@ -2,6 +2,7 @@ use crate::domain_name::DOMAIN_NAME_MAX_RECURSION;
 use crate::encode::Encoder;
 use crate::label::Label;
 use crate::{DomainName, EncodeError, EncodeResult};
+use bytes::BytesMut;
 use std::collections::HashMap;
 
 const MAX_OFFSET: u16 = 0b0011_1111_1111_1111;
@@ -10,6 +11,10 @@ const COMPRESSION_BITS: u16 = 0b1100_0000_0000_0000;
 impl Encoder {
     #[inline]
     fn compress(&mut self, domain_name: &DomainName) -> EncodeResult<Option<usize>> {
+        if !self.compression {
+            return Ok(None);
+        }
+
         if let Some((index, recursion)) = self.domain_name_index.get(domain_name) {
             let index = *index;
             if MAX_OFFSET < index {
@@ -33,7 +38,11 @@ impl Encoder {
     #[inline]
     fn label(&mut self, label: &Label) -> EncodeResult<u16> {
         let index = self.get_offset()?;
-        self.string_with_len(label.as_ref())?;
+        if self.lowercase {
+            self.vec_with_len(&label.0.to_ascii_lowercase())?;
+        } else {
+            self.vec_with_len(label.as_bytes())?;
+        }
         Ok(index)
     }
 
@@ -71,9 +80,33 @@ impl Encoder {
         self.merge_domain_name_index(domain_name_index, 0)?;
         Ok(())
     }
+
+    pub(super) fn domain_name_without_compression(
+        &mut self,
+        domain_name: &DomainName,
+    ) -> EncodeResult<()> {
+        for label in domain_name.0.iter() {
+            self.label(label)?;
+        }
+        self.string_with_len("")
+    }
 }
 
 impl DomainName {
+    /// Encode the domain name in the [canonical form], which means without compression and in
+    /// lowercase.
+    ///
+    /// [canonical form]: https://tools.ietf.org/html/rfc4034#section-6.2
+    pub fn encode_canonical(&self) -> EncodeResult<BytesMut> {
+        let mut encoder = Encoder {
+            compression: false,
+            lowercase: true,
+            ..Default::default()
+        };
+        encoder.domain_name(self)?;
+        Ok(encoder.bytes)
+    }
+
     fn iter(&self) -> DomainNameIter<'_> {
         DomainNameIter { labels: &self.0 }
     }
@@ -6,6 +6,9 @@ use std::convert::TryInto;
 pub(crate) struct Encoder {
     pub bytes: BytesMut,
     pub domain_name_index: HashMap<DomainName, (u16, usize)>,
+    pub compression: bool,
+    /// Encode the labels of the domain names in lowercase, which is used for the canonical form.
+    pub lowercase: bool,
 }
 
 impl Encoder {
@@ -25,6 +28,8 @@ impl Default for Encoder {
         Encoder {
             bytes: BytesMut::new(),
             domain_name_index: HashMap::new(),
+            compression: true,
+            lowercase: false,
         }
     }
 }
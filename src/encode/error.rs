@@ -5,6 +5,8 @@ use thiserror::Error;
 pub enum EncodeError {
     #[error("String too big to be encoded as u8: {0}")]
     String(usize),
+    #[error("Vec too big to be encoded as u8: {0}")]
+    Vec(usize),
     #[error("Length too big to be encoded as u16: {0}")]
     Length(usize),
     #[error("Not enough bytes to set the data at the index: got {0} index {1}")]
@@ -15,4 +17,10 @@ pub enum EncodeError {
     MaxRecursion(usize),
     #[error("Could not encode address length, because it is too big: {APL_NEGATION_MASK} <= {0}")]
     APLAddressLength(u8),
+    #[error("Could not encode the TSIG time signed, because it is bigger than 48 bits: {0}")]
+    TSIGTimeSigned(u64),
+    #[error("The header, the questions and the OPT record need {0} bytes, but the limit is {1}")]
+    Limit(usize, usize),
+    #[error("The signed message does not fit into the limit {0}")]
+    LimitSigned(usize),
 }
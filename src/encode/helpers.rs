@@ -16,7 +16,7 @@ impl Encoder {
         self.bytes.put_u16(n)
     }
 
-    fn set_u16(&mut self, n: u16, index: usize) -> EncodeResult<()> {
+    pub(super) fn set_u16(&mut self, n: u16, index: usize) -> EncodeResult<()> {
         let bytes = n.to_be_bytes();
         let bytes_len = self.bytes.len();
         if index + size_of::<u16>() - 1 < bytes_len {
@@ -88,6 +88,17 @@ impl Encoder {
         self.bytes.extend_from_slice(v);
     }
 
+    pub(super) fn vec_with_len(&mut self, v: &[u8]) -> EncodeResult<()> {
+        let length = v.len();
+        if length > 255 {
+            return Err(EncodeError::Vec(length));
+        }
+
+        self.u8(length as u8);
+        self.vec(v);
+        Ok(())
+    }
+
     #[inline]
     pub(super) fn create_length_index(&mut self) -> usize {
         let length_index = self.bytes.len();
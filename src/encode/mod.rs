@@ -10,6 +10,7 @@ mod rr;
 #[cfg(test)]
 mod tests;
 
+pub use dns::Omitted;
 use encoder::Encoder;
 pub use error::EncodeError;
 
@@ -0,0 +1,12 @@
+use crate::encode::Encoder;
+use crate::rr::edns::{EDNSOptionCode, NSID};
+use crate::EncodeResult;
+
+impl Encoder {
+    pub(super) fn rr_edns_nsid(&mut self, nsid: &NSID) -> EncodeResult<()> {
+        self.rr_edns_option_code(&EDNSOptionCode::NSID);
+        let length_index = self.create_length_index();
+        self.vec(&nsid.0);
+        self.set_length_index(length_index)
+    }
+}
@@ -21,12 +21,25 @@ impl Encoder {
 
     fn rr_edns_option(&mut self, edns_option: &EDNSOption) -> EncodeResult<()> {
         match edns_option {
+            EDNSOption::NSID(nsid) => self.rr_edns_nsid(nsid)?,
+            EDNSOption::DAU(dau) => self.rr_edns_dau(dau)?,
+            EDNSOption::DHU(dhu) => self.rr_edns_dhu(dhu)?,
+            EDNSOption::N3U(n3u) => self.rr_edns_n3u(n3u)?,
             EDNSOption::ECS(ecs) => self.rr_edns_ecs(ecs)?,
+            EDNSOption::Expire(expire) => self.rr_edns_expire(*expire),
             EDNSOption::Cookie(cookie) => self.rr_edns_cookie(cookie)?,
+            EDNSOption::TcpKeepalive(tcp_keepalive) => self.rr_edns_tcp_keepalive(tcp_keepalive),
             EDNSOption::Padding(padding) => self.rr_edns_padding(padding),
+            EDNSOption::Chain(closest_trust_point) => self.rr_edns_chain(closest_trust_point)?,
             EDNSOption::ExtendedDNSErrors(extended_dns_errors) => {
                 self.rr_edns_extended_dns_errors(extended_dns_errors)?
             }
+            EDNSOption::Unknown { code, data } => {
+                self.u16(*code);
+                let length_index = self.create_length_index();
+                self.vec(data);
+                self.set_length_index(length_index)?
+            }
         }
         Ok(())
     }
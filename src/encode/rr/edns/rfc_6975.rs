@@ -0,0 +1,32 @@
+use crate::encode::Encoder;
+use crate::rr::edns::{EDNSOptionCode, DAU, DHU, N3U};
+use crate::EncodeResult;
+
+impl Encoder {
+    pub(super) fn rr_edns_dau(&mut self, dau: &DAU) -> EncodeResult<()> {
+        self.rr_edns_option_code(&EDNSOptionCode::DAU);
+        let length_index = self.create_length_index();
+        for algorithm_type in &dau.0 {
+            self.u8(*algorithm_type);
+        }
+        self.set_length_index(length_index)
+    }
+
+    pub(super) fn rr_edns_dhu(&mut self, dhu: &DHU) -> EncodeResult<()> {
+        self.rr_edns_option_code(&EDNSOptionCode::DHU);
+        let length_index = self.create_length_index();
+        for digest_type in &dhu.0 {
+            self.u8(*digest_type);
+        }
+        self.set_length_index(length_index)
+    }
+
+    pub(super) fn rr_edns_n3u(&mut self, n3u: &N3U) -> EncodeResult<()> {
+        self.rr_edns_option_code(&EDNSOptionCode::N3U);
+        let length_index = self.create_length_index();
+        for hash_algorithm in &n3u.0 {
+            self.u8(*hash_algorithm);
+        }
+        self.set_length_index(length_index)
+    }
+}
@@ -0,0 +1,15 @@
+use crate::encode::Encoder;
+use crate::rr::edns::EDNSOptionCode;
+
+impl Encoder {
+    pub(super) fn rr_edns_expire(&mut self, expire: Option<u32>) {
+        self.rr_edns_option_code(&EDNSOptionCode::Expire);
+        match expire {
+            Some(expire) => {
+                self.u16(4);
+                self.u32(expire);
+            }
+            None => self.u16(0),
+        }
+    }
+}
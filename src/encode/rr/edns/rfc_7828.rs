@@ -0,0 +1,15 @@
+use crate::encode::Encoder;
+use crate::rr::edns::{EDNSOptionCode, TcpKeepalive};
+
+impl Encoder {
+    pub(super) fn rr_edns_tcp_keepalive(&mut self, tcp_keepalive: &TcpKeepalive) {
+        self.rr_edns_option_code(&EDNSOptionCode::TcpKeepalive);
+        match tcp_keepalive.0 {
+            Some(timeout) => {
+                self.u16(2);
+                self.u16(timeout);
+            }
+            None => self.u16(0),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::encode::Encoder;
+use crate::rr::edns::EDNSOptionCode;
+use crate::{DomainName, EncodeResult};
+
+impl Encoder {
+    pub(super) fn rr_edns_chain(&mut self, closest_trust_point: &DomainName) -> EncodeResult<()> {
+        self.rr_edns_option_code(&EDNSOptionCode::Chain);
+        let length_index = self.create_length_index();
+        // The domain name must not be compressed.
+        self.domain_name_without_compression(closest_trust_point)?;
+        self.set_length_index(length_index)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::encode::Encoder;
 use crate::rr::{Class, Type, RR};
 use crate::EncodeResult;
+use bytes::BytesMut;
 
 impl Encoder {
     #[inline]
@@ -61,6 +62,17 @@ impl Encoder {
             RR::CAA(caa) => self.rr_caa(caa),
             RR::SVCB(svcb) => self.rr_service_binding(svcb),
             RR::HTTPS(https) => self.rr_service_binding(https),
+            RR::RRSIG(rrsig) => self.rr_rrsig(rrsig),
+            RR::NSEC(nsec) => self.rr_nsec(nsec),
+            RR::NSEC3(nsec3) => self.rr_nsec3(nsec3),
+            RR::NSEC3PARAM(nsec3param) => self.rr_nsec3param(nsec3param),
+            RR::TLSA(tlsa) => self.rr_tlsa(tlsa),
+            RR::SMIMEA(smimea) => self.rr_smimea(smimea),
+            RR::NAPTR(naptr) => self.rr_naptr(naptr),
+            RR::CDS(cds) => self.rr_cds(cds),
+            RR::CDNSKEY(cdnskey) => self.rr_cdnskey(cdnskey),
+            RR::TSIG(tsig) => self.rr_tsig(tsig),
+            RR::Unknown(unknown) => self.rr_unknown(unknown),
         }
     }
 }
@@ -70,3 +82,92 @@ impl_encode_without_result!(Type, rr_type);
 impl_encode_without_result!(Class, rr_class);
 
 impl_encode!(RR, rr);
+
+/// Returns the length of the owner name, which is not compressed.
+fn rr_owner_length(bytes: &[u8]) -> usize {
+    let mut index = 0;
+    while bytes[index] != 0 {
+        index += bytes[index] as usize + 1;
+    }
+    index + 1
+}
+
+impl RR {
+    /// Returns `true` if the domain names in the RDATA are in lowercase in the canonical form,
+    /// see [RFC 4034 Section 6.2]. The next domain name of NSEC is not lowercased, see
+    /// [RFC 6840 Section 5.1].
+    ///
+    /// [RFC 4034 Section 6.2]: https://tools.ietf.org/html/rfc4034#section-6.2
+    /// [RFC 6840 Section 5.1]: https://tools.ietf.org/html/rfc6840#section-5.1
+    fn is_rdata_canonical_lowercase(&self) -> bool {
+        matches!(
+            self,
+            RR::NS(_)
+                | RR::MD(_)
+                | RR::MF(_)
+                | RR::CNAME(_)
+                | RR::SOA(_)
+                | RR::MB(_)
+                | RR::MG(_)
+                | RR::MR(_)
+                | RR::PTR(_)
+                | RR::HINFO(_)
+                | RR::MINFO(_)
+                | RR::MX(_)
+                | RR::RP(_)
+                | RR::AFSDB(_)
+                | RR::RT(_)
+                | RR::PX(_)
+                | RR::NAPTR(_)
+                | RR::KX(_)
+                | RR::SRV(_)
+                | RR::DNAME(_)
+                | RR::RRSIG(_)
+        )
+    }
+
+    /// Encode the resource record in the [canonical form].
+    ///
+    /// The domain names are not compressed and the owner name is in lowercase. The domain names
+    /// in the RDATA are in lowercase for the types, which are listed in RFC 4034 Section 6.2
+    /// without NSEC (RFC 6840 Section 5.1).
+    ///
+    /// [canonical form]: https://tools.ietf.org/html/rfc4034#section-6.2
+    pub fn encode_canonical(&self) -> EncodeResult<BytesMut> {
+        let mut encoder = Encoder {
+            compression: false,
+            lowercase: self.is_rdata_canonical_lowercase(),
+            ..Default::default()
+        };
+        encoder.rr(self)?;
+        let mut bytes = encoder.bytes;
+        // The owner name is not compressed and is always in lowercase. The length octets of the
+        // labels are not letters, so they are not changed.
+        let owner_length = rr_owner_length(&bytes);
+        bytes[..owner_length].make_ascii_lowercase();
+        Ok(bytes)
+    }
+
+    /// Encode the RDATA in the canonical form, see [`RR::encode_canonical`].
+    pub(crate) fn encode_canonical_rdata(&self) -> EncodeResult<BytesMut> {
+        let mut bytes = self.encode_canonical()?;
+        // Skip the owner name, the type, the class, the TTL and the RDLENGTH.
+        let index = rr_owner_length(&bytes) + 10;
+        Ok(bytes.split_off(index))
+    }
+
+    /// Encode the RDATA without compression and return it with the type code.
+    pub(crate) fn encode_rdata(&self) -> EncodeResult<(u16, Vec<u8>)> {
+        let mut encoder = Encoder {
+            compression: false,
+            ..Default::default()
+        };
+        encoder.rr(self)?;
+        let bytes = encoder.bytes;
+        let index = rr_owner_length(&bytes);
+        let type_code = u16::from_be_bytes([bytes[index], bytes[index + 1]]);
+        // Skip the type, the class, the TTL and the RDLENGTH.
+        let rdata = bytes[index + 10..].to_vec();
+        Ok((type_code, rdata))
+    }
+}
@@ -80,6 +80,8 @@ fn set_address_length_index_error_1() {
     let mut encoder = Encoder {
         bytes,
         domain_name_index: HashMap::new(),
+        compression: true,
+        lowercase: false,
     };
     assert_eq!(
         encoder.set_address_length_index(false, 0),
@@ -101,6 +103,8 @@ fn set_address_length_index_error_2() {
     let mut encoder = Encoder {
         bytes,
         domain_name_index: HashMap::new(),
+        compression: true,
+        lowercase: false,
     };
     assert_eq!(
         encoder.set_address_length_index(false, 0),
@@ -117,6 +121,8 @@ fn set_u8() {
     let mut encoder = Encoder {
         bytes,
         domain_name_index: HashMap::new(),
+        compression: true,
+        lowercase: false,
     };
     assert_eq!(
         encoder.set_u8(10, 0),
@@ -0,0 +1,23 @@
+use crate::encode::Encoder;
+use crate::rr::{Type, NAPTR};
+use crate::EncodeResult;
+
+impl Encoder {
+    pub(super) fn rr_naptr(&mut self, naptr: &NAPTR) -> EncodeResult<()> {
+        self.domain_name(&naptr.domain_name)?;
+        self.rr_type(&Type::NAPTR);
+        self.rr_class(&naptr.class);
+        self.u32(naptr.ttl);
+        let length_index = self.create_length_index();
+        self.u16(naptr.order);
+        self.u16(naptr.preference);
+        self.string_with_len(&naptr.flags)?;
+        self.string_with_len(&naptr.services)?;
+        self.string_with_len(&naptr.regexp)?;
+        // The replacement must not be compressed, see RFC 3403 Section 4.1.
+        self.domain_name_without_compression(&naptr.replacement)?;
+        self.set_length_index(length_index)
+    }
+}
+
+impl_encode_rr!(NAPTR, rr_naptr);
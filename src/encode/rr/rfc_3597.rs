@@ -0,0 +1,17 @@
+use crate::encode::Encoder;
+use crate::rr::Unknown;
+use crate::EncodeResult;
+
+impl Encoder {
+    pub(super) fn rr_unknown(&mut self, unknown: &Unknown) -> EncodeResult<()> {
+        self.domain_name(&unknown.domain_name)?;
+        self.u16(unknown.type_code);
+        self.rr_class(&unknown.class);
+        self.u32(unknown.ttl);
+        let length_index = self.create_length_index();
+        self.vec(&unknown.rdata);
+        self.set_length_index(length_index)
+    }
+}
+
+impl_encode_rr!(Unknown, rr_unknown);
@@ -1,32 +1,45 @@
 use crate::encode::Encoder;
-use crate::rr::{AlgorithmType, DigestType, Type, DNSKEY, DS};
+use crate::rr::{AlgorithmType, DigestType, Type, DNSKEY, DS, NSEC, RRSIG};
 use crate::EncodeResult;
+use bytes::BytesMut;
 
 impl Encoder {
-    fn rr_algorithm_type(&mut self, algorithm_type: AlgorithmType) {
+    pub(super) fn rr_algorithm_type(&mut self, algorithm_type: AlgorithmType) {
         self.u8(algorithm_type as u8);
     }
 
-    fn rr_digest_type(&mut self, digest_type: DigestType) {
+    pub(super) fn rr_digest_type(&mut self, digest_type: DigestType) {
         self.u8(digest_type as u8);
     }
 
-    pub(super) fn rr_dnskey(&mut self, dnskey: &DNSKEY) -> EncodeResult<()> {
+    fn rr_dnskey_with_type(&mut self, dnskey: &DNSKEY, type_: &Type) -> EncodeResult<()> {
         self.domain_name(&dnskey.domain_name)?;
-        self.rr_type(&Type::DNSKEY);
+        self.rr_type(type_);
         self.rr_class(&dnskey.class);
         self.u32(dnskey.ttl);
         let length_index = self.create_length_index();
+        self.rr_dnskey_rdata(dnskey);
+        self.set_length_index(length_index)
+    }
+
+    fn rr_dnskey_rdata(&mut self, dnskey: &DNSKEY) {
         self.u16(dnskey.get_flags());
         self.u8(3);
         self.rr_algorithm_type(dnskey.algorithm_type);
         self.vec(&dnskey.public_key);
-        self.set_length_index(length_index)
     }
 
-    pub(super) fn rr_ds(&mut self, ds: &DS) -> EncodeResult<()> {
+    pub(super) fn rr_dnskey(&mut self, dnskey: &DNSKEY) -> EncodeResult<()> {
+        self.rr_dnskey_with_type(dnskey, &Type::DNSKEY)
+    }
+
+    pub(super) fn rr_cdnskey(&mut self, cdnskey: &DNSKEY) -> EncodeResult<()> {
+        self.rr_dnskey_with_type(cdnskey, &Type::CDNSKEY)
+    }
+
+    fn rr_ds_with_type(&mut self, ds: &DS, type_: &Type) -> EncodeResult<()> {
         self.domain_name(&ds.domain_name)?;
-        self.rr_type(&Type::DS);
+        self.rr_type(type_);
         self.rr_class(&ds.class);
         self.u32(ds.ttl);
         let length_index = self.create_length_index();
@@ -36,4 +49,97 @@ impl Encoder {
         self.vec(&ds.digest);
         self.set_length_index(length_index)
     }
+
+    pub(super) fn rr_ds(&mut self, ds: &DS) -> EncodeResult<()> {
+        self.rr_ds_with_type(ds, &Type::DS)
+    }
+
+    pub(super) fn rr_cds(&mut self, cds: &DS) -> EncodeResult<()> {
+        self.rr_ds_with_type(cds, &Type::CDS)
+    }
+
+    pub(super) fn rr_rrsig(&mut self, rrsig: &RRSIG) -> EncodeResult<()> {
+        self.domain_name(&rrsig.domain_name)?;
+        self.rr_type(&Type::RRSIG);
+        self.rr_class(&rrsig.class);
+        self.u32(rrsig.ttl);
+        let length_index = self.create_length_index();
+        self.rr_rrsig_fields(rrsig);
+        // The signer's name must not be compressed, see RFC 4034 Section 3.1.7.
+        self.domain_name_without_compression(&rrsig.signer_name)?;
+        self.vec(&rrsig.signature);
+        self.set_length_index(length_index)
+    }
+
+    fn rr_rrsig_fields(&mut self, rrsig: &RRSIG) {
+        self.u16(rrsig.type_covered);
+        self.u8(rrsig.algorithm_type);
+        self.u8(rrsig.labels);
+        self.u32(rrsig.original_ttl);
+        self.u32(rrsig.signature_expiration);
+        self.u32(rrsig.signature_inception);
+        self.u16(rrsig.key_tag);
+    }
+
+    pub(super) fn rr_nsec(&mut self, nsec: &NSEC) -> EncodeResult<()> {
+        self.domain_name(&nsec.domain_name)?;
+        self.rr_type(&Type::NSEC);
+        self.rr_class(&nsec.class);
+        self.u32(nsec.ttl);
+        let length_index = self.create_length_index();
+        // The next domain name must not be compressed, see RFC 4034 Section 4.1.1.
+        self.domain_name_without_compression(&nsec.next_domain_name)?;
+        self.rr_type_bit_map(&nsec.types);
+        self.set_length_index(length_index)
+    }
+}
+
+impl_encode_rr!(RRSIG, rr_rrsig);
+
+impl_encode_rr!(NSEC, rr_nsec);
+
+impl DNSKEY {
+    /// Encode the RDATA, which does not contain a domain name and is therefore always in the
+    /// canonical form.
+    pub(crate) fn encode_rdata(&self) -> BytesMut {
+        let mut encoder = Encoder::default();
+        encoder.rr_dnskey_rdata(self);
+        encoder.bytes
+    }
+}
+
+#[cfg(feature = "dnssec")]
+impl DNSKEY {
+    /// Encode the data, which is digested for a DS resource record, see
+    /// [RFC 4034 Section 5.1.4].
+    ///
+    /// [RFC 4034 Section 5.1.4]: https://tools.ietf.org/html/rfc4034#section-5.1.4
+    pub(crate) fn encode_ds_data(&self) -> EncodeResult<BytesMut> {
+        let mut encoder = Encoder {
+            compression: false,
+            lowercase: true,
+            ..Default::default()
+        };
+        encoder.domain_name(&self.domain_name)?;
+        encoder.rr_dnskey_rdata(self);
+        Ok(encoder.bytes)
+    }
+}
+
+#[cfg(feature = "dnssec")]
+impl RRSIG {
+    /// Encode the RRSIG RDATA without the signature and with the signer's name in the canonical
+    /// form, which is the beginning of the signed data, see [RFC 4034 Section 3.1.8.1].
+    ///
+    /// [RFC 4034 Section 3.1.8.1]: https://tools.ietf.org/html/rfc4034#section-3.1.8.1
+    pub(crate) fn encode_signed_rdata(&self) -> EncodeResult<BytesMut> {
+        let mut encoder = Encoder {
+            compression: false,
+            lowercase: true,
+            ..Default::default()
+        };
+        encoder.rr_rrsig_fields(self);
+        encoder.domain_name(&self.signer_name)?;
+        Ok(encoder.bytes)
+    }
 }
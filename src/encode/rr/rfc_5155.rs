@@ -0,0 +1,37 @@
+use crate::encode::Encoder;
+use crate::rr::{Type, NSEC3, NSEC3PARAM};
+use crate::EncodeResult;
+
+impl Encoder {
+    pub(super) fn rr_nsec3(&mut self, nsec3: &NSEC3) -> EncodeResult<()> {
+        self.domain_name(&nsec3.domain_name)?;
+        self.rr_type(&Type::NSEC3);
+        self.rr_class(&nsec3.class);
+        self.u32(nsec3.ttl);
+        let length_index = self.create_length_index();
+        self.u8(nsec3.hash_algorithm);
+        self.u8(nsec3.flags);
+        self.u16(nsec3.iterations);
+        self.vec_with_len(&nsec3.salt)?;
+        self.vec_with_len(&nsec3.next_hashed_owner_name)?;
+        self.rr_type_bit_map(&nsec3.types);
+        self.set_length_index(length_index)
+    }
+
+    pub(super) fn rr_nsec3param(&mut self, nsec3param: &NSEC3PARAM) -> EncodeResult<()> {
+        self.domain_name(&nsec3param.domain_name)?;
+        self.rr_type(&Type::NSEC3PARAM);
+        self.rr_class(&nsec3param.class);
+        self.u32(nsec3param.ttl);
+        let length_index = self.create_length_index();
+        self.u8(nsec3param.hash_algorithm);
+        self.u8(nsec3param.flags);
+        self.u16(nsec3param.iterations);
+        self.vec_with_len(&nsec3param.salt)?;
+        self.set_length_index(length_index)
+    }
+}
+
+impl_encode_rr!(NSEC3, rr_nsec3);
+
+impl_encode_rr!(NSEC3PARAM, rr_nsec3param);
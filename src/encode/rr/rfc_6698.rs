@@ -0,0 +1,35 @@
+use crate::encode::Encoder;
+use crate::rr::{TLSACertificateUsage, TLSAMatchingType, TLSASelector, Type, TLSA};
+use crate::EncodeResult;
+
+impl Encoder {
+    #[inline]
+    pub(super) fn rr_tlsa_certificate_usage(&mut self, certificate_usage: &TLSACertificateUsage) {
+        self.u8(*certificate_usage as u8);
+    }
+
+    #[inline]
+    pub(super) fn rr_tlsa_selector(&mut self, selector: &TLSASelector) {
+        self.u8(*selector as u8);
+    }
+
+    #[inline]
+    pub(super) fn rr_tlsa_matching_type(&mut self, matching_type: &TLSAMatchingType) {
+        self.u8(*matching_type as u8);
+    }
+
+    pub(super) fn rr_tlsa(&mut self, tlsa: &TLSA) -> EncodeResult<()> {
+        self.domain_name(&tlsa.domain_name)?;
+        self.rr_type(&Type::TLSA);
+        self.rr_class(&tlsa.class);
+        self.u32(tlsa.ttl);
+        let length_index = self.create_length_index();
+        self.rr_tlsa_certificate_usage(&tlsa.certificate_usage);
+        self.rr_tlsa_selector(&tlsa.selector);
+        self.rr_tlsa_matching_type(&tlsa.matching_type);
+        self.vec(&tlsa.certificate_association_data);
+        self.set_length_index(length_index)
+    }
+}
+
+impl_encode_rr!(TLSA, rr_tlsa);
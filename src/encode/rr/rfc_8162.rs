@@ -0,0 +1,20 @@
+use crate::encode::Encoder;
+use crate::rr::{Type, SMIMEA};
+use crate::EncodeResult;
+
+impl Encoder {
+    pub(super) fn rr_smimea(&mut self, smimea: &SMIMEA) -> EncodeResult<()> {
+        self.domain_name(&smimea.domain_name)?;
+        self.rr_type(&Type::SMIMEA);
+        self.rr_class(&smimea.class);
+        self.u32(smimea.ttl);
+        let length_index = self.create_length_index();
+        self.rr_tlsa_certificate_usage(&smimea.certificate_usage);
+        self.rr_tlsa_selector(&smimea.selector);
+        self.rr_tlsa_matching_type(&smimea.matching_type);
+        self.vec(&smimea.certificate_association_data);
+        self.set_length_index(length_index)
+    }
+}
+
+impl_encode_rr!(SMIMEA, rr_smimea);
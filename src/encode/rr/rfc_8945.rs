@@ -0,0 +1,95 @@
+use crate::encode::Encoder;
+use crate::rr::{Type, TSIG, TSIG_CLASS};
+use crate::{EncodeError, EncodeResult};
+#[cfg(feature = "tsig")]
+use bytes::BytesMut;
+use std::convert::TryInto;
+
+const TIME_SIGNED_MAX: u64 = 0xffff_ffff_ffff;
+
+impl Encoder {
+    fn rr_tsig_vec(&mut self, v: &[u8]) -> EncodeResult<()> {
+        let length = v.len();
+        if let Ok(length) = length.try_into() {
+            self.u16(length);
+            self.vec(v);
+            Ok(())
+        } else {
+            Err(EncodeError::Length(length))
+        }
+    }
+
+    fn rr_tsig_time_signed(&mut self, time_signed: u64) -> EncodeResult<()> {
+        if TIME_SIGNED_MAX < time_signed {
+            return Err(EncodeError::TSIGTimeSigned(time_signed));
+        }
+        self.u16((time_signed >> 32) as u16);
+        self.u32(time_signed as u32);
+        Ok(())
+    }
+
+    pub(super) fn rr_tsig(&mut self, tsig: &TSIG) -> EncodeResult<()> {
+        // The names must not be compressed, see RFC 8945 Section 4.2.
+        self.domain_name_without_compression(&tsig.domain_name)?;
+        self.rr_type(&Type::TSIG);
+        self.u16(TSIG_CLASS);
+        self.u32(0);
+        let length_index = self.create_length_index();
+        self.domain_name_without_compression(&tsig.algorithm_name)?;
+        self.rr_tsig_time_signed(tsig.time_signed)?;
+        self.u16(tsig.fudge);
+        self.rr_tsig_vec(&tsig.mac)?;
+        self.u16(tsig.original_id);
+        self.u16(tsig.error);
+        self.rr_tsig_vec(&tsig.other_data)?;
+        self.set_length_index(length_index)
+    }
+
+    /// Encode the [TSIG variables], which are part of the digest. The domain names are in the
+    /// canonical form, if the encoder does not compress and lowercases them.
+    ///
+    /// [TSIG variables]: https://tools.ietf.org/html/rfc8945#section-4.3.3
+    #[cfg(feature = "tsig")]
+    fn rr_tsig_variables(&mut self, tsig: &TSIG) -> EncodeResult<()> {
+        self.domain_name(&tsig.domain_name)?;
+        self.u16(TSIG_CLASS);
+        self.u32(0);
+        self.domain_name(&tsig.algorithm_name)?;
+        self.rr_tsig_time_signed(tsig.time_signed)?;
+        self.u16(tsig.fudge);
+        self.u16(tsig.error);
+        self.rr_tsig_vec(&tsig.other_data)
+    }
+
+    /// Encode the [TSIG timers], which are part of the digest of the subsequent messages of a
+    /// multi-message response.
+    ///
+    /// [TSIG timers]: https://tools.ietf.org/html/rfc8945#section-5.3.1
+    #[cfg(feature = "tsig")]
+    fn rr_tsig_timers(&mut self, tsig: &TSIG) -> EncodeResult<()> {
+        self.rr_tsig_time_signed(tsig.time_signed)?;
+        self.u16(tsig.fudge);
+        Ok(())
+    }
+}
+
+impl_encode_rr!(TSIG, rr_tsig);
+
+#[cfg(feature = "tsig")]
+impl TSIG {
+    pub(crate) fn encode_variables(&self) -> EncodeResult<BytesMut> {
+        let mut encoder = Encoder {
+            compression: false,
+            lowercase: true,
+            ..Default::default()
+        };
+        encoder.rr_tsig_variables(self)?;
+        Ok(encoder.bytes)
+    }
+
+    pub(crate) fn encode_timers(&self) -> EncodeResult<BytesMut> {
+        let mut encoder = Encoder::default();
+        encoder.rr_tsig_timers(self)?;
+        Ok(encoder.bytes)
+    }
+}
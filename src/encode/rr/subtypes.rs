@@ -1,5 +1,6 @@
 use crate::encode::Encoder;
-use crate::rr::{Address, AddressFamilyNumber};
+use crate::rr::{Address, AddressFamilyNumber, TypeBitMap};
+use std::collections::BTreeMap;
 use std::net::{Ipv4Addr, Ipv6Addr};
 
 impl Encoder {
@@ -38,4 +39,24 @@ impl Encoder {
             Address::Ipv6(ipv6_addr) => self.rr_address_ipv6(ipv6_addr, prefix_length),
         }
     }
+
+    /// Encode the types as [type bit map].
+    ///
+    /// [type bit map]: https://tools.ietf.org/html/rfc4034#section-4.1.2
+    pub(super) fn rr_type_bit_map(&mut self, types: &TypeBitMap) {
+        let mut window_blocks: BTreeMap<u8, Vec<u8>> = BTreeMap::new();
+        for type_code in types.iter_codes() {
+            let bitmap = window_blocks.entry((type_code >> 8) as u8).or_default();
+            let index = ((type_code & 0xff) >> 3) as usize;
+            if bitmap.len() <= index {
+                bitmap.resize(index + 1, 0);
+            }
+            bitmap[index] |= 0b1000_0000 >> (type_code & 0b0111);
+        }
+        for (window, bitmap) in window_blocks {
+            self.u8(window);
+            self.u8(bitmap.len() as u8);
+            self.vec(&bitmap);
+        }
+    }
 }
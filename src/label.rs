@@ -1,6 +1,7 @@
 use std::{
+    cmp::Ordering,
     convert::TryFrom,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Display, Formatter, Result as FmtResult, Write},
     hash::{Hash, Hasher},
     str::FromStr,
 };
@@ -14,13 +15,21 @@ pub enum LabelError {
     Length(usize),
     #[error("Label is empty")]
     Empty,
+    #[error("Invalid escape sequence: {0}")]
+    Escape(String),
 }
 
+/// Represent a label of a domain name.
+///
+/// A label is a sequence of arbitrary bytes. Labels are compared case-insensitively according to
+/// [RFC 4343], only the ASCII letters are folded.
+///
+/// [RFC 4343]: https://tools.ietf.org/html/rfc4343
 #[derive(Debug, Clone, Eq)]
-pub struct Label(pub(super) String);
+pub struct Label(pub(super) Vec<u8>);
 
 #[inline]
-fn check_label(label: &str) -> Result<(), LabelError> {
+fn check_label(label: &[u8]) -> Result<(), LabelError> {
     let label_length = label.len();
     if label_length == 0 {
         Err(LabelError::Empty)
@@ -31,56 +40,152 @@ fn check_label(label: &str) -> Result<(), LabelError> {
     }
 }
 
+/// Resolve the escape sequences `\X` and `\DDD`, see [RFC 1035 Section 5.1].
+///
+/// [RFC 1035 Section 5.1]: https://tools.ietf.org/html/rfc1035#section-5.1
+fn unescape(label: &str) -> Result<Vec<u8>, LabelError> {
+    let escape_error = |index: usize| {
+        let escape = label[index..].chars().take(4).collect();
+        LabelError::Escape(escape)
+    };
+    let bytes = label.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] != b'\\' {
+            result.push(bytes[index]);
+            index += 1;
+            continue;
+        }
+        match bytes.get(index + 1) {
+            Some(b) if b.is_ascii_digit() => {
+                let value = bytes
+                    .get(index + 1..index + 4)
+                    .filter(|digits| digits.iter().all(u8::is_ascii_digit))
+                    .and_then(|digits| std::str::from_utf8(digits).ok())
+                    .and_then(|digits| digits.parse().ok())
+                    .ok_or_else(|| escape_error(index))?;
+                result.push(value);
+                index += 4;
+            }
+            Some(b) => {
+                result.push(*b);
+                index += 2;
+            }
+            None => return Err(escape_error(index)),
+        }
+    }
+    Ok(result)
+}
+
 impl Label {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Returns the raw bytes of the label.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
 }
 
-impl TryFrom<String> for Label {
+impl TryFrom<Vec<u8>> for Label {
     type Error = LabelError;
 
-    fn try_from(label: String) -> Result<Self, <Self as TryFrom<String>>::Error> {
+    fn try_from(label: Vec<u8>) -> Result<Self, <Self as TryFrom<Vec<u8>>>::Error> {
         check_label(&label)?;
         Ok(Label(label))
     }
 }
 
+impl TryFrom<&[u8]> for Label {
+    type Error = LabelError;
+
+    fn try_from(label: &[u8]) -> Result<Self, <Self as TryFrom<&[u8]>>::Error> {
+        check_label(label)?;
+        Ok(Label(label.to_vec()))
+    }
+}
+
+impl TryFrom<String> for Label {
+    type Error = LabelError;
+
+    /// Create a label from the bytes of the string, the escape sequences are not resolved.
+    fn try_from(label: String) -> Result<Self, <Self as TryFrom<String>>::Error> {
+        Label::try_from(label.into_bytes())
+    }
+}
+
 impl FromStr for Label {
     type Err = LabelError;
 
+    /// Parse the presentation format of a label, the escape sequences `\X` and `\DDD` are
+    /// resolved.
     fn from_str(label: &str) -> Result<Self, <Self as FromStr>::Err> {
-        check_label(label)?;
-        Ok(Label(label.to_owned()))
+        Label::try_from(unescape(label)?)
     }
 }
 
+/// Write the label in the presentation format.
+///
+/// The special characters are escaped with `\X` and the non-printable bytes with `\DDD`.
+pub(crate) fn fmt_label(f: &mut Formatter<'_>, label: &[u8]) -> FmtResult {
+    for b in label.iter() {
+        match b {
+            b'.' | b'\\' | b'"' | b'(' | b')' | b';' | b'@' | b'$' => {
+                write!(f, "\\{}", *b as char)?
+            }
+            0x21..=0x7e => f.write_char(*b as char)?,
+            _ => write!(f, "\\{:03}", b)?,
+        }
+    }
+    Ok(())
+}
+
 impl Display for Label {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{}", self.0)
+        fmt_label(f, &self.0)
     }
 }
 
-impl AsRef<str> for Label {
-    fn as_ref(&self) -> &str {
+impl AsRef<[u8]> for Label {
+    fn as_ref(&self) -> &[u8] {
         &self.0
     }
 }
 
 impl PartialEq<&str> for Label {
     fn eq(&self, other: &&str) -> bool {
-        self.0.to_lowercase() == other.to_lowercase()
+        self.0.eq_ignore_ascii_case(other.as_bytes())
     }
 }
 
 impl PartialEq<Label> for Label {
     fn eq(&self, other: &Label) -> bool {
-        self.0.to_lowercase() == other.0.to_lowercase()
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Ord for Label {
+    /// Compare the labels in the [canonical order], which means in lowercase as unsigned
+    /// left-justified octet strings.
+    ///
+    /// [canonical order]: https://tools.ietf.org/html/rfc4034#section-6.1
+    fn cmp(&self, other: &Self) -> Ordering {
+        let label_1 = self.0.iter().map(u8::to_ascii_lowercase);
+        let label_2 = other.0.iter().map(u8::to_ascii_lowercase);
+        label_1.cmp(label_2)
+    }
+}
+
+impl PartialOrd for Label {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl Hash for Label {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.to_lowercase().hash(state);
+        self.0.to_ascii_lowercase().hash(state);
     }
 }
@@ -4,6 +4,8 @@
 
 mod decode;
 mod dns;
+#[cfg(feature = "dnssec")]
+pub mod dnssec;
 mod domain_name;
 mod encode;
 mod label;
@@ -12,12 +14,18 @@ mod macros;
 pub mod question;
 pub mod rr;
 mod subtypes;
+#[cfg(feature = "tsig")]
+pub mod tsig;
+pub mod update;
+pub mod zone;
 
-pub use decode::{DecodeError, DecodeResult};
-pub use dns::{Dns, Flags};
+pub use decode::{
+    view, DecodeError, DecodeErrorContext, DecodeOptions, DecodeResult, DecodeWarning, Section,
+};
+pub use dns::{Dns, Flags, EDNS_DEFAULT_PAYLOAD_SIZE};
 pub use domain_name::{DomainName, DomainNameError};
-pub use encode::{EncodeError, EncodeResult};
+pub use encode::{EncodeError, EncodeResult, Omitted};
 pub use label::{Label, LabelError};
-pub use subtypes::{Opcode, RCode};
+pub use subtypes::{Opcode, ParseNameError, RCode};
 
 pub const MAXIMUM_DNS_PACKET_SIZE: usize = 65536;
@@ -41,6 +41,23 @@ macro_rules! try_from_enum_to_integer {
                 }
             }
         }
+
+        impl std::str::FromStr for $enum {
+            type Err = crate::ParseNameError;
+
+            /// Parse the name of the variant, the case is ignored.
+            fn from_str(name: &str) -> Result<$enum, crate::ParseNameError> {
+                $(
+                    if name.eq_ignore_ascii_case(stringify!($variant)) {
+                        return Ok($enum::$variant);
+                    }
+                )*
+                Err(crate::ParseNameError {
+                    enum_name: stringify!($enum),
+                    name: name.to_owned(),
+                })
+            }
+        }
     }
 }
 
@@ -79,3 +96,88 @@ macro_rules! try_from_enum_to_integer_without_display {
         }
     }
 }
+
+macro_rules! enum_to_integer_with_unknown {
+    (
+        #[repr($integer:ident)]
+        #[unknown = $unknown_prefix:literal]
+        $(#[$meta:meta])*
+        $visibility:vis enum $enum:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:expr
+            ),*
+            $( , )?
+        }
+    ) => {
+        $(#[$meta])*
+        $visibility enum $enum {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value, which has no variant.
+            Unknown($integer),
+        }
+
+        impl From<$integer> for $enum {
+            fn from(value: $integer) -> $enum {
+                match value {
+                    $(
+                        $value => $enum::$variant,
+                    )*
+                    value => $enum::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$enum> for $integer {
+            fn from(value: $enum) -> $integer {
+                match value {
+                    $(
+                        $enum::$variant => $value,
+                    )*
+                    $enum::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl std::fmt::Display for $enum {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        $enum::$variant => write!(f, stringify!($variant)),
+                    )*
+                    $enum::Unknown(value) => write!(f, concat!($unknown_prefix, "{}"), value),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $enum {
+            type Err = crate::ParseNameError;
+
+            /// Parse the name of the variant or the prefix of the unknown values followed by the
+            /// value, the case is ignored.
+            fn from_str(name: &str) -> Result<$enum, crate::ParseNameError> {
+                $(
+                    if name.eq_ignore_ascii_case(stringify!($variant)) {
+                        return Ok($enum::$variant);
+                    }
+                )*
+                let prefix_len = $unknown_prefix.len();
+                if name.len() > prefix_len
+                    && name.is_char_boundary(prefix_len)
+                    && name[..prefix_len].eq_ignore_ascii_case($unknown_prefix)
+                {
+                    if let Ok(value) = name[prefix_len..].parse::<$integer>() {
+                        return Ok($enum::from(value));
+                    }
+                }
+                Err(crate::ParseNameError {
+                    enum_name: stringify!($enum),
+                    name: name.to_owned(),
+                })
+            }
+        }
+    }
+}
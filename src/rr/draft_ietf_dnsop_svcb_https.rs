@@ -169,19 +169,29 @@ impl ServiceParameter {
 }
 
 /// Escape backslashes and commas in an ALPN ID
+///
+/// The escape sequences of the value list are escaped again in the presentation format, see
+/// [RFC 9460 Appendix A.1].
+///
+/// [RFC 9460 Appendix A.1]: https://tools.ietf.org/html/rfc9460#appendix-A.1
 fn escape_alpn(alpn: &str) -> String {
     let mut result = String::new();
     for char in alpn.chars() {
         if char == '\\' {
             result.push_str("\\\\\\");
         } else if char == ',' {
-            result.push('\\');
+            result.push_str("\\\\");
         }
         result.push(char);
     }
     result
 }
 
+/// Returns `true` if the byte can be written in the presentation format without escaping.
+fn is_plain(byte: u8) -> bool {
+    matches!(byte, 0x21..=0x7e) && !matches!(byte, b'"' | b'\\' | b';' | b'(' | b')')
+}
+
 impl Display for ServiceParameter {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -242,27 +252,20 @@ impl Display for ServiceParameter {
             }
             ServiceParameter::PRIVATE { number, wire_data } => {
                 let key = format!("key{}", number);
-                let value = String::from_utf8(wire_data.clone());
-                if let Ok(value) = value {
+                if !wire_data.is_empty() && wire_data.iter().all(|byte| is_plain(*byte)) {
+                    // The bytes are printable ASCII characters.
+                    let value: String = wire_data.iter().map(|byte| *byte as char).collect();
                     write!(f, "{}={}", key, value)
                 } else {
-                    let mut escaped = vec![];
+                    write!(f, "{}=\"", key)?;
                     for byte in wire_data {
-                        if *byte < b'0'
-                            || (*byte > b'9' && *byte < b'A')
-                            || (*byte > b'Z' && *byte < b'a')
-                            || *byte > b'z'
-                        {
-                            escaped.extend_from_slice(format!("\\{}", *byte).as_bytes());
+                        if is_plain(*byte) {
+                            write!(f, "{}", *byte as char)?;
                         } else {
-                            escaped.push(*byte);
+                            write!(f, "\\{:03}", byte)?;
                         }
                     }
-                    if let Ok(value) = String::from_utf8(escaped) {
-                        write!(f, "{}=\"{}\"", key, value)
-                    } else {
-                        write!(f, "{}=\"{}\"", key, Base64Standard.encode(wire_data))
-                    }
+                    write!(f, "\"")
                 }
             }
             ServiceParameter::KEY_65535 => write!(f, "reserved"),
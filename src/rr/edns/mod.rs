@@ -1,11 +1,17 @@
+mod rfc_5001;
 pub(crate) mod rfc_6891;
+mod rfc_6975;
+mod rfc_7828;
 mod rfc_7830;
 mod rfc_7871;
 mod rfc_7873;
 mod rfc_8914;
 
 //pub use rfc_6891::OPT;
+pub use rfc_5001::NSID;
 pub use rfc_6891::{EDNSOption, EDNSOptionCode, EDNS_DNSSEC_MASK};
+pub use rfc_6975::{DAU, DHU, N3U};
+pub use rfc_7828::TcpKeepalive;
 pub use rfc_7830::Padding;
 pub use rfc_7871::ECS;
 pub use rfc_7873::{
@@ -0,0 +1,28 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The [name server identifier] option.
+///
+/// The identifier is displayed like dig does, as hex bytes followed by the printable ASCII
+/// characters, for example `NSID 67 70 64 6e 73 ("gpdns")`.
+///
+/// [name server identifier]: https://tools.ietf.org/html/rfc5001
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct NSID(pub Vec<u8>);
+
+impl Display for NSID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "NSID")?;
+        for b in &self.0 {
+            write!(f, " {:02x}", b)?;
+        }
+        write!(f, " (\"")?;
+        for b in &self.0 {
+            match b {
+                b'"' | b'\\' => write!(f, "\\{}", *b as char)?,
+                0x20..=0x7e => write!(f, "{}", *b as char)?,
+                _ => write!(f, ".")?,
+            }
+        }
+        write!(f, "\")")
+    }
+}
@@ -1,4 +1,6 @@
-use super::{Cookie, ExtendedDNSErrors, Padding, ECS};
+use super::{Cookie, ExtendedDNSErrors, Padding, TcpKeepalive, DAU, DHU, ECS, N3U, NSID};
+use crate::DomainName;
+use hex::encode;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 pub const EDNS_DNSSEC_MASK: u8 = 0x80;
@@ -7,28 +9,74 @@ try_from_enum_to_integer! {
     #[repr(u16)]
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub enum EDNSOptionCode {
+        NSID = 0x0003,
+        DAU = 0x0005,
+        DHU = 0x0006,
+        N3U = 0x0007,
         ECS = 0x00008,
+        Expire = 0x0009,
         Cookie = 0x000a,
+        TcpKeepalive = 0x000b,
         Padding = 0x000c,
+        Chain = 0x000d,
         ExtendedDnsError = 0x000f,
     }
 }
 
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
 pub enum EDNSOption {
+    NSID(NSID),
+    DAU(DAU),
+    DHU(DHU),
+    N3U(N3U),
     ECS(ECS),
+    /// The [Expire] option, which is empty in a request and contains the expire timer of the
+    /// SOA record in a response.
+    ///
+    /// [Expire]: https://tools.ietf.org/html/rfc7314
+    Expire(Option<u32>),
     Cookie(Cookie),
+    TcpKeepalive(TcpKeepalive),
     Padding(Padding),
+    /// The [CHAIN] option, which contains the closest trust point.
+    ///
+    /// [CHAIN]: https://tools.ietf.org/html/rfc7901
+    Chain(DomainName),
     ExtendedDNSErrors(ExtendedDNSErrors),
+    /// An EDNS option, which does not have a dedicated struct.
+    ///
+    /// The data is kept as opaque bytes and is displayed in the format
+    /// `OPTION<code> \# <length> <hex>`, which is similar to the [generic format] of RFC 3597.
+    ///
+    /// [generic format]: https://tools.ietf.org/html/rfc3597#section-5
+    Unknown {
+        code: u16,
+        data: Vec<u8>,
+    },
 }
 
 impl Display for EDNSOption {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
+            EDNSOption::NSID(nsid) => nsid.fmt(f),
+            EDNSOption::DAU(dau) => dau.fmt(f),
+            EDNSOption::DHU(dhu) => dhu.fmt(f),
+            EDNSOption::N3U(n3u) => n3u.fmt(f),
             EDNSOption::ECS(ecs) => ecs.fmt(f),
+            EDNSOption::Expire(Some(expire)) => write!(f, "Expire {}", expire),
+            EDNSOption::Expire(None) => write!(f, "Expire"),
             EDNSOption::Cookie(cookie) => cookie.fmt(f),
+            EDNSOption::TcpKeepalive(tcp_keepalive) => tcp_keepalive.fmt(f),
             EDNSOption::Padding(padding) => padding.fmt(f),
+            EDNSOption::Chain(closest_trust_point) => write!(f, "Chain {}", closest_trust_point),
             EDNSOption::ExtendedDNSErrors(extended_dns_errors) => extended_dns_errors.fmt(f),
+            EDNSOption::Unknown { code, data } => {
+                write!(f, "OPTION{} \\# {}", code, data.len())?;
+                if !data.is_empty() {
+                    write!(f, " {}", encode(data))?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -36,6 +84,9 @@ impl Display for EDNSOption {
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
 pub struct OPT {
     pub requestor_payload_size: u16,
+    /// The upper 8 bits of the extended RCODE, see [`Dns::rcode`].
+    ///
+    /// [`Dns::rcode`]: crate::Dns::rcode
     pub extend_rcode: u8,
     pub version: u8,
     pub dnssec: bool,
@@ -46,7 +97,6 @@ impl_to_type!(OPT);
 
 impl Display for OPT {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        // TODO extend_rcode
         write!(
             f,
             ". OPT {} {} {} {}",
@@ -0,0 +1,59 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The [DNSSEC Algorithm Understood] option, which lists the supported DNSSEC algorithms.
+///
+/// The algorithms are kept as raw codes, because a client can list algorithms, which are not
+/// known to this library (see [`AlgorithmType`]).
+///
+/// [`AlgorithmType`]: crate::rr::AlgorithmType
+/// [DNSSEC Algorithm Understood]: https://tools.ietf.org/html/rfc6975#section-3
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct DAU(pub Vec<u8>);
+
+impl Display for DAU {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "DAU")?;
+        for algorithm_type in &self.0 {
+            write!(f, " {}", algorithm_type)?;
+        }
+        Ok(())
+    }
+}
+
+/// The [DS Hash Understood] option, which lists the supported DS hash algorithms.
+///
+/// The hash algorithms are kept as raw codes (see [`DigestType`]).
+///
+/// [`DigestType`]: crate::rr::DigestType
+/// [DS Hash Understood]: https://tools.ietf.org/html/rfc6975#section-3
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct DHU(pub Vec<u8>);
+
+impl Display for DHU {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "DHU")?;
+        for digest_type in &self.0 {
+            write!(f, " {}", digest_type)?;
+        }
+        Ok(())
+    }
+}
+
+/// The [NSEC3 Hash Understood] option, which lists the supported NSEC3 hash algorithms.
+///
+/// The hash algorithms are kept as raw codes (see [`NSEC3HashAlgorithm`]).
+///
+/// [`NSEC3HashAlgorithm`]: crate::rr::NSEC3HashAlgorithm
+/// [NSEC3 Hash Understood]: https://tools.ietf.org/html/rfc6975#section-3
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct N3U(pub Vec<u8>);
+
+impl Display for N3U {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "N3U")?;
+        for hash_algorithm in &self.0 {
+            write!(f, " {}", hash_algorithm)?;
+        }
+        Ok(())
+    }
+}
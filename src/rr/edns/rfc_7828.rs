@@ -0,0 +1,20 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The [TCP keepalive] option.
+///
+/// The timeout is in units of 100 milliseconds. It is omitted in queries and present in
+/// responses.
+///
+/// [TCP keepalive]: https://tools.ietf.org/html/rfc7828
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct TcpKeepalive(pub Option<u16>);
+
+impl Display for TcpKeepalive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "TcpKeepalive")?;
+        if let Some(timeout) = self.0 {
+            write!(f, " {}", timeout)?;
+        }
+        Ok(())
+    }
+}
@@ -1,9 +1,11 @@
 pub use super::{
-    A, AAAA, AFSDB, APL, CAA, CNAME, DNAME, DNSKEY, DS, EID, EUI48, EUI64, GPOS, HINFO, ISDN, KX,
-    L32, L64, LOC, LP, MB, MD, MF, MG, MINFO, MR, MX, NID, NIMLOC, NS, NSAP, NULL, OPT, PTR, PX,
-    RP, RT, SOA, SRV, SSHFP, TXT, URI, WKS, X25,
+    Unknown, A, AAAA, AFSDB, APL, CAA, CNAME, DNAME, DNSKEY, DS, EID, EUI48, EUI64, GPOS, HINFO,
+    ISDN, KX, L32, L64, LOC, LP, MB, MD, MF, MG, MINFO, MR, MX, NAPTR, NID, NIMLOC, NS, NSAP, NSEC,
+    NSEC3, NSEC3PARAM, NULL, OPT, PTR, PX, RP, RRSIG, RT, SMIMEA, SOA, SRV, SSHFP, TLSA, TSIG, TXT,
+    URI, WKS, X25,
 };
 use crate::rr::draft_ietf_dnsop_svcb_https::ServiceBinding;
+use crate::DomainName;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 try_from_enum_to_integer! {
@@ -22,6 +24,14 @@ try_from_enum_to_integer! {
         CH = 3,
         /// The Hesiod class.
         HS = 4,
+        /// The [NONE] class, which is used in UPDATE messages.
+        ///
+        /// [NONE]: https://tools.ietf.org/html/rfc2136#section-2.4
+        NONE = 254,
+        /// The [ANY] class, which is used in UPDATE messages and by TSIG records.
+        ///
+        /// [ANY]: https://tools.ietf.org/html/rfc2136#section-2.4
+        ANY = 255,
     }
 }
 
@@ -31,7 +41,7 @@ try_from_enum_to_integer! {
     ///
     /// [type]: https://tools.ietf.org/html/rfc1035#section-3.2.2
     /// [resource records]: crate::rr::RR
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub enum Type {
         /// The [IPv4] [host address] type.
         ///
@@ -171,6 +181,9 @@ try_from_enum_to_integer! {
         DS = 43,
         SSHFP = 44,
         IPSECKEY = 45,
+        /// The [RRSIG] type.
+        ///
+        /// [RRSIG]: https://tools.ietf.org/html/rfc4034#section-3
         RRSIG = 46,
         NSEC = 47,
         /// The [DNSKEY] type.
@@ -187,7 +200,13 @@ try_from_enum_to_integer! {
         NINFO = 56,
         RKEY = 57,
         TALINK = 58,
+        /// The [CDS] type.
+        ///
+        /// [CDS]: https://tools.ietf.org/html/rfc7344#section-3.1
         CDS = 59,
+        /// The [CDNSKEY] type.
+        ///
+        /// [CDNSKEY]: https://tools.ietf.org/html/rfc7344#section-3.2
         CDNSKEY = 60,
         OPENPGPKEY = 61,
         CSYNC = 62,
@@ -284,6 +303,17 @@ pub enum RR {
     CAA(CAA),
     SVCB(ServiceBinding),
     HTTPS(ServiceBinding),
+    RRSIG(RRSIG),
+    NSEC(NSEC),
+    NSEC3(NSEC3),
+    NSEC3PARAM(NSEC3PARAM),
+    TLSA(TLSA),
+    SMIMEA(SMIMEA),
+    NAPTR(NAPTR),
+    CDS(DS),
+    CDNSKEY(DNSKEY),
+    TSIG(TSIG),
+    Unknown(Unknown),
 }
 
 impl RR {
@@ -335,6 +365,142 @@ impl RR {
             RR::CAA(caa) => Some(caa.ttl),
             RR::SVCB(svcb) => Some(svcb.ttl),
             RR::HTTPS(https) => Some(https.ttl),
+            RR::RRSIG(rrsig) => Some(rrsig.ttl),
+            RR::NSEC(nsec) => Some(nsec.ttl),
+            RR::NSEC3(nsec3) => Some(nsec3.ttl),
+            RR::NSEC3PARAM(nsec3param) => Some(nsec3param.ttl),
+            RR::TLSA(tlsa) => Some(tlsa.ttl),
+            RR::SMIMEA(smimea) => Some(smimea.ttl),
+            RR::NAPTR(naptr) => Some(naptr.ttl),
+            RR::CDS(cds) => Some(cds.ttl),
+            RR::CDNSKEY(cdnskey) => Some(cdnskey.ttl),
+            RR::TSIG(_) => None,
+            RR::Unknown(unknown) => Some(unknown.ttl),
+        }
+    }
+
+    pub fn get_domain_name(&self) -> Option<&DomainName> {
+        match self {
+            RR::A(a) => Some(&a.domain_name),
+            RR::NS(ns) => Some(&ns.domain_name),
+            RR::MD(md) => Some(&md.domain_name),
+            RR::MF(mf) => Some(&mf.domain_name),
+            RR::CNAME(c_name) => Some(&c_name.domain_name),
+            RR::SOA(soa) => Some(&soa.domain_name),
+            RR::MB(mb) => Some(&mb.domain_name),
+            RR::MG(mg) => Some(&mg.domain_name),
+            RR::MR(mr) => Some(&mr.domain_name),
+            RR::NULL(null) => Some(&null.domain_name),
+            RR::WKS(wks) => Some(&wks.domain_name),
+            RR::PTR(ptr) => Some(&ptr.domain_name),
+            RR::HINFO(h_info) => Some(&h_info.domain_name),
+            RR::MINFO(m_info) => Some(&m_info.domain_name),
+            RR::MX(mx) => Some(&mx.domain_name),
+            RR::TXT(txt) => Some(&txt.domain_name),
+            RR::RP(rp) => Some(&rp.domain_name),
+            RR::AFSDB(afsdb) => Some(&afsdb.domain_name),
+            RR::X25(x_25) => Some(&x_25.domain_name),
+            RR::ISDN(isdn) => Some(&isdn.domain_name),
+            RR::RT(rt) => Some(&rt.domain_name),
+            RR::NSAP(nsap) => Some(&nsap.domain_name),
+            RR::PX(px) => Some(&px.domain_name),
+            RR::GPOS(gpos) => Some(&gpos.domain_name),
+            RR::AAAA(aaaa) => Some(&aaaa.domain_name),
+            RR::LOC(loc) => Some(&loc.domain_name),
+            RR::NIMLOC(nim_loc) => Some(&nim_loc.domain_name),
+            RR::SRV(srv) => Some(&srv.domain_name),
+            RR::KX(kx) => Some(&kx.domain_name),
+            RR::DNAME(d_name) => Some(&d_name.domain_name),
+            RR::OPT(_) => None,
+            RR::APL(apl) => Some(&apl.domain_name),
+            RR::SSHFP(ssh_fp) => Some(&ssh_fp.domain_name),
+            RR::NID(nid) => Some(&nid.domain_name),
+            RR::L32(l_32) => Some(&l_32.domain_name),
+            RR::L64(l_64) => Some(&l_64.domain_name),
+            RR::LP(lp) => Some(&lp.domain_name),
+            RR::EUI48(eui_48) => Some(&eui_48.domain_name),
+            RR::EUI64(eui_64) => Some(&eui_64.domain_name),
+            RR::URI(uri) => Some(&uri.domain_name),
+            RR::EID(eid) => Some(&eid.domain_name),
+            RR::DS(ds) => Some(&ds.domain_name),
+            RR::DNSKEY(dnskey) => Some(&dnskey.domain_name),
+            RR::CAA(caa) => Some(&caa.domain_name),
+            RR::SVCB(svcb) => Some(&svcb.name),
+            RR::HTTPS(https) => Some(&https.name),
+            RR::RRSIG(rrsig) => Some(&rrsig.domain_name),
+            RR::NSEC(nsec) => Some(&nsec.domain_name),
+            RR::NSEC3(nsec3) => Some(&nsec3.domain_name),
+            RR::NSEC3PARAM(nsec3param) => Some(&nsec3param.domain_name),
+            RR::TLSA(tlsa) => Some(&tlsa.domain_name),
+            RR::SMIMEA(smimea) => Some(&smimea.domain_name),
+            RR::NAPTR(naptr) => Some(&naptr.domain_name),
+            RR::CDS(cds) => Some(&cds.domain_name),
+            RR::CDNSKEY(cdnskey) => Some(&cdnskey.domain_name),
+            RR::TSIG(tsig) => Some(&tsig.domain_name),
+            RR::Unknown(unknown) => Some(&unknown.domain_name),
+        }
+    }
+
+    /// Returns the type code of the resource record.
+    pub fn get_type_code(&self) -> u16 {
+        match self {
+            RR::A(_) => Type::A as u16,
+            RR::NS(_) => Type::NS as u16,
+            RR::MD(_) => Type::MD as u16,
+            RR::MF(_) => Type::MF as u16,
+            RR::CNAME(_) => Type::CNAME as u16,
+            RR::SOA(_) => Type::SOA as u16,
+            RR::MB(_) => Type::MB as u16,
+            RR::MG(_) => Type::MG as u16,
+            RR::MR(_) => Type::MR as u16,
+            RR::NULL(_) => Type::NULL as u16,
+            RR::WKS(_) => Type::WKS as u16,
+            RR::PTR(_) => Type::PTR as u16,
+            RR::HINFO(_) => Type::HINFO as u16,
+            RR::MINFO(_) => Type::MINFO as u16,
+            RR::MX(_) => Type::MX as u16,
+            RR::TXT(_) => Type::TXT as u16,
+            RR::RP(_) => Type::RP as u16,
+            RR::AFSDB(_) => Type::AFSDB as u16,
+            RR::X25(_) => Type::X25 as u16,
+            RR::ISDN(_) => Type::ISDN as u16,
+            RR::RT(_) => Type::RT as u16,
+            RR::NSAP(_) => Type::NSAP as u16,
+            RR::PX(_) => Type::PX as u16,
+            RR::GPOS(_) => Type::GPOS as u16,
+            RR::AAAA(_) => Type::AAAA as u16,
+            RR::LOC(_) => Type::LOC as u16,
+            RR::NIMLOC(_) => Type::NIMLOC as u16,
+            RR::SRV(_) => Type::SRV as u16,
+            RR::KX(_) => Type::KX as u16,
+            RR::DNAME(_) => Type::DNAME as u16,
+            RR::OPT(_) => Type::OPT as u16,
+            RR::APL(_) => Type::APL as u16,
+            RR::SSHFP(_) => Type::SSHFP as u16,
+            RR::URI(_) => Type::URI as u16,
+            RR::EID(_) => Type::EID as u16,
+            RR::NID(_) => Type::NID as u16,
+            RR::L32(_) => Type::L32 as u16,
+            RR::L64(_) => Type::L64 as u16,
+            RR::LP(_) => Type::LP as u16,
+            RR::EUI48(_) => Type::EUI48 as u16,
+            RR::EUI64(_) => Type::EUI64 as u16,
+            RR::DS(_) => Type::DS as u16,
+            RR::DNSKEY(_) => Type::DNSKEY as u16,
+            RR::CAA(_) => Type::CAA as u16,
+            RR::SVCB(_) => Type::SVCB as u16,
+            RR::HTTPS(_) => Type::HTTPS as u16,
+            RR::RRSIG(_) => Type::RRSIG as u16,
+            RR::NSEC(_) => Type::NSEC as u16,
+            RR::NSEC3(_) => Type::NSEC3 as u16,
+            RR::NSEC3PARAM(_) => Type::NSEC3PARAM as u16,
+            RR::TLSA(_) => Type::TLSA as u16,
+            RR::SMIMEA(_) => Type::SMIMEA as u16,
+            RR::NAPTR(_) => Type::NAPTR as u16,
+            RR::CDS(_) => Type::CDS as u16,
+            RR::CDNSKEY(_) => Type::CDNSKEY as u16,
+            RR::TSIG(_) => Type::TSIG as u16,
+            RR::Unknown(unknown) => unknown.type_code,
         }
     }
 
@@ -386,6 +552,17 @@ impl RR {
             RR::CAA(caa) => Some(caa.class),
             RR::SVCB(_) => Some(Class::IN),
             RR::HTTPS(_) => Some(Class::IN),
+            RR::RRSIG(rrsig) => Some(rrsig.class),
+            RR::NSEC(nsec) => Some(nsec.class),
+            RR::NSEC3(nsec3) => Some(nsec3.class),
+            RR::NSEC3PARAM(nsec3param) => Some(nsec3param.class),
+            RR::TLSA(tlsa) => Some(tlsa.class),
+            RR::SMIMEA(smimea) => Some(smimea.class),
+            RR::NAPTR(naptr) => Some(naptr.class),
+            RR::CDS(cds) => Some(cds.class),
+            RR::CDNSKEY(cdnskey) => Some(cdnskey.class),
+            RR::TSIG(_) => None,
+            RR::Unknown(unknown) => Some(unknown.class),
         }
     }
 }
@@ -439,6 +616,17 @@ impl Display for RR {
             RR::CAA(caa) => caa.fmt(f),
             RR::SVCB(svcb) => svcb.fmt(f),
             RR::HTTPS(https) => https.fmt(f),
+            RR::RRSIG(rrsig) => rrsig.fmt(f),
+            RR::NSEC(nsec) => nsec.fmt(f),
+            RR::NSEC3(nsec3) => nsec3.fmt(f),
+            RR::NSEC3PARAM(nsec3param) => nsec3param.fmt(f),
+            RR::TLSA(tlsa) => tlsa.fmt(f),
+            RR::SMIMEA(smimea) => smimea.fmt(f),
+            RR::NAPTR(naptr) => naptr.fmt(f),
+            RR::CDS(cds) => cds.fmt_with_type(f, Type::CDS),
+            RR::CDNSKEY(cdnskey) => cdnskey.fmt_with_type(f, Type::CDNSKEY),
+            RR::TSIG(tsig) => tsig.fmt(f),
+            RR::Unknown(unknown) => unknown.fmt(f),
         }
     }
 }
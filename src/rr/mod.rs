@@ -55,14 +55,20 @@ mod rfc_2163;
 mod rfc_2230;
 mod rfc_2782;
 mod rfc_3123;
+mod rfc_3403;
 mod rfc_3596;
+mod rfc_3597;
 mod rfc_3658;
 mod rfc_4034;
+mod rfc_5155;
 mod rfc_6672;
+mod rfc_6698;
 mod rfc_6742;
 mod rfc_7043;
 mod rfc_7553;
+mod rfc_8162;
 mod rfc_8659;
+mod rfc_8945;
 mod subtypes;
 #[cfg(test)]
 mod tests;
@@ -83,15 +89,22 @@ pub use rfc_2163::PX;
 pub use rfc_2230::KX;
 pub use rfc_2782::SRV;
 pub use rfc_3123::{APItem, APL, APL_NEGATION_MASK};
+pub use rfc_3403::NAPTR;
 pub use rfc_3596::AAAA;
+pub use rfc_3597::Unknown;
 pub use rfc_3658::{SSHFPAlgorithm, SSHFPType, SSHFP};
 pub use rfc_4034::{
-    AlgorithmType, DigestType, DNSKEY, DNSKEY_ZERO_MASK, DS, SECURE_ENTRY_POINT_FLAG, ZONE_KEY_FLAG,
+    sort_rrset, AlgorithmType, DigestType, DNSKEY, DNSKEY_ZERO_MASK, DS, NSEC, RRSIG,
+    SECURE_ENTRY_POINT_FLAG, ZONE_KEY_FLAG,
 };
+pub use rfc_5155::{NSEC3HashAlgorithm, NSEC3, NSEC3PARAM, NSEC3_OPT_OUT_FLAG};
 pub use rfc_6672::DNAME;
+pub use rfc_6698::{TLSACertificateUsage, TLSAMatchingType, TLSASelector, TLSA};
 pub use rfc_6742::{L32, L64, LP, NID};
 pub use rfc_7043::{EUI48, EUI64};
 pub use rfc_7553::URI;
+pub use rfc_8162::SMIMEA;
 pub use rfc_8659::{Tag, TagError, CAA};
-pub use subtypes::{Address, AddressError, AddressFamilyNumber, NonEmptyVec};
+pub use rfc_8945::{TSIG, TSIG_CLASS};
+pub use subtypes::{Address, AddressError, AddressFamilyNumber, NonEmptyVec, TypeBitMap};
 pub use unknown::{EID, NIMLOC};
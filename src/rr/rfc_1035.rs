@@ -1,5 +1,7 @@
+use super::subtypes::fmt_character_string;
 use super::{Class, NonEmptyVec};
 use crate::DomainName;
+use hex::encode;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::net::Ipv4Addr;
 
@@ -142,14 +144,29 @@ pub struct WKS {
 
 impl_to_type!(WKS);
 
+/// The maximum length of the bit map, which covers all ports.
+const WKS_BIT_MAP_MAX_LENGTH: usize = 8192;
+
 impl Display for WKS {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        // TODO
-        write!(
-            f,
-            "{} {} IN WKS {} {:x?}",
-            self.domain_name, self.ttl, self.protocol, self.bit_map
-        )
+        write!(f, "{} {} IN WKS ", self.domain_name, self.ttl)?;
+        // The list of ports can not express trailing zero bytes of the bit map, therefore the
+        // generic format is used.
+        if self.bit_map.last() == Some(&0) || self.bit_map.len() > WKS_BIT_MAP_MAX_LENGTH {
+            let mut rdata = self.ipv4_addr.octets().to_vec();
+            rdata.push(self.protocol);
+            rdata.extend_from_slice(&self.bit_map);
+            return write!(f, "\\# {} {}", rdata.len(), encode(rdata));
+        }
+        write!(f, "{} {}", self.ipv4_addr, self.protocol)?;
+        for (index, byte) in self.bit_map.iter().enumerate() {
+            for bit in 0..8 {
+                if byte & (0b1000_0000 >> bit) != 0 {
+                    write!(f, " {}", index * 8 + bit)?;
+                }
+            }
+        }
+        Ok(())
     }
 }
 
@@ -177,12 +194,10 @@ impl_to_type!(HINFO);
 
 impl Display for HINFO {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        // TODO
-        write!(
-            f,
-            "{} {} {} HINFO {} {}",
-            self.domain_name, self.ttl, self.class, self.cpu, self.os,
-        )
+        write!(f, "{} {} {} HINFO ", self.domain_name, self.ttl, self.class)?;
+        fmt_character_string(f, self.cpu.as_bytes())?;
+        write!(f, " ")?;
+        fmt_character_string(f, self.os.as_bytes())
     }
 }
 
@@ -206,6 +221,9 @@ struct_u16_domain_name!(
 
 /// The [text] resource record type.
 ///
+/// The strings have to be valid UTF-8. A TXT record with other bytes, like `\255` in a zone
+/// file, cannot be decoded or parsed.
+///
 /// [text]: https://tools.ietf.org/html/rfc1035#section-3.3.14
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TXT {
@@ -221,7 +239,8 @@ impl Display for TXT {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{} {} {} TXT", self.domain_name, self.ttl, self.class,)?;
         for string in self.strings.iter() {
-            write!(f, " \"{}\"", string.escape_default())?;
+            write!(f, " ")?;
+            fmt_character_string(f, string.as_bytes())?;
         }
         Ok(())
     }
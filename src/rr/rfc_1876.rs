@@ -1,5 +1,6 @@
 use super::Class;
 use crate::DomainName;
+use hex::encode;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 /// The [location information] resource record type.
@@ -21,21 +22,88 @@ pub struct LOC {
 
 impl_to_type!(LOC);
 
+/// The value of the equator and the prime meridian, see [RFC 1876 Section 2].
+///
+/// [RFC 1876 Section 2]: https://tools.ietf.org/html/rfc1876#section-2
+const LOC_EQUATOR: i64 = 1 << 31;
+/// The altitude is relative to 100,000m below the WGS 84 reference spheroid.
+const LOC_ALTITUDE_BASE: i64 = 10_000_000;
+/// The number of thousandths of a second of arc of one degree.
+const LOC_DEGREE: i64 = 3_600_000;
+
+/// Returns `true` if the size or the precision has a mantissa and an exponent between 0 and 9,
+/// which has a unique presentation.
+fn is_precision_valid(precision: u8) -> bool {
+    let mantissa = precision >> 4;
+    let exponent = precision & 0x0f;
+    mantissa <= 9 && exponent <= 9 && (mantissa != 0 || exponent == 0)
+}
+
+fn fmt_centimeters(f: &mut Formatter<'_>, centimeters: i64) -> FmtResult {
+    let sign = if centimeters < 0 { "-" } else { "" };
+    let centimeters = centimeters.abs();
+    write!(f, "{}{}.{:02}m", sign, centimeters / 100, centimeters % 100)
+}
+
+fn fmt_precision(f: &mut Formatter<'_>, precision: u8) -> FmtResult {
+    let mantissa = (precision >> 4) as i64;
+    let exponent = (precision & 0x0f) as u32;
+    fmt_centimeters(f, mantissa * 10i64.pow(exponent))
+}
+
+fn fmt_coordinate(f: &mut Formatter<'_>, value: u32, hemispheres: (char, char)) -> FmtResult {
+    let value = value as i64 - LOC_EQUATOR;
+    let hemisphere = if value < 0 {
+        hemispheres.1
+    } else {
+        hemispheres.0
+    };
+    let value = value.abs();
+    write!(
+        f,
+        "{} {} {}.{:03} {}",
+        value / LOC_DEGREE,
+        (value % LOC_DEGREE) / 60_000,
+        (value % 60_000) / 1_000,
+        value % 1_000,
+        hemisphere
+    )
+}
+
+impl LOC {
+    /// Returns `true` if the values can be written in the presentation format of [RFC 1876].
+    ///
+    /// [RFC 1876]: https://tools.ietf.org/html/rfc1876#section-3
+    fn is_presentable(&self) -> bool {
+        self.version == 0
+            && is_precision_valid(self.size)
+            && is_precision_valid(self.horiz_pre)
+            && is_precision_valid(self.vert_pre)
+            && (self.latitube as i64 - LOC_EQUATOR).abs() <= 90 * LOC_DEGREE
+            && (self.longitube as i64 - LOC_EQUATOR).abs() <= 180 * LOC_DEGREE
+    }
+}
+
 impl Display for LOC {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(
-            f,
-            "{} {} {} LOC {} {} {} {} {} {} {}",
-            self.domain_name,
-            self.ttl,
-            self.class,
-            self.version,
-            self.size,
-            self.horiz_pre,
-            self.vert_pre,
-            self.latitube,
-            self.longitube,
-            self.altitube,
-        )
+        write!(f, "{} {} {} LOC ", self.domain_name, self.ttl, self.class)?;
+        if !self.is_presentable() {
+            let mut rdata = vec![self.version, self.size, self.horiz_pre, self.vert_pre];
+            rdata.extend_from_slice(&self.latitube.to_be_bytes());
+            rdata.extend_from_slice(&self.longitube.to_be_bytes());
+            rdata.extend_from_slice(&self.altitube.to_be_bytes());
+            return write!(f, "\\# {} {}", rdata.len(), encode(rdata));
+        }
+        fmt_coordinate(f, self.latitube, ('N', 'S'))?;
+        write!(f, " ")?;
+        fmt_coordinate(f, self.longitube, ('E', 'W'))?;
+        write!(f, " ")?;
+        fmt_centimeters(f, self.altitube as i64 - LOC_ALTITUDE_BASE)?;
+        write!(f, " ")?;
+        fmt_precision(f, self.size)?;
+        write!(f, " ")?;
+        fmt_precision(f, self.horiz_pre)?;
+        write!(f, " ")?;
+        fmt_precision(f, self.vert_pre)
     }
 }
@@ -0,0 +1,38 @@
+use super::subtypes::fmt_character_string;
+use super::Class;
+use crate::DomainName;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The [naming authority pointer] resource record type.
+///
+/// [naming authority pointer]: https://tools.ietf.org/html/rfc3403#section-4
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NAPTR {
+    pub domain_name: DomainName,
+    pub ttl: u32,
+    pub class: Class,
+    pub order: u16,
+    pub preference: u16,
+    pub flags: String,
+    pub services: String,
+    pub regexp: String,
+    pub replacement: DomainName,
+}
+
+impl_to_type!(NAPTR);
+
+impl Display for NAPTR {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} {} {} NAPTR {} {} ",
+            self.domain_name, self.ttl, self.class, self.order, self.preference,
+        )?;
+        fmt_character_string(f, self.flags.as_bytes())?;
+        write!(f, " ")?;
+        fmt_character_string(f, self.services.as_bytes())?;
+        write!(f, " ")?;
+        fmt_character_string(f, self.regexp.as_bytes())?;
+        write!(f, " {}", self.replacement)
+    }
+}
@@ -0,0 +1,32 @@
+use super::subtypes::fmt_type_code;
+use super::Class;
+use crate::DomainName;
+use hex::encode;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A resource record of an [unknown type].
+///
+/// The RDATA is kept as opaque bytes, which makes it possible to decode and encode resource
+/// records, which do not have a dedicated struct.
+///
+/// [unknown type]: https://tools.ietf.org/html/rfc3597
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Unknown {
+    pub domain_name: DomainName,
+    pub ttl: u32,
+    pub class: Class,
+    pub type_code: u16,
+    pub rdata: Vec<u8>,
+}
+
+impl Display for Unknown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} {} {} ", self.domain_name, self.ttl, self.class)?;
+        fmt_type_code(f, self.type_code)?;
+        write!(f, " \\# {}", self.rdata.len())?;
+        if !self.rdata.is_empty() {
+            write!(f, " {}", encode(&self.rdata))?;
+        }
+        Ok(())
+    }
+}
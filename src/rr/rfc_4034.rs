@@ -1,5 +1,7 @@
-use crate::rr::Class;
-use crate::DomainName;
+use super::subtypes::{fmt_type_code, TypeBitMap};
+use crate::rr::{Class, Type, RR};
+use crate::{DomainName, EncodeResult};
+use base64::{engine::general_purpose::STANDARD as Base64Standard, Engine};
 use hex::encode;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
@@ -27,6 +29,7 @@ try_from_enum_to_integer! {
         DsaNsec3 = 0x06,
         RsaSha1Nsec3Sha1 = 0x07,
         RsaSha256 = 0x08,
+        RsaSha512 = 0x0a,
         GostR = 0x0c,
         EcDsaP256 = 0x0d,
         EcDsaP386 = 0x0e,
@@ -73,23 +76,67 @@ impl DNSKEY {
         }
         flags
     }
-}
 
-impl Display for DNSKEY {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    /// Returns `true` if this is the [delete sentinel] of a CDNSKEY resource record
+    /// (`0 3 0 AA==`).
+    ///
+    /// [delete sentinel]: https://tools.ietf.org/html/rfc8078#section-4
+    pub fn is_delete(&self) -> bool {
+        self.get_flags() == 0
+            && self.algorithm_type == AlgorithmType::Reserved
+            && self.public_key == [0]
+    }
+
+    /// Returns the key tag, see [RFC 4034 Appendix B].
+    ///
+    /// [RFC 4034 Appendix B]: https://tools.ietf.org/html/rfc4034#appendix-B
+    pub fn key_tag(&self) -> u16 {
+        if self.algorithm_type == AlgorithmType::RsaMd5 {
+            // The key tag is the most significant 16 bits of the least significant 24 bits of
+            // the modulus, see RFC 4034 Appendix B.1.
+            let public_key_len = self.public_key.len();
+            if public_key_len < 3 {
+                return 0;
+            }
+            let b_1 = self.public_key[public_key_len - 3] as u16;
+            let b_2 = self.public_key[public_key_len - 2] as u16;
+            return (b_1 << 8) | b_2;
+        }
+
+        let rdata = self.encode_rdata();
+        let mut accumulator: u32 = 0;
+        for (index, b) in rdata.iter().enumerate() {
+            if index & 1 == 0 {
+                accumulator += (*b as u32) << 8;
+            } else {
+                accumulator += *b as u32;
+            }
+        }
+        accumulator += (accumulator >> 16) & 0xffff;
+        (accumulator & 0xffff) as u16
+    }
+
+    pub(super) fn fmt_with_type(&self, f: &mut Formatter<'_>, type_: Type) -> FmtResult {
         write!(
             f,
-            "{} {} {} DNSKEY {} 3 {} {}",
+            "{} {} {} {} {} 3 {} {}",
             self.domain_name,
             self.ttl,
             self.class,
+            type_,
             self.get_flags(),
             self.algorithm_type as u8,
-            encode(&self.public_key),
+            Base64Standard.encode(&self.public_key),
         )
     }
 }
 
+impl Display for DNSKEY {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_with_type(f, Type::DNSKEY)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
 pub struct DS {
     pub domain_name: DomainName,
@@ -101,14 +148,25 @@ pub struct DS {
     pub digest: Vec<u8>,
 }
 
-impl Display for DS {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+impl DS {
+    /// Returns `true` if this is the [delete sentinel] of a CDS resource record (`0 0 0 00`).
+    ///
+    /// [delete sentinel]: https://tools.ietf.org/html/rfc8078#section-4
+    pub fn is_delete(&self) -> bool {
+        self.key_tag == 0
+            && self.algorithm_type == AlgorithmType::Reserved
+            && self.digest_type == DigestType::Reserved
+            && self.digest == [0]
+    }
+
+    pub(super) fn fmt_with_type(&self, f: &mut Formatter<'_>, type_: Type) -> FmtResult {
         write!(
             f,
-            "{} {} {} DS {} {} {} {}",
+            "{} {} {} {} {} {} {} {}",
             self.domain_name,
             self.ttl,
             self.class,
+            type_,
             self.key_tag,
             self.algorithm_type as u8,
             self.digest_type as u8,
@@ -116,3 +174,135 @@ impl Display for DS {
         )
     }
 }
+
+impl Display for DS {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_with_type(f, Type::DS)
+    }
+}
+
+/// Write the timestamp in the `YYYYMMDDHHmmSS` format, see [RFC 4034 Section 3.2].
+///
+/// [RFC 4034 Section 3.2]: https://tools.ietf.org/html/rfc4034#section-3.2
+fn fmt_timestamp(f: &mut Formatter<'_>, timestamp: u32) -> FmtResult {
+    let timestamp = timestamp as u64;
+    let days = timestamp / 86400;
+    let seconds = timestamp % 86400;
+    // Convert the days since 1970-01-01 into a civil date.
+    let days = days + 719468;
+    let era = days / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    write!(
+        f,
+        "{:04}{:02}{:02}{:02}{:02}{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        (seconds % 3600) / 60,
+        seconds % 60
+    )
+}
+
+/// The [resource record signature] resource record type.
+///
+/// [resource record signature]: https://tools.ietf.org/html/rfc4034#section-3
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct RRSIG {
+    pub domain_name: DomainName,
+    pub ttl: u32,
+    pub class: Class,
+    /// The code of the type, which is covered by the RRSIG.
+    ///
+    /// The code is kept as an integer, because it can be a type, which is not known by the
+    /// [`Type`] enum.
+    pub type_covered: u16,
+    /// The code of the algorithm, which can be an algorithm, which is not known by the
+    /// [`AlgorithmType`] enum.
+    pub algorithm_type: u8,
+    pub labels: u8,
+    pub original_ttl: u32,
+    pub signature_expiration: u32,
+    pub signature_inception: u32,
+    pub key_tag: u16,
+    pub signer_name: DomainName,
+    pub signature: Vec<u8>,
+}
+
+impl_to_type!(RRSIG);
+
+impl Display for RRSIG {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} {} {} RRSIG ", self.domain_name, self.ttl, self.class,)?;
+        fmt_type_code(f, self.type_covered)?;
+        write!(
+            f,
+            " {} {} {} ",
+            self.algorithm_type, self.labels, self.original_ttl,
+        )?;
+        fmt_timestamp(f, self.signature_expiration)?;
+        write!(f, " ")?;
+        fmt_timestamp(f, self.signature_inception)?;
+        write!(
+            f,
+            " {} {} {}",
+            self.key_tag,
+            self.signer_name,
+            Base64Standard.encode(&self.signature),
+        )
+    }
+}
+
+/// The [next secure] resource record type.
+///
+/// [next secure]: https://tools.ietf.org/html/rfc4034#section-4
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct NSEC {
+    pub domain_name: DomainName,
+    pub ttl: u32,
+    pub class: Class,
+    pub next_domain_name: DomainName,
+    /// The types, which are present at the owner name.
+    pub types: TypeBitMap,
+}
+
+impl_to_type!(NSEC);
+
+impl Display for NSEC {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} {} {} NSEC {}{}",
+            self.domain_name, self.ttl, self.class, self.next_domain_name, self.types,
+        )
+    }
+}
+
+/// Sort the resource records of an RRset in the [canonical order] and remove the duplicates.
+///
+/// The resource records are ordered by their RDATA in the canonical form, see
+/// [`RR::encode_canonical`].
+///
+/// [canonical order]: https://tools.ietf.org/html/rfc4034#section-6.3
+pub fn sort_rrset(rrset: &mut Vec<RR>) -> EncodeResult<()> {
+    let mut rrs = Vec::with_capacity(rrset.len());
+    for rr in rrset.drain(..) {
+        let rdata = rr.encode_canonical_rdata()?;
+        rrs.push((rdata, rr));
+    }
+    rrs.sort_by(|(rdata_1, _), (rdata_2, _)| rdata_1.cmp(rdata_2));
+    rrs.dedup_by(|(rdata_1, _), (rdata_2, _)| rdata_1 == rdata_2);
+    rrset.extend(rrs.into_iter().map(|(_, rr)| rr));
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use super::subtypes::TypeBitMap;
+use super::Class;
+use crate::DomainName;
+use data_encoding::BASE32HEX_NOPAD;
+use hex::encode;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The bit at offset 7 of the NSEC3 flags field is the [Opt-Out flag].
+///
+/// [Opt-Out flag]: https://tools.ietf.org/html/rfc5155#section-3.1.2.1
+pub const NSEC3_OPT_OUT_FLAG: u8 = 0b0000_0001;
+
+try_from_enum_to_integer! {
+    #[repr(u8)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// https://www.iana.org/assignments/dnssec-nsec3-parameters/dnssec-nsec3-parameters.xhtml
+    pub enum NSEC3HashAlgorithm {
+        Reserved = 0x00,
+        Sha1 = 0x01,
+    }
+}
+
+fn fmt_salt(f: &mut Formatter<'_>, salt: &[u8]) -> FmtResult {
+    if salt.is_empty() {
+        write!(f, "-")
+    } else {
+        write!(f, "{}", encode(salt))
+    }
+}
+
+/// The [hashed authenticated denial of existence] resource record type.
+///
+/// [hashed authenticated denial of existence]: https://tools.ietf.org/html/rfc5155#section-3
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct NSEC3 {
+    pub domain_name: DomainName,
+    pub ttl: u32,
+    pub class: Class,
+    /// The hash algorithm, see [`NSEC3HashAlgorithm`].
+    ///
+    /// Unknown hash algorithms are kept, because such records have to be ignored by a validator
+    /// instead of rejecting the whole response, see [RFC 5155 Section 8.1].
+    ///
+    /// [RFC 5155 Section 8.1]: https://tools.ietf.org/html/rfc5155#section-8.1
+    pub hash_algorithm: u8,
+    /// The flags field, where only the [Opt-Out flag] is defined. The other bits are kept, see
+    /// [RFC 5155 Section 8.2].
+    ///
+    /// [Opt-Out flag]: NSEC3_OPT_OUT_FLAG
+    /// [RFC 5155 Section 8.2]: https://tools.ietf.org/html/rfc5155#section-8.2
+    pub flags: u8,
+    pub iterations: u16,
+    pub salt: Vec<u8>,
+    /// The next hashed owner name in binary form.
+    pub next_hashed_owner_name: Vec<u8>,
+    /// The types, which are present at the original owner name.
+    pub types: TypeBitMap,
+}
+
+impl_to_type!(NSEC3);
+
+impl NSEC3 {
+    /// Returns `true` if the [Opt-Out flag] is set.
+    ///
+    /// [Opt-Out flag]: NSEC3_OPT_OUT_FLAG
+    pub fn is_opt_out(&self) -> bool {
+        (self.flags & NSEC3_OPT_OUT_FLAG) == NSEC3_OPT_OUT_FLAG
+    }
+}
+
+impl Display for NSEC3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} {} {} NSEC3 {} {} {} ",
+            self.domain_name,
+            self.ttl,
+            self.class,
+            self.hash_algorithm,
+            self.flags,
+            self.iterations,
+        )?;
+        fmt_salt(f, &self.salt)?;
+        write!(
+            f,
+            " {}{}",
+            BASE32HEX_NOPAD.encode(&self.next_hashed_owner_name),
+            self.types,
+        )
+    }
+}
+
+/// The [NSEC3 parameters] resource record type.
+///
+/// [NSEC3 parameters]: https://tools.ietf.org/html/rfc5155#section-4
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct NSEC3PARAM {
+    pub domain_name: DomainName,
+    pub ttl: u32,
+    pub class: Class,
+    /// The hash algorithm, see [`NSEC3::hash_algorithm`].
+    pub hash_algorithm: u8,
+    pub flags: u8,
+    pub iterations: u16,
+    pub salt: Vec<u8>,
+}
+
+impl_to_type!(NSEC3PARAM);
+
+impl Display for NSEC3PARAM {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} {} {} NSEC3PARAM {} {} {} ",
+            self.domain_name,
+            self.ttl,
+            self.class,
+            self.hash_algorithm,
+            self.flags,
+            self.iterations,
+        )?;
+        fmt_salt(f, &self.salt)
+    }
+}
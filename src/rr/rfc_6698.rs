@@ -0,0 +1,72 @@
+use super::Class;
+use crate::DomainName;
+use hex::encode;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+try_from_enum_to_integer! {
+    #[repr(u8)]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    /// https://www.iana.org/assignments/dane-parameters/dane-parameters.xhtml#certificate-usages
+    pub enum TLSACertificateUsage {
+        PkixTa = 0,
+        PkixEe = 1,
+        DaneTa = 2,
+        DaneEe = 3,
+        PrivCert = 255,
+    }
+}
+
+try_from_enum_to_integer! {
+    #[repr(u8)]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    /// https://www.iana.org/assignments/dane-parameters/dane-parameters.xhtml#selectors
+    pub enum TLSASelector {
+        Cert = 0,
+        Spki = 1,
+        PrivSel = 255,
+    }
+}
+
+try_from_enum_to_integer! {
+    #[repr(u8)]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    /// https://www.iana.org/assignments/dane-parameters/dane-parameters.xhtml#matching-types
+    pub enum TLSAMatchingType {
+        Full = 0,
+        Sha256 = 1,
+        Sha512 = 2,
+        PrivMatch = 255,
+    }
+}
+
+/// The [TLSA] resource record type.
+///
+/// [TLSA]: https://tools.ietf.org/html/rfc6698#section-2
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TLSA {
+    pub domain_name: DomainName,
+    pub ttl: u32,
+    pub class: Class,
+    pub certificate_usage: TLSACertificateUsage,
+    pub selector: TLSASelector,
+    pub matching_type: TLSAMatchingType,
+    pub certificate_association_data: Vec<u8>,
+}
+
+impl_to_type!(TLSA);
+
+impl Display for TLSA {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} {} {} TLSA {} {} {} {}",
+            self.domain_name,
+            self.ttl,
+            self.class,
+            self.certificate_usage as u8,
+            self.selector as u8,
+            self.matching_type as u8,
+            encode(&self.certificate_association_data),
+        )
+    }
+}
@@ -1,3 +1,4 @@
+use super::subtypes::fmt_character_string;
 use super::Class;
 use crate::DomainName;
 use std::fmt::{Display, Formatter, Result as FmtResult};
@@ -18,8 +19,9 @@ impl Display for URI {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(
             f,
-            "{} {} {} URI {} {} {}",
-            self.domain_name, self.ttl, self.class, self.priority, self.weight, self.uri,
-        )
+            "{} {} {} URI {} {} ",
+            self.domain_name, self.ttl, self.class, self.priority, self.weight,
+        )?;
+        fmt_character_string(f, self.uri.as_bytes())
     }
 }
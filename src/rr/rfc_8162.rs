@@ -0,0 +1,38 @@
+use super::{Class, TLSACertificateUsage, TLSAMatchingType, TLSASelector};
+use crate::DomainName;
+use hex::encode;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The [SMIMEA] resource record type.
+///
+/// The RDATA has the same format as the [`TLSA`](super::TLSA) resource record type.
+///
+/// [SMIMEA]: https://tools.ietf.org/html/rfc8162#section-2
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SMIMEA {
+    pub domain_name: DomainName,
+    pub ttl: u32,
+    pub class: Class,
+    pub certificate_usage: TLSACertificateUsage,
+    pub selector: TLSASelector,
+    pub matching_type: TLSAMatchingType,
+    pub certificate_association_data: Vec<u8>,
+}
+
+impl_to_type!(SMIMEA);
+
+impl Display for SMIMEA {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} {} {} SMIMEA {} {} {} {}",
+            self.domain_name,
+            self.ttl,
+            self.class,
+            self.certificate_usage as u8,
+            self.selector as u8,
+            self.matching_type as u8,
+            encode(&self.certificate_association_data),
+        )
+    }
+}
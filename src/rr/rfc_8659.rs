@@ -1,6 +1,6 @@
+use super::subtypes::fmt_character_string;
 use super::Class;
 use crate::DomainName;
-use hex::encode;
 use std::convert::{AsRef, TryFrom};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use thiserror::Error;
@@ -66,13 +66,9 @@ impl Display for CAA {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(
             f,
-            "{} {} {} CAA {} {} {}",
-            self.domain_name,
-            self.ttl,
-            self.class,
-            self.flags,
-            self.tag,
-            encode(&self.value)
-        )
+            "{} {} {} CAA {} {} ",
+            self.domain_name, self.ttl, self.class, self.flags, self.tag,
+        )?;
+        fmt_character_string(f, &self.value)
     }
 }
@@ -0,0 +1,58 @@
+use crate::DomainName;
+use base64::{engine::general_purpose::STANDARD as Base64Standard, Engine};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The class of a [TSIG] resource record, which is always `ANY`.
+///
+/// [TSIG]: https://tools.ietf.org/html/rfc8945#section-4.2
+pub const TSIG_CLASS: u16 = 255;
+
+/// The [transaction signature] resource record type.
+///
+/// The class is always `ANY` and the TTL is always zero, therefore both fields are omitted.
+/// To sign and verify messages see the [`tsig`](crate::tsig) module.
+///
+/// [transaction signature]: https://tools.ietf.org/html/rfc8945#section-4.2
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct TSIG {
+    /// The name of the key.
+    pub domain_name: DomainName,
+    pub algorithm_name: DomainName,
+    /// The seconds since 1970-01-01 UTC, only the lower 48 bits are encoded.
+    pub time_signed: u64,
+    pub fudge: u16,
+    pub mac: Vec<u8>,
+    pub original_id: u16,
+    pub error: u16,
+    pub other_data: Vec<u8>,
+}
+
+impl_to_type!(TSIG);
+
+impl Display for TSIG {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} 0 ANY TSIG {} {} {} {}",
+            self.domain_name,
+            self.algorithm_name,
+            self.time_signed,
+            self.fudge,
+            self.mac.len(),
+        )?;
+        if !self.mac.is_empty() {
+            write!(f, " {}", Base64Standard.encode(&self.mac))?;
+        }
+        write!(
+            f,
+            " {} {} {}",
+            self.original_id,
+            self.error,
+            self.other_data.len()
+        )?;
+        if !self.other_data.is_empty() {
+            write!(f, " {}", Base64Standard.encode(&self.other_data))?;
+        }
+        Ok(())
+    }
+}
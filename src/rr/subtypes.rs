@@ -1,6 +1,9 @@
+use super::Type;
 use std::cmp::Ordering;
+use std::collections::BTreeSet;
 use std::convert::TryFrom;
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::iter::FromIterator;
 use std::net::{Ipv4Addr, Ipv6Addr};
 use std::slice::Iter;
 use thiserror::Error;
@@ -146,3 +149,111 @@ impl<T> From<NonEmptyVec<T>> for Vec<T> {
         vec.0
     }
 }
+
+/// The [type bit map] of the NSEC and NSEC3 resource records, which contains the types present
+/// at an owner name.
+///
+/// The type codes are kept as integers, because the bit map can contain types, which are not
+/// known by the [`Type`] enum.
+///
+/// [type bit map]: https://tools.ietf.org/html/rfc4034#section-4.1.2
+#[derive(Debug, Default, PartialEq, Clone, Eq, Hash)]
+pub struct TypeBitMap(BTreeSet<u16>);
+
+impl TypeBitMap {
+    pub fn new() -> TypeBitMap {
+        TypeBitMap::default()
+    }
+
+    /// Adds the type. Returns `false` if the type was already present.
+    pub fn insert(&mut self, type_: Type) -> bool {
+        self.0.insert(type_ as u16)
+    }
+
+    /// Adds the type code, which does not have to be known by the [`Type`] enum. Returns `false`
+    /// if the type code was already present.
+    pub fn insert_code(&mut self, type_code: u16) -> bool {
+        self.0.insert(type_code)
+    }
+
+    pub fn contains(&self, type_: Type) -> bool {
+        self.0.contains(&(type_ as u16))
+    }
+
+    pub fn contains_code(&self, type_code: u16) -> bool {
+        self.0.contains(&type_code)
+    }
+
+    /// Returns the types in ascending order, the type codes, which are not known by the [`Type`]
+    /// enum, are skipped.
+    pub fn iter_types(&self) -> impl Iterator<Item = Type> + '_ {
+        self.0
+            .iter()
+            .filter_map(|type_code| Type::try_from(*type_code).ok())
+    }
+
+    /// Returns all type codes in ascending order.
+    pub fn iter_codes(&self) -> impl Iterator<Item = u16> + '_ {
+        self.0.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<Type> for TypeBitMap {
+    fn from_iter<I: IntoIterator<Item = Type>>(iter: I) -> Self {
+        TypeBitMap(iter.into_iter().map(|type_| type_ as u16).collect())
+    }
+}
+
+impl FromIterator<u16> for TypeBitMap {
+    fn from_iter<I: IntoIterator<Item = u16>>(iter: I) -> Self {
+        TypeBitMap(iter.into_iter().collect())
+    }
+}
+
+/// Writes the types in the presentation format, each prefixed by a space.
+impl Display for TypeBitMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for type_code in &self.0 {
+            write!(f, " ")?;
+            fmt_type_code(f, *type_code)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the mnemonic of the type code or the generic `TYPE` notation of [RFC 3597 Section 5],
+/// if the type code is unknown.
+///
+/// [RFC 3597 Section 5]: https://tools.ietf.org/html/rfc3597#section-5
+pub(super) fn fmt_type_code(f: &mut Formatter<'_>, type_code: u16) -> FmtResult {
+    match Type::try_from(type_code) {
+        Ok(type_) => write!(f, "{}", type_),
+        Err(type_code) => write!(f, "TYPE{}", type_code),
+    }
+}
+
+/// Writes a [character string] in the quoted presentation format.
+///
+/// The characters `"` and `\` are escaped with a backslash and non-printable characters are
+/// written as `\DDD`.
+///
+/// [character string]: https://tools.ietf.org/html/rfc1035#section-5.1
+pub(super) fn fmt_character_string(f: &mut Formatter<'_>, string: &[u8]) -> FmtResult {
+    write!(f, "\"")?;
+    for &b in string {
+        match b {
+            b'"' | b'\\' => write!(f, "\\{}", b as char)?,
+            0x20..=0x7e => write!(f, "{}", b as char)?,
+            _ => write!(f, "\\{:03}", b)?,
+        }
+    }
+    write!(f, "\"")
+}
@@ -1,5 +1,5 @@
 use crate::{
-    rr::{ServiceBinding, ServiceParameter},
+    rr::{ServiceBinding, ServiceParameter, Type, RR},
     DomainName,
 };
 use std::{
@@ -265,6 +265,14 @@ fn test_https_alpn_with_escaped_values() {
     // then
     assert_eq!(
         result,
-        "example.org. 300 IN HTTPS 16 foo.example.org. alpn=\"f\\\\\\\\oo\\,bar,h2\""
+        "example.org. 300 IN HTTPS 16 foo.example.org. alpn=\"f\\\\\\\\oo\\\\,bar,h2\""
     );
 }
+
+#[test]
+fn rr_get_type_code() {
+    let rr = RR::from_str("example.org. 3600 IN AAAA ::1").unwrap();
+    assert_eq!(rr.get_type_code(), Type::AAAA as u16);
+    let rr = RR::from_str("example.org. 3600 IN TYPE65000 \\# 1 00").unwrap();
+    assert_eq!(rr.get_type_code(), 65000);
+}
@@ -1,5 +1,19 @@
-try_from_enum_to_integer! {
+use thiserror::Error;
+
+/// The error of the [`FromStr`] implementations of the enums, which is returned if the name is
+/// not the name of a variant.
+///
+/// [`FromStr`]: std::str::FromStr
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+#[error("Unknown name of {enum_name}: {name}")]
+pub struct ParseNameError {
+    pub enum_name: &'static str,
+    pub name: String,
+}
+
+enum_to_integer_with_unknown! {
     #[repr(u8)]
+    #[unknown = "OPCODE"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub enum Opcode {
         Query = 0,
@@ -12,8 +26,9 @@ try_from_enum_to_integer! {
     }
 }
 
-try_from_enum_to_integer! {
+enum_to_integer_with_unknown! {
     #[repr(u8)]
+    #[unknown = "RCODE"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub enum RCode {
         NoError = 0,
@@ -0,0 +1,486 @@
+//! Sign and verify DNS messages with [transaction signatures] (TSIG).
+//!
+//! # Example
+//! ```
+//! # use dns_message_parser::tsig::{TSIGAlgorithm, TSIGKey};
+//! # use dns_message_parser::{Dns, Flags, Opcode, RCode};
+//! let key = TSIGKey {
+//!     name: "key.example.org".parse().unwrap(),
+//!     algorithm: TSIGAlgorithm::HmacSha256,
+//!     secret: b"secret".to_vec(),
+//! };
+//! let flags = Flags {
+//!     qr: false,
+//!     opcode: Opcode::Query,
+//!     aa: false,
+//!     tc: false,
+//!     rd: true,
+//!     ra: false,
+//!     ad: false,
+//!     cd: false,
+//!     rcode: RCode::NoError,
+//! };
+//! let mut dns = Dns {
+//!     id: 0x1234,
+//!     flags,
+//!     questions: Vec::new(),
+//!     answers: Vec::new(),
+//!     authorities: Vec::new(),
+//!     additionals: Vec::new(),
+//! };
+//! key.sign(&mut dns, 1_700_000_000, 300, None).unwrap();
+//! let msg = dns.encode().unwrap();
+//! assert!(key.verify(&msg, 1_700_000_100, None).is_ok());
+//! ```
+//!
+//! The messages of a multi-message response, like a zone transfer, are signed and verified with a
+//! [`TSIGStream`], which chains the MAC of each message into the next one.
+//!
+//! [transaction signatures]: https://tools.ietf.org/html/rfc8945
+use crate::rr::{RR, TSIG};
+use crate::{DecodeError, Dns, DomainName, EncodeError};
+use bytes::Bytes;
+use ring::hmac::{sign, Algorithm, Key, HMAC_SHA256, HMAC_SHA384, HMAC_SHA512};
+use std::convert::TryFrom;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use thiserror::Error;
+
+/// The minimum length of a truncated MAC, see [RFC 8945 Section 5.2.2.1].
+///
+/// [RFC 8945 Section 5.2.2.1]: https://tools.ietf.org/html/rfc8945#section-5.2.2.1
+const MAC_MIN_LENGTH: usize = 10;
+
+/// The maximum number of unsigned messages in a row in a multi-message response, see
+/// [RFC 8945 Section 5.3.1].
+///
+/// [RFC 8945 Section 5.3.1]: https://tools.ietf.org/html/rfc8945#section-5.3.1
+const UNSIGNED_MAX_COUNT: usize = 99;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum TSIGError {
+    #[error("Could not decode the message: {0}")]
+    DecodeError(#[from] DecodeError),
+    #[error("Could not encode the message: {0}")]
+    EncodeError(#[from] EncodeError),
+    #[error("The message is already signed")]
+    AlreadySigned,
+    #[error("The message does not contain a TSIG record")]
+    NoTSIG,
+    #[error("The TSIG record is not the last record of the message")]
+    TSIGNotLast,
+    #[error("Too many unsigned messages in a row: {0}")]
+    TooManyUnsigned(usize),
+    #[error("The key name does not match: {0}")]
+    BadKey(DomainName),
+    #[error("The algorithm does not match: {0}")]
+    BadAlgorithm(DomainName),
+    #[error("The MAC is truncated too much: {0}")]
+    BadTrunc(usize),
+    #[error("The MAC does not match")]
+    BadSig,
+    #[error("The time signed {0} is outside of the fudge {1} relative to {2}")]
+    BadTime(u64, u16, u64),
+}
+
+pub type TSIGResult<T> = Result<T, TSIGError>;
+
+/// The HMAC [algorithms], which are supported.
+///
+/// [algorithms]: https://tools.ietf.org/html/rfc8945#section-6
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TSIGAlgorithm {
+    HmacSha256,
+    HmacSha384,
+    HmacSha512,
+}
+
+impl TSIGAlgorithm {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TSIGAlgorithm::HmacSha256 => "hmac-sha256",
+            TSIGAlgorithm::HmacSha384 => "hmac-sha384",
+            TSIGAlgorithm::HmacSha512 => "hmac-sha512",
+        }
+    }
+
+    /// Returns the algorithm name, which is used in the TSIG record.
+    pub fn name(&self) -> DomainName {
+        // The names are static and valid.
+        self.as_str().parse().unwrap()
+    }
+
+    fn mac(&self, secret: &[u8], data: &[u8]) -> Vec<u8> {
+        match self {
+            TSIGAlgorithm::HmacSha256 => hmac(HMAC_SHA256, secret, data),
+            TSIGAlgorithm::HmacSha384 => hmac(HMAC_SHA384, secret, data),
+            TSIGAlgorithm::HmacSha512 => hmac(HMAC_SHA512, secret, data),
+        }
+    }
+}
+
+impl TryFrom<&DomainName> for TSIGAlgorithm {
+    type Error = TSIGError;
+
+    fn try_from(algorithm_name: &DomainName) -> Result<Self, Self::Error> {
+        let algorithms = [
+            TSIGAlgorithm::HmacSha256,
+            TSIGAlgorithm::HmacSha384,
+            TSIGAlgorithm::HmacSha512,
+        ];
+        algorithms
+            .iter()
+            .find(|algorithm| &algorithm.name() == algorithm_name)
+            .copied()
+            .ok_or_else(|| TSIGError::BadAlgorithm(algorithm_name.clone()))
+    }
+}
+
+fn hmac(algorithm: Algorithm, secret: &[u8], data: &[u8]) -> Vec<u8> {
+    let key = Key::new(algorithm, secret);
+    sign(&key, data).as_ref().to_vec()
+}
+
+/// Compare the received MAC with the beginning of the expected MAC in constant time.
+fn compare_mac(expected: &[u8], received: &[u8]) -> bool {
+    expected
+        .iter()
+        .zip(received.iter())
+        .fold(0, |result, (a, b)| result | (a ^ b))
+        == 0
+}
+
+/// The TSIG variables, which are covered by the MAC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Variables {
+    /// All variables, see [RFC 8945 Section 4.3.3].
+    ///
+    /// [RFC 8945 Section 4.3.3]: https://tools.ietf.org/html/rfc8945#section-4.3.3
+    All,
+    /// Only the timers, which are used by the subsequent messages of a multi-message response,
+    /// see [RFC 8945 Section 5.3.1].
+    ///
+    /// [RFC 8945 Section 5.3.1]: https://tools.ietf.org/html/rfc8945#section-5.3.1
+    Timers,
+}
+
+/// A shared secret, which is used to sign and verify messages.
+///
+/// The secret is not printed by the [`Debug`] implementation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TSIGKey {
+    pub name: DomainName,
+    pub algorithm: TSIGAlgorithm,
+    pub secret: Vec<u8>,
+}
+
+impl Debug for TSIGKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("TSIGKey")
+            .field("name", &self.name)
+            .field("algorithm", &self.algorithm)
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+impl TSIGKey {
+    /// Create the data, which is covered by the MAC, see [RFC 8945 Section 4.3].
+    ///
+    /// `prior_mac` is the MAC of the request or of the previous signed message of a
+    /// multi-message response. `unsigned` are the unsigned messages, which were received since
+    /// the previous signed message.
+    ///
+    /// [RFC 8945 Section 4.3]: https://tools.ietf.org/html/rfc8945#section-4.3
+    fn digest_data(
+        &self,
+        prior_mac: Option<&[u8]>,
+        unsigned: &[u8],
+        msg: &[u8],
+        tsig: &TSIG,
+        variables: Variables,
+    ) -> TSIGResult<Vec<u8>> {
+        let mut data = Vec::new();
+        if let Some(prior_mac) = prior_mac {
+            let prior_mac_length = prior_mac.len();
+            let prior_mac_length = match u16::try_from(prior_mac_length) {
+                Ok(prior_mac_length) => prior_mac_length,
+                Err(_) => return Err(EncodeError::Length(prior_mac_length).into()),
+            };
+            data.extend_from_slice(&prior_mac_length.to_be_bytes());
+            data.extend_from_slice(prior_mac);
+        }
+        data.extend_from_slice(unsigned);
+        data.extend_from_slice(msg);
+        match variables {
+            Variables::All => data.extend_from_slice(&tsig.encode_variables()?),
+            Variables::Timers => data.extend_from_slice(&tsig.encode_timers()?),
+        }
+        Ok(data)
+    }
+
+    /// Sign the message by appending a TSIG record to the additional section.
+    ///
+    /// `time_signed` is the current time in seconds since 1970-01-01 UTC. If the message is a
+    /// response then `request_mac` has to be the MAC of the request. The MAC of the message is
+    /// returned, which is needed to verify the response.
+    ///
+    /// The messages of a multi-message response have to be signed with [`TSIGKey::stream`].
+    pub fn sign(
+        &self,
+        dns: &mut Dns,
+        time_signed: u64,
+        fudge: u16,
+        request_mac: Option<&[u8]>,
+    ) -> TSIGResult<Vec<u8>> {
+        self.sign_message(dns, time_signed, fudge, request_mac, Variables::All)
+    }
+
+    fn sign_message(
+        &self,
+        dns: &mut Dns,
+        time_signed: u64,
+        fudge: u16,
+        prior_mac: Option<&[u8]>,
+        variables: Variables,
+    ) -> TSIGResult<Vec<u8>> {
+        let has_tsig = dns
+            .answers
+            .iter()
+            .chain(dns.authorities.iter())
+            .chain(dns.additionals.iter())
+            .any(|rr| matches!(rr, RR::TSIG(_)));
+        if has_tsig {
+            return Err(TSIGError::AlreadySigned);
+        }
+
+        let msg = dns.encode()?;
+        let mut tsig = TSIG {
+            domain_name: self.name.clone(),
+            algorithm_name: self.algorithm.name(),
+            time_signed,
+            fudge,
+            mac: Vec::new(),
+            original_id: dns.id,
+            error: 0,
+            other_data: Vec::new(),
+        };
+        let data = self.digest_data(prior_mac, &[], &msg, &tsig, variables)?;
+        tsig.mac = self.algorithm.mac(&self.secret, &data);
+        let mac = tsig.mac.clone();
+        dns.additionals.push(RR::TSIG(tsig));
+        Ok(mac)
+    }
+
+    fn verify_mac(&self, data: &[u8], mac: &[u8]) -> TSIGResult<()> {
+        let expected = self.algorithm.mac(&self.secret, data);
+        let mac_length = mac.len();
+        let mac_min_length = MAC_MIN_LENGTH.max(expected.len() / 2);
+        if mac_length > expected.len() {
+            return Err(TSIGError::BadSig);
+        } else if mac_length < mac_min_length {
+            return Err(TSIGError::BadTrunc(mac_length));
+        }
+        if compare_mac(&expected, mac) {
+            Ok(())
+        } else {
+            Err(TSIGError::BadSig)
+        }
+    }
+
+    /// Verify the TSIG record of the received message.
+    ///
+    /// `now` is the current time in seconds since 1970-01-01 UTC. If the message is a response
+    /// then `request_mac` has to be the MAC of the request. The decoded message is returned,
+    /// which still contains the TSIG record as the last record of the additional section.
+    ///
+    /// The messages of a multi-message response have to be verified with [`TSIGKey::stream`].
+    pub fn verify(&self, msg: &[u8], now: u64, request_mac: Option<&[u8]>) -> TSIGResult<Dns> {
+        self.verify_message(msg, now, request_mac, &[], Variables::All)
+    }
+
+    fn verify_message(
+        &self,
+        msg: &[u8],
+        now: u64,
+        prior_mac: Option<&[u8]>,
+        unsigned: &[u8],
+        variables: Variables,
+    ) -> TSIGResult<Dns> {
+        let bytes = Bytes::copy_from_slice(msg);
+        let (dns, tsig_offset) = Dns::decode_with_last_additional_offset(bytes)?;
+
+        let additionals_count = dns.additionals.len().saturating_sub(1);
+        let has_other_tsig = dns
+            .answers
+            .iter()
+            .chain(dns.authorities.iter())
+            .chain(dns.additionals[..additionals_count].iter())
+            .any(|rr| matches!(rr, RR::TSIG(_)));
+        if has_other_tsig {
+            return Err(TSIGError::TSIGNotLast);
+        }
+        let tsig = match dns.additionals.last() {
+            Some(RR::TSIG(tsig)) => tsig,
+            _ => return Err(TSIGError::NoTSIG),
+        };
+
+        if tsig.domain_name != self.name {
+            return Err(TSIGError::BadKey(tsig.domain_name.clone()));
+        }
+        if TSIGAlgorithm::try_from(&tsig.algorithm_name)? != self.algorithm {
+            return Err(TSIGError::BadAlgorithm(tsig.algorithm_name.clone()));
+        }
+
+        // Restore the message as it was before the TSIG record was added.
+        let mut msg = msg[..tsig_offset].to_vec();
+        msg[0..2].copy_from_slice(&tsig.original_id.to_be_bytes());
+        let additional_count = dns.additionals.len() as u16 - 1;
+        msg[10..12].copy_from_slice(&additional_count.to_be_bytes());
+
+        let data = self.digest_data(prior_mac, unsigned, &msg, tsig, variables)?;
+        self.verify_mac(&data, &tsig.mac)?;
+
+        if now.max(tsig.time_signed) - now.min(tsig.time_signed) > tsig.fudge as u64 {
+            return Err(TSIGError::BadTime(tsig.time_signed, tsig.fudge, now));
+        }
+
+        Ok(dns)
+    }
+
+    /// Create a [`TSIGStream`] to sign or verify the messages of a multi-message response to the
+    /// request with the MAC `request_mac`.
+    pub fn stream(&self, request_mac: &[u8]) -> TSIGStream<'_> {
+        TSIGStream {
+            key: self,
+            prior_mac: request_mac.to_vec(),
+            is_first: true,
+            unsigned: Vec::new(),
+            unsigned_count: 0,
+        }
+    }
+}
+
+/// Signs or verifies the messages of a [multi-message] response, like a zone transfer.
+///
+/// The first message is signed with all TSIG variables and the MAC of the request. Each
+/// subsequent message is signed with the MAC of the previous message and only the TSIG timers.
+///
+/// A verifier accepts up to 99 unsigned messages in a row after the first message, which are
+/// covered by the MAC of the next signed message. The last message of the response has to be
+/// signed, which can be checked with [`TSIGStream::unsigned_count`].
+///
+/// # Example
+/// ```
+/// # use dns_message_parser::tsig::{TSIGAlgorithm, TSIGKey};
+/// # use dns_message_parser::{Dns, Flags, Opcode, RCode};
+/// # let key = TSIGKey {
+/// #     name: "key.example.org".parse().unwrap(),
+/// #     algorithm: TSIGAlgorithm::HmacSha256,
+/// #     secret: b"secret".to_vec(),
+/// # };
+/// # let flags = Flags {
+/// #     qr: true,
+/// #     opcode: Opcode::Query,
+/// #     aa: true,
+/// #     tc: false,
+/// #     rd: false,
+/// #     ra: false,
+/// #     ad: false,
+/// #     cd: false,
+/// #     rcode: RCode::NoError,
+/// # };
+/// # let dns = Dns {
+/// #     id: 0x1234,
+/// #     flags,
+/// #     questions: Vec::new(),
+/// #     answers: Vec::new(),
+/// #     authorities: Vec::new(),
+/// #     additionals: Vec::new(),
+/// # };
+/// # let request_mac = [0; 32];
+/// let mut signer = key.stream(&request_mac);
+/// let mut verifier = key.stream(&request_mac);
+/// for time_signed in 1_700_000_000..1_700_000_003 {
+///     let mut dns = dns.clone();
+///     signer.sign(&mut dns, time_signed, 300).unwrap();
+///     let msg = dns.encode().unwrap();
+///     assert!(verifier.verify(&msg, time_signed).is_ok());
+/// }
+/// ```
+///
+/// [multi-message]: https://tools.ietf.org/html/rfc8945#section-5.3.1
+#[derive(Debug, Clone)]
+pub struct TSIGStream<'a> {
+    key: &'a TSIGKey,
+    prior_mac: Vec<u8>,
+    is_first: bool,
+    unsigned: Vec<u8>,
+    unsigned_count: usize,
+}
+
+impl TSIGStream<'_> {
+    fn variables(&self) -> Variables {
+        if self.is_first {
+            Variables::All
+        } else {
+            Variables::Timers
+        }
+    }
+
+    /// Returns the MAC of the request or of the last message, which was signed or verified.
+    pub fn prior_mac(&self) -> &[u8] {
+        &self.prior_mac
+    }
+
+    /// Returns the number of unsigned messages, which were received since the last signed
+    /// message.
+    pub fn unsigned_count(&self) -> usize {
+        self.unsigned_count
+    }
+
+    /// Sign the next message of the response, see [`TSIGKey::sign`]. The MAC of the message is
+    /// returned.
+    pub fn sign(&mut self, dns: &mut Dns, time_signed: u64, fudge: u16) -> TSIGResult<Vec<u8>> {
+        let variables = self.variables();
+        let mac =
+            self.key
+                .sign_message(dns, time_signed, fudge, Some(&self.prior_mac), variables)?;
+        self.prior_mac = mac.clone();
+        self.is_first = false;
+        Ok(mac)
+    }
+
+    /// Verify the next message of the response, see [`TSIGKey::verify`]. If the verification
+    /// fails, then the state is not changed.
+    ///
+    /// An unsigned message, which is not the first message, is decoded and returned without a
+    /// verification. It is covered by the MAC of the next signed message.
+    pub fn verify(&mut self, msg: &[u8], now: u64) -> TSIGResult<Dns> {
+        let variables = self.variables();
+        let result =
+            self.key
+                .verify_message(msg, now, Some(&self.prior_mac), &self.unsigned, variables);
+        let dns = match result {
+            Ok(dns) => dns,
+            Err(TSIGError::NoTSIG) if !self.is_first => return self.verify_unsigned(msg),
+            Err(e) => return Err(e),
+        };
+        if let Some(RR::TSIG(tsig)) = dns.additionals.last() {
+            self.prior_mac = tsig.mac.clone();
+        }
+        self.is_first = false;
+        self.unsigned.clear();
+        self.unsigned_count = 0;
+        Ok(dns)
+    }
+
+    fn verify_unsigned(&mut self, msg: &[u8]) -> TSIGResult<Dns> {
+        if self.unsigned_count >= UNSIGNED_MAX_COUNT {
+            return Err(TSIGError::TooManyUnsigned(self.unsigned_count + 1));
+        }
+        let dns = Dns::decode(Bytes::copy_from_slice(msg))?;
+        self.unsigned.extend_from_slice(msg);
+        self.unsigned_count += 1;
+        Ok(dns)
+    }
+}
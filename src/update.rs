@@ -0,0 +1,344 @@
+//! This module contains the [UPDATE] message handling.
+//!
+//! An UPDATE message reuses the sections of a [`Dns`] message, but with a different meaning:
+//! the question section is the zone section, the answer section is the prerequisite section and
+//! the authority section is the update section. The [`UpdateMessage`] struct represents these
+//! sections and can be converted from and into a [`Dns`] message.
+//!
+//! # Example
+//! ```rust
+//! use dns_message_parser::rr::{Class, Type, A, RR};
+//! use dns_message_parser::update::{Prerequisite, Update, UpdateMessage};
+//!
+//! let mut update = UpdateMessage::new(0x1234, "example.org".parse().unwrap(), Class::IN);
+//! update
+//!     .prerequisites
+//!     .push(Prerequisite::NameNotInUse("www.example.org".parse().unwrap()));
+//! update.updates.push(Update::Add(RR::A(A {
+//!     domain_name: "www.example.org".parse().unwrap(),
+//!     ttl: 3600,
+//!     ipv4_addr: "10.0.0.1".parse().unwrap(),
+//! })));
+//!
+//! // Encode the UPDATE message into bytes::BytesMut
+//! let bytes = update.encode().unwrap();
+//!
+//! // Decode the UPDATE message
+//! let update_decoded = UpdateMessage::decode(bytes.freeze()).unwrap();
+//! assert_eq!(update, update_decoded);
+//! ```
+//!
+//! [UPDATE]: https://tools.ietf.org/html/rfc2136
+use crate::question::{QClass, QType, Question};
+use crate::rr::{Class, Type, Unknown, RR};
+use crate::{DecodeError, Dns, DomainName, EncodeError, Flags, Opcode, RCode};
+use bytes::{Bytes, BytesMut};
+use std::convert::TryFrom;
+use thiserror::Error;
+
+/// The type code of the `ANY` type, which is only a [`QType`].
+const TYPE_ANY: u16 = QType::ALL as u16;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum UpdateError {
+    #[error("Could not decode the message: {0}")]
+    DecodeError(#[from] DecodeError),
+    #[error("Could not encode the message: {0}")]
+    EncodeError(#[from] EncodeError),
+    #[error("The opcode is not UPDATE: {0:?}")]
+    Opcode(Opcode),
+    #[error("The zone section does not contain exactly one zone: {0}")]
+    ZoneCount(usize),
+    #[error("The zone type is not SOA: {0}")]
+    ZoneType(QType),
+    #[error("Could not convert the zone class: {0}")]
+    ZoneClass(QClass),
+    #[error("Could not decode Type: {0}")]
+    Type(u16),
+    #[error("The prerequisite is not valid: {0}")]
+    Prerequisite(Box<RR>),
+    #[error("The update is not valid: {0}")]
+    Update(Box<RR>),
+}
+
+pub type UpdateResult<T> = Result<T, UpdateError>;
+
+/// A [prerequisite], which has to be satisfied before the updates are applied.
+///
+/// [prerequisite]: https://tools.ietf.org/html/rfc2136#section-2.4
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Prerequisite {
+    /// At least one resource record with the name and type exists.
+    RRsetExists(DomainName, Type),
+    /// A set of resource records exists, which is equal to all records with the same name and
+    /// type. The class of the resource records has to be the class of the zone.
+    RRsetExistsValue(RR),
+    /// No resource record with the name and type exists.
+    RRsetDoesNotExist(DomainName, Type),
+    /// At least one resource record with the name exists.
+    NameInUse(DomainName),
+    /// No resource record with the name exists.
+    NameNotInUse(DomainName),
+}
+
+/// An [update], which is applied to the zone.
+///
+/// [update]: https://tools.ietf.org/html/rfc2136#section-2.5
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Update {
+    /// Add the resource record to the RRset. The class of the resource record has to be the class
+    /// of the zone.
+    Add(RR),
+    /// Delete all resource records with the name and type.
+    DeleteRRset(DomainName, Type),
+    /// Delete all resource records with the name.
+    DeleteAllRRsets(DomainName),
+    /// Delete the resource record from the RRset. The class and the TTL of the resource record
+    /// are ignored.
+    DeleteRR(RR),
+}
+
+/// An [UPDATE] message.
+///
+/// [UPDATE]: https://tools.ietf.org/html/rfc2136#section-2
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateMessage {
+    pub id: u16,
+    pub flags: Flags,
+    /// The name of the zone, which is updated.
+    pub zone: DomainName,
+    /// The class of the zone.
+    pub class: Class,
+    pub prerequisites: Vec<Prerequisite>,
+    pub updates: Vec<Update>,
+    pub additionals: Vec<RR>,
+}
+
+impl UpdateMessage {
+    /// Create an empty UPDATE request for the zone.
+    pub fn new(id: u16, zone: DomainName, class: Class) -> UpdateMessage {
+        let flags = Flags {
+            qr: false,
+            opcode: Opcode::Update,
+            aa: false,
+            tc: false,
+            rd: false,
+            ra: false,
+            ad: false,
+            cd: false,
+            rcode: RCode::NoError,
+        };
+        UpdateMessage {
+            id,
+            flags,
+            zone,
+            class,
+            prerequisites: Vec::new(),
+            updates: Vec::new(),
+            additionals: Vec::new(),
+        }
+    }
+
+    pub fn decode(bytes: Bytes) -> UpdateResult<UpdateMessage> {
+        let dns = Dns::decode(bytes)?;
+        UpdateMessage::try_from(dns)
+    }
+
+    pub fn encode(&self) -> UpdateResult<BytesMut> {
+        let dns = Dns::try_from(self)?;
+        Ok(dns.encode()?)
+    }
+}
+
+fn zone_class(q_class: QClass) -> UpdateResult<Class> {
+    match Class::try_from(q_class as u16) {
+        Ok(class) => Ok(class),
+        Err(_) => Err(UpdateError::ZoneClass(q_class)),
+    }
+}
+
+fn zone_q_class(class: Class) -> QClass {
+    match class {
+        Class::IN => QClass::IN,
+        Class::CS => QClass::CS,
+        Class::CH => QClass::CH,
+        Class::HS => QClass::HS,
+        Class::NONE => QClass::NONE,
+        Class::ANY => QClass::ANY,
+    }
+}
+
+fn update_type(type_code: u16) -> UpdateResult<Type> {
+    match Type::try_from(type_code) {
+        Ok(type_) => Ok(type_),
+        Err(type_code) => Err(UpdateError::Type(type_code)),
+    }
+}
+
+/// Create a resource record with an empty RDATA and a TTL of zero.
+fn empty_rr(domain_name: &DomainName, class: Class, type_code: u16) -> RR {
+    RR::Unknown(Unknown {
+        domain_name: domain_name.clone(),
+        ttl: 0,
+        class,
+        type_code,
+        rdata: Vec::new(),
+    })
+}
+
+impl Prerequisite {
+    fn from_rr(rr: RR) -> UpdateResult<Prerequisite> {
+        // The TTL of every prerequisite is zero, see RFC 2136 Section 2.4.
+        if rr.get_ttl().unwrap_or(0) != 0 {
+            return Err(UpdateError::Prerequisite(Box::new(rr)));
+        }
+        let unknown = match &rr {
+            RR::Unknown(unknown) if unknown.class == Class::ANY || unknown.class == Class::NONE => {
+                unknown
+            }
+            _ => return Ok(Prerequisite::RRsetExistsValue(rr)),
+        };
+        if !unknown.rdata.is_empty() {
+            return Err(UpdateError::Prerequisite(Box::new(rr)));
+        }
+        let domain_name = unknown.domain_name.clone();
+        match (unknown.class, unknown.type_code) {
+            (Class::ANY, TYPE_ANY) => Ok(Prerequisite::NameInUse(domain_name)),
+            (Class::ANY, type_code) => Ok(Prerequisite::RRsetExists(
+                domain_name,
+                update_type(type_code)?,
+            )),
+            (_, TYPE_ANY) => Ok(Prerequisite::NameNotInUse(domain_name)),
+            (_, type_code) => Ok(Prerequisite::RRsetDoesNotExist(
+                domain_name,
+                update_type(type_code)?,
+            )),
+        }
+    }
+
+    fn to_rr(&self) -> RR {
+        match self {
+            Prerequisite::RRsetExists(domain_name, type_) => {
+                empty_rr(domain_name, Class::ANY, *type_ as u16)
+            }
+            Prerequisite::RRsetExistsValue(rr) => rr.clone(),
+            Prerequisite::RRsetDoesNotExist(domain_name, type_) => {
+                empty_rr(domain_name, Class::NONE, *type_ as u16)
+            }
+            Prerequisite::NameInUse(domain_name) => empty_rr(domain_name, Class::ANY, TYPE_ANY),
+            Prerequisite::NameNotInUse(domain_name) => empty_rr(domain_name, Class::NONE, TYPE_ANY),
+        }
+    }
+}
+
+impl Update {
+    fn from_rr(rr: RR, class: Class) -> UpdateResult<Update> {
+        let unknown = match &rr {
+            RR::Unknown(unknown) if unknown.class == Class::ANY || unknown.class == Class::NONE => {
+                unknown
+            }
+            _ => return Ok(Update::Add(rr)),
+        };
+        let domain_name = unknown.domain_name.clone();
+        match (unknown.class, unknown.type_code, unknown.rdata.is_empty()) {
+            (Class::ANY, TYPE_ANY, true) => Ok(Update::DeleteAllRRsets(domain_name)),
+            (Class::ANY, type_code, true) => {
+                Ok(Update::DeleteRRset(domain_name, update_type(type_code)?))
+            }
+            (Class::NONE, _, false) => Ok(Update::DeleteRR(unknown.decode_rdata(class)?)),
+            _ => Err(UpdateError::Update(Box::new(rr))),
+        }
+    }
+
+    fn to_rr(&self) -> UpdateResult<RR> {
+        match self {
+            Update::Add(rr) => Ok(rr.clone()),
+            Update::DeleteRRset(domain_name, type_) => {
+                Ok(empty_rr(domain_name, Class::ANY, *type_ as u16))
+            }
+            Update::DeleteAllRRsets(domain_name) => Ok(empty_rr(domain_name, Class::ANY, TYPE_ANY)),
+            Update::DeleteRR(rr) => {
+                let (type_code, rdata) = rr.encode_rdata()?;
+                let domain_name = match rr.get_domain_name() {
+                    Some(domain_name) => domain_name.clone(),
+                    None => return Err(UpdateError::Update(Box::new(rr.clone()))),
+                };
+                Ok(RR::Unknown(Unknown {
+                    domain_name,
+                    ttl: 0,
+                    class: Class::NONE,
+                    type_code,
+                    rdata,
+                }))
+            }
+        }
+    }
+}
+
+impl TryFrom<Dns> for UpdateMessage {
+    type Error = UpdateError;
+
+    fn try_from(dns: Dns) -> Result<Self, Self::Error> {
+        if dns.flags.opcode != Opcode::Update {
+            return Err(UpdateError::Opcode(dns.flags.opcode));
+        }
+        let zone = match &dns.questions[..] {
+            [zone] => zone,
+            questions => return Err(UpdateError::ZoneCount(questions.len())),
+        };
+        if zone.q_type != QType::SOA {
+            return Err(UpdateError::ZoneType(zone.q_type));
+        }
+        let class = zone_class(zone.q_class)?;
+
+        let prerequisites = dns
+            .answers
+            .into_iter()
+            .map(Prerequisite::from_rr)
+            .collect::<UpdateResult<Vec<_>>>()?;
+        let updates = dns
+            .authorities
+            .into_iter()
+            .map(|rr| Update::from_rr(rr, class))
+            .collect::<UpdateResult<Vec<_>>>()?;
+        Ok(UpdateMessage {
+            id: dns.id,
+            flags: dns.flags,
+            zone: zone.domain_name.clone(),
+            class,
+            prerequisites,
+            updates,
+            additionals: dns.additionals,
+        })
+    }
+}
+
+impl TryFrom<&UpdateMessage> for Dns {
+    type Error = UpdateError;
+
+    fn try_from(update: &UpdateMessage) -> Result<Self, Self::Error> {
+        let zone = Question {
+            domain_name: update.zone.clone(),
+            q_class: zone_q_class(update.class),
+            q_type: QType::SOA,
+        };
+        let answers = update
+            .prerequisites
+            .iter()
+            .map(Prerequisite::to_rr)
+            .collect();
+        let authorities = update
+            .updates
+            .iter()
+            .map(Update::to_rr)
+            .collect::<UpdateResult<Vec<_>>>()?;
+        Ok(Dns {
+            id: update.id,
+            flags: update.flags.clone(),
+            questions: vec![zone],
+            answers,
+            authorities,
+            additionals: update.additionals.clone(),
+        })
+    }
+}
@@ -0,0 +1,108 @@
+use crate::rr::edns::{CookieError, ExtendedDNSErrorExtraTextError};
+use crate::rr::{AddressError, Class, ISDNError, PSDNAddressError, TagError, Type};
+use crate::{DecodeError, DomainName, DomainNameError, LabelError};
+use hex::FromHexError;
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum ZoneErrorKind {
+    #[error("Quoted string is not terminated")]
+    UnterminatedString,
+    #[error("Parentheses are not balanced")]
+    Parentheses,
+    #[error("Invalid escape sequence: {0}")]
+    Escape(String),
+    #[error("Unknown directive: {0}")]
+    Directive(String),
+    #[error("Domain name is relative, but the origin is not set: {0}")]
+    Origin(String),
+    #[error("Owner is omitted, but there is no previous owner")]
+    Owner,
+    #[error("TTL is omitted, but there is no default TTL")]
+    TTL,
+    #[error("Expected {0}")]
+    Missing(&'static str),
+    #[error("Unexpected token: {0}")]
+    Unexpected(String),
+    #[error("Could not parse integer: {0}")]
+    Integer(String),
+    #[error("Could not parse TTL: {0}")]
+    TTLValue(String),
+    #[error("Unknown type: {0}")]
+    Type(String),
+    #[error("Unknown class: {0}")]
+    Class(String),
+    #[error("Class {1} is not supported for {0} records")]
+    TypeClass(Type, Class),
+    #[error("Invalid {0}: {1}")]
+    Value(&'static str, String),
+    #[error("Could not parse IPv4 address: {0}")]
+    Ipv4Addr(String),
+    #[error("Could not parse IPv6 address: {0}")]
+    Ipv6Addr(String),
+    #[error("Could not parse timestamp: {0}")]
+    Timestamp(String),
+    #[error("Character string is too long: {0}")]
+    CharacterString(usize),
+    #[error("Character string is not valid UTF-8, which is required by the struct: {0}")]
+    CharacterStringUtf8(String),
+    #[error("Could not parse label: {0}")]
+    LabelError(#[from] LabelError),
+    #[error("Could not parse domain name: {0}")]
+    DomainNameError(#[from] DomainNameError),
+    #[error("Could not parse hex string: {0}")]
+    FromHexError(#[from] FromHexError),
+    #[error("Could not parse base64 string: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("Could not parse base32hex string: {0}")]
+    Base32(#[from] data_encoding::DecodeError),
+    #[error("Could not parse address: {0}")]
+    AddressError(#[from] AddressError),
+    #[error("Could not parse PSDN address: {0}")]
+    PSDNAddressError(#[from] PSDNAddressError),
+    #[error("Could not parse ISDN: {0}")]
+    ISDNError(#[from] ISDNError),
+    #[error("Could not parse tag: {0}")]
+    TagError(#[from] TagError),
+    #[error("Could not parse cookie: {0}")]
+    CookieError(#[from] CookieError),
+    #[error("Could not parse extra text: {0}")]
+    ExtendedDNSErrorExtraTextError(#[from] ExtendedDNSErrorExtraTextError),
+    #[error("The record is not a {0} record")]
+    RRType(&'static str),
+    #[error("Unknown EDNS option: {0}")]
+    EDNSOption(String),
+    #[error("OPT record domain name is not root: {0}")]
+    OPTDomainName(DomainName),
+    #[error("The RDATA length does not match: expected {0} got {1}")]
+    RDataLength(usize, usize),
+    #[error("Could not decode the RDATA: {0}")]
+    DecodeError(Box<DecodeError>),
+    #[error("The presentation format of type {0} is not supported")]
+    NotYetImplemented(Type),
+    #[error("Could not include {0}: {1}")]
+    Include(String, String),
+    #[error("$INCLUDE is used, but there is no include resolver")]
+    IncludeResolver,
+    #[error("Too many nested $INCLUDE directives: {0}")]
+    IncludeDepth(usize),
+}
+
+impl From<DecodeError> for ZoneErrorKind {
+    fn from(decode_error: DecodeError) -> Self {
+        ZoneErrorKind::DecodeError(Box::new(decode_error))
+    }
+}
+
+/// An error, which occurred while parsing a zone file.
+///
+/// The line and the column start at one and refer to the file, which contains the error. If the
+/// error is in a file, which was included by `$INCLUDE`, then `file` contains its name.
+#[derive(Debug, PartialEq, Error)]
+#[error("{}{line}:{column}: {kind}", .file.as_ref().map(|file| format!("{}:", file)).unwrap_or_default())]
+pub struct ZoneError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub kind: ZoneErrorKind,
+}
@@ -0,0 +1,163 @@
+use super::lexer::{Entry, Lexer};
+use super::parser::ZoneParser;
+use super::scanner::{class, type_code, Scanner};
+use super::{ZoneError, ZoneErrorKind, ZoneResult};
+use crate::question::{QClass, QType, Question};
+use crate::rr::{
+    ServiceBinding, Unknown, A, AAAA, AFSDB, APL, CAA, CNAME, DNAME, DNSKEY, DS, EID, EUI48, EUI64,
+    GPOS, HINFO, ISDN, KX, L32, L64, LOC, LP, MB, MD, MF, MG, MINFO, MR, MX, NAPTR, NID, NIMLOC,
+    NS, NSAP, NSEC, NSEC3, NSEC3PARAM, NULL, OPT, PTR, PX, RP, RR, RRSIG, RT, SMIMEA, SOA, SRV,
+    SSHFP, TLSA, TSIG, TXT, URI, WKS, X25,
+};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// Returns the only entry of the input, which can span multiple lines by parentheses.
+fn single_entry(input: &str) -> ZoneResult<Entry<'_>> {
+    let mut lexer = Lexer::new(input);
+    let mut entry = match lexer.entry()? {
+        Some(entry) => entry,
+        None => {
+            return Err(ZoneError {
+                file: None,
+                line: lexer.line(),
+                column: lexer.column(),
+                kind: ZoneErrorKind::Missing("entry"),
+            })
+        }
+    };
+    if let Some(next_entry) = lexer.entry()? {
+        let token = &next_entry.tokens[0];
+        return Err(token.error(ZoneErrorKind::Unexpected(token.text.to_owned())));
+    }
+    // Leading whitespaces do not omit the owner.
+    entry.blank = false;
+    Ok(entry)
+}
+
+impl FromStr for RR {
+    type Err = ZoneError;
+
+    /// Parse a resource record in the presentation format, which is written by the `Display`
+    /// trait.
+    ///
+    /// The domain names have to be absolute and the TTL and the class can not be omitted, except
+    /// for the OPT record.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entry = single_entry(s)?;
+        ZoneParser::new(None).rr(&entry)
+    }
+}
+
+macro_rules! impl_from_str {
+    ($i:ident) => {
+        impl_from_str!($i, $i);
+    };
+    ($i:ident, $($v:ident),+) => {
+        impl FromStr for $i {
+            type Err = ZoneError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match RR::from_str(s)? {
+                    $(
+                        RR::$v(rr) => Ok(rr),
+                    )+
+                    _ => Err(ZoneError {
+                        file: None,
+                        line: 1,
+                        column: 1,
+                        kind: ZoneErrorKind::RRType(stringify!($i)),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_from_str!(A);
+impl_from_str!(NS);
+impl_from_str!(MD);
+impl_from_str!(MF);
+impl_from_str!(CNAME);
+impl_from_str!(SOA);
+impl_from_str!(MB);
+impl_from_str!(MG);
+impl_from_str!(MR);
+impl_from_str!(NULL);
+impl_from_str!(WKS);
+impl_from_str!(PTR);
+impl_from_str!(HINFO);
+impl_from_str!(MINFO);
+impl_from_str!(MX);
+impl_from_str!(TXT);
+impl_from_str!(RP);
+impl_from_str!(AFSDB);
+impl_from_str!(X25);
+impl_from_str!(ISDN);
+impl_from_str!(RT);
+impl_from_str!(NSAP);
+impl_from_str!(PX);
+impl_from_str!(GPOS);
+impl_from_str!(AAAA);
+impl_from_str!(LOC);
+impl_from_str!(NIMLOC);
+impl_from_str!(SRV);
+impl_from_str!(KX);
+impl_from_str!(DNAME);
+impl_from_str!(OPT);
+impl_from_str!(APL);
+impl_from_str!(SSHFP);
+impl_from_str!(URI);
+impl_from_str!(EID);
+impl_from_str!(NID);
+impl_from_str!(L32);
+impl_from_str!(L64);
+impl_from_str!(LP);
+impl_from_str!(EUI48);
+impl_from_str!(EUI64);
+impl_from_str!(DS, DS, CDS);
+impl_from_str!(DNSKEY, DNSKEY, CDNSKEY);
+impl_from_str!(CAA);
+impl_from_str!(ServiceBinding, SVCB, HTTPS);
+impl_from_str!(RRSIG);
+impl_from_str!(NSEC);
+impl_from_str!(NSEC3);
+impl_from_str!(NSEC3PARAM);
+impl_from_str!(TLSA);
+impl_from_str!(SMIMEA);
+impl_from_str!(NAPTR);
+impl_from_str!(TSIG);
+impl_from_str!(Unknown);
+
+impl FromStr for Question {
+    type Err = ZoneError;
+
+    /// Parse a question in the format `<domain name> <class> <type>`, which is written by the
+    /// `Display` trait.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entry = single_entry(s)?;
+        let mut scanner = Scanner::new(&entry.tokens, None, entry.end_line, entry.end_column);
+        let domain_name = scanner.domain_name("domain name")?;
+        let q_class = scanner.parse("class", |token| {
+            let class_error = || ZoneErrorKind::Class(token.text.to_owned());
+            if let Ok(q_class) = QClass::from_str(token.text) {
+                return Ok(q_class);
+            }
+            let class = class(token).ok_or_else(class_error)??;
+            QClass::try_from(class as u16).map_err(|_| class_error())
+        })?;
+        let q_type = scanner.parse("type", |token| {
+            if let Ok(q_type) = QType::from_str(token.text) {
+                return Ok(q_type);
+            }
+            QType::try_from(type_code(token)?)
+                .map_err(|_| ZoneErrorKind::Type(token.text.to_owned()))
+        })?;
+        scanner.finished()?;
+        Ok(Question {
+            domain_name,
+            q_class,
+            q_type,
+        })
+    }
+}
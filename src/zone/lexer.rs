@@ -0,0 +1,321 @@
+use super::{ZoneError, ZoneErrorKind, ZoneResult};
+
+/// A token of an entry, which is either a character string or a quoted string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Token<'a> {
+    /// The text of the token without the quotes, the escape sequences are not resolved.
+    pub(super) text: &'a str,
+    pub(super) quoted: bool,
+    pub(super) line: usize,
+    pub(super) column: usize,
+}
+
+impl<'a> Token<'a> {
+    pub(super) fn error(&self, kind: ZoneErrorKind) -> ZoneError {
+        ZoneError {
+            file: None,
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+
+    /// Returns `true` if the token is not quoted and equal to the text, the case is ignored.
+    pub(super) fn is(&self, text: &str) -> bool {
+        !self.quoted && self.text.eq_ignore_ascii_case(text)
+    }
+
+    /// Resolve the escape sequences `\X` and `\DDD`, see [RFC 1035 Section 5.1].
+    ///
+    /// [RFC 1035 Section 5.1]: https://tools.ietf.org/html/rfc1035#section-5.1
+    pub(super) fn unescape(&self) -> Result<Vec<u8>, ZoneErrorKind> {
+        unescape(self.text)
+    }
+
+    /// Resolve the escape sequences and return the result as string.
+    ///
+    /// An error is returned, if the result is not valid UTF-8, for example `\255`.
+    pub(super) fn unescape_string(&self) -> Result<String, ZoneErrorKind> {
+        String::from_utf8(self.unescape()?)
+            .map_err(|_| ZoneErrorKind::CharacterStringUtf8(self.text.to_owned()))
+    }
+}
+
+/// Returns the index after the escape sequence, which starts at the backslash at `index`.
+fn escape_end(text: &[u8], index: usize) -> Result<(u8, usize), ZoneErrorKind> {
+    let escape_error = || {
+        let end = text.len().min(index + 4);
+        ZoneErrorKind::Escape(String::from_utf8_lossy(&text[index..end]).into_owned())
+    };
+    match text.get(index + 1) {
+        Some(b) if b.is_ascii_digit() => {
+            let digits = text.get(index + 1..index + 4).ok_or_else(escape_error)?;
+            if !digits.iter().all(u8::is_ascii_digit) {
+                return Err(escape_error());
+            }
+            let value = digits
+                .iter()
+                .fold(0u16, |value, digit| value * 10 + (digit - b'0') as u16);
+            if value > u8::MAX as u16 {
+                return Err(escape_error());
+            }
+            Ok((value as u8, index + 4))
+        }
+        Some(b) => Ok((*b, index + 2)),
+        None => Err(escape_error()),
+    }
+}
+
+pub(super) fn unescape(text: &str) -> Result<Vec<u8>, ZoneErrorKind> {
+    let text = text.as_bytes();
+    let mut result = Vec::with_capacity(text.len());
+    let mut index = 0;
+    while index < text.len() {
+        if text[index] == b'\\' {
+            let (b, end) = escape_end(text, index)?;
+            result.push(b);
+            index = end;
+        } else {
+            result.push(text[index]);
+            index += 1;
+        }
+    }
+    Ok(result)
+}
+
+/// Split the text at the separator, which is not escaped, and unescape the parts.
+pub(super) fn split_unescaped(text: &str, separator: u8) -> Result<Vec<Vec<u8>>, ZoneErrorKind> {
+    let text = text.as_bytes();
+    let mut parts = Vec::new();
+    let mut part = Vec::new();
+    let mut index = 0;
+    while index < text.len() {
+        match text[index] {
+            b'\\' => {
+                let (b, end) = escape_end(text, index)?;
+                part.push(b);
+                index = end;
+            }
+            b if b == separator => {
+                parts.push(part);
+                part = Vec::new();
+                index += 1;
+            }
+            b => {
+                part.push(b);
+                index += 1;
+            }
+        }
+    }
+    parts.push(part);
+    Ok(parts)
+}
+
+/// Split the text of a domain name at the dots, which are not escaped.
+///
+/// The labels are unescaped and `true` is returned if the domain name is absolute.
+pub(super) fn split_labels(text: &str) -> Result<(Vec<Vec<u8>>, bool), ZoneErrorKind> {
+    if text == "." {
+        return Ok((Vec::new(), true));
+    }
+    let mut labels = split_unescaped(text, b'.')?;
+    let absolute = labels.len() > 1 && labels.last().is_some_and(Vec::is_empty);
+    if absolute {
+        labels.pop();
+    }
+    Ok((labels, absolute))
+}
+
+/// An entry of a zone file, which is a directive or a resource record.
+#[derive(Debug)]
+pub(super) struct Entry<'a> {
+    /// `true` if the entry starts with a blank, which means the owner is omitted.
+    pub(super) blank: bool,
+    pub(super) tokens: Vec<Token<'a>>,
+    /// The position after the last token, which is used if a token is missing.
+    pub(super) end_line: usize,
+    pub(super) end_column: usize,
+}
+
+/// Split a zone file into entries, see [RFC 1035 Section 5.1].
+///
+/// Comments are removed and entries, which contain parentheses, are joined.
+///
+/// [RFC 1035 Section 5.1]: https://tools.ietf.org/html/rfc1035#section-5.1
+pub(super) struct Lexer<'a> {
+    input: &'a str,
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub(super) fn new(input: &'a str) -> Lexer<'a> {
+        Lexer {
+            input,
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub(super) fn line(&self) -> usize {
+        self.line
+    }
+
+    pub(super) fn column(&self) -> usize {
+        self.column
+    }
+
+    fn error(&self, line: usize, column: usize, kind: ZoneErrorKind) -> ZoneError {
+        ZoneError {
+            file: None,
+            line,
+            column,
+            kind,
+        }
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.offset).copied()
+    }
+
+    fn advance(&mut self) {
+        if let Some(b) = self.peek() {
+            self.offset += 1;
+            if b == b'\n' {
+                self.line += 1;
+                self.column = 1;
+            } else if b & 0b1100_0000 != 0b1000_0000 {
+                // Count only the first byte of an UTF-8 character.
+                self.column += 1;
+            }
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        while let Some(b) = self.peek() {
+            if b == b'\n' {
+                break;
+            }
+            self.advance();
+        }
+    }
+
+    fn quoted_token(&mut self) -> ZoneResult<Token<'a>> {
+        let (line, column) = (self.line, self.column);
+        self.advance();
+        let start = self.offset;
+        loop {
+            match self.peek() {
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    self.advance();
+                    if self.peek() != Some(b'\n') {
+                        self.advance();
+                    }
+                }
+                Some(b'\n') | None => {
+                    return Err(self.error(line, column, ZoneErrorKind::UnterminatedString))
+                }
+                Some(_) => self.advance(),
+            }
+        }
+        let text = &self.input[start..self.offset];
+        self.advance();
+        Ok(Token {
+            text,
+            quoted: true,
+            line,
+            column,
+        })
+    }
+
+    fn token(&mut self) -> Token<'a> {
+        let (line, column) = (self.line, self.column);
+        let start = self.offset;
+        while let Some(b) = self.peek() {
+            match b {
+                b' ' | b'\t' | b'\r' | b'\n' | b';' | b'(' | b')' | b'"' => break,
+                b'\\' => {
+                    self.advance();
+                    if self.peek() != Some(b'\n') {
+                        self.advance();
+                    }
+                }
+                _ => self.advance(),
+            }
+        }
+        Token {
+            text: &self.input[start..self.offset],
+            quoted: false,
+            line,
+            column,
+        }
+    }
+
+    /// Returns the next entry, which contains at least one token, or `None` at the end of the
+    /// input.
+    pub(super) fn entry(&mut self) -> ZoneResult<Option<Entry<'a>>> {
+        loop {
+            if self.peek().is_none() {
+                return Ok(None);
+            }
+            let blank = matches!(self.peek(), Some(b' ') | Some(b'\t'));
+            let mut tokens = Vec::new();
+            let mut parentheses = None;
+            loop {
+                match self.peek() {
+                    None => break,
+                    Some(b'\n') => {
+                        if parentheses.is_none() {
+                            break;
+                        }
+                        self.advance();
+                    }
+                    Some(b' ') | Some(b'\t') | Some(b'\r') => self.advance(),
+                    Some(b';') => self.skip_comment(),
+                    Some(b'(') => {
+                        if parentheses.is_some() {
+                            return Err(self.error(
+                                self.line,
+                                self.column,
+                                ZoneErrorKind::Parentheses,
+                            ));
+                        }
+                        parentheses = Some((self.line, self.column));
+                        self.advance();
+                    }
+                    Some(b')') => {
+                        if parentheses.is_none() {
+                            return Err(self.error(
+                                self.line,
+                                self.column,
+                                ZoneErrorKind::Parentheses,
+                            ));
+                        }
+                        parentheses = None;
+                        self.advance();
+                    }
+                    Some(b'"') => tokens.push(self.quoted_token()?),
+                    Some(_) => tokens.push(self.token()),
+                }
+            }
+            if let Some((line, column)) = parentheses {
+                return Err(self.error(line, column, ZoneErrorKind::Parentheses));
+            }
+            let (end_line, end_column) = (self.line, self.column);
+            // Skip the newline.
+            self.advance();
+            if !tokens.is_empty() {
+                return Ok(Some(Entry {
+                    blank,
+                    tokens,
+                    end_line,
+                    end_column,
+                }));
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! This module contains a parser for [zone files].
+//!
+//! The [`ZoneParser`] struct parses the master file format of [RFC 1035 Section 5] into
+//! [resource records].
+//!
+//! The same parser implements the `FromStr` trait for [`RR`], [`Question`] and each resource
+//! record struct. The text, which is written by the `Display` trait, is parsed back into an
+//! equal value.
+//!
+//! # Example
+//! ```rust
+//! use dns_message_parser::zone::ZoneParser;
+//!
+//! let included = "www A 10.0.0.2";
+//! let mut resolver = |file_name: &str| {
+//!     assert_eq!(file_name, "www.zone");
+//!     Ok(included.to_string())
+//! };
+//!
+//! let zone = "\
+//! $TTL 1h
+//! @          NS    ns1.example.org.
+//!            MX    10 mail ; The mail server
+//! mail  300  A     10.0.0.1
+//! $INCLUDE www.zone
+//! ";
+//! let mut parser = ZoneParser::new(Some("example.org".parse().unwrap()));
+//! parser.set_include_resolver(&mut resolver);
+//! let rrs = parser.parse(zone).unwrap();
+//! for rr in rrs {
+//!     println!("{}", rr);
+//! }
+//! ```
+//!
+//! [zone files]: https://tools.ietf.org/html/rfc1035#section-5
+//! [RFC 1035 Section 5]: https://tools.ietf.org/html/rfc1035#section-5
+//! [resource records]: crate::rr::RR
+//! [`RR`]: crate::rr::RR
+//! [`Question`]: crate::question::Question
+
+mod error;
+mod from_str;
+mod lexer;
+mod parser;
+mod rr;
+mod scanner;
+
+pub use error::{ZoneError, ZoneErrorKind};
+pub use parser::{IncludeResolver, ZoneParser, INCLUDE_MAX_DEPTH};
+
+pub type ZoneResult<T> = std::result::Result<T, ZoneError>;
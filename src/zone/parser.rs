@@ -0,0 +1,252 @@
+use super::lexer::{Entry, Lexer, Token};
+use super::rr::Header;
+use super::scanner::{class, ttl, type_code, Scanner};
+use super::{ZoneErrorKind, ZoneResult};
+use crate::rr::{Class, RR};
+use crate::DomainName;
+use std::io::Result as IoResult;
+
+/// The maximum depth of nested `$INCLUDE` directives.
+pub const INCLUDE_MAX_DEPTH: usize = 16;
+
+/// Resolve the file name of an `$INCLUDE` directive and return the content of the file.
+///
+/// The trait is implemented for closures, therefore the resolver can read the file from the file
+/// system or from memory.
+pub trait IncludeResolver {
+    fn resolve(&mut self, file_name: &str) -> IoResult<String>;
+}
+
+impl<F> IncludeResolver for F
+where
+    F: FnMut(&str) -> IoResult<String>,
+{
+    fn resolve(&mut self, file_name: &str) -> IoResult<String> {
+        self(file_name)
+    }
+}
+
+/// A parser for [zone files], which returns the resource records.
+///
+/// The parser supports the directives `$ORIGIN`, `$TTL` and `$INCLUDE`, relative domain names,
+/// `@` for the origin, the omission of the owner, the TTL and the class, parentheses, comments,
+/// quoted strings and escape sequences.
+///
+/// If the TTL of a resource record is omitted then the TTL of the last `$TTL` directive is used,
+/// or the TTL of the previous resource record if there is no `$TTL` directive. If the class is
+/// omitted then the class of the previous resource record is used, which is `IN` by default.
+///
+/// # Example
+/// ```rust
+/// use dns_message_parser::rr::{RR, A};
+/// use dns_message_parser::zone::ZoneParser;
+///
+/// let zone = "\
+/// $ORIGIN example.org.
+/// $TTL 3600
+/// @    IN  SOA  ns1 hostmaster ( 2021010101 ; serial
+///                                7200 3600 1209600 3600 )
+///          NS   ns1
+/// ns1      A    10.0.0.1
+/// ";
+/// let rrs = ZoneParser::new(None).parse(zone).unwrap();
+/// assert_eq!(rrs.len(), 3);
+/// assert_eq!(
+///     rrs[2],
+///     RR::A(A {
+///         domain_name: "ns1.example.org".parse().unwrap(),
+///         ttl: 3600,
+///         ipv4_addr: "10.0.0.1".parse().unwrap(),
+///     })
+/// );
+/// ```
+///
+/// [zone files]: https://tools.ietf.org/html/rfc1035#section-5
+pub struct ZoneParser<'r> {
+    origin: Option<DomainName>,
+    default_ttl: Option<u32>,
+    last_owner: Option<DomainName>,
+    last_ttl: Option<u32>,
+    last_class: Class,
+    resolver: Option<&'r mut dyn IncludeResolver>,
+    depth: usize,
+}
+
+impl<'r> ZoneParser<'r> {
+    /// Create a parser, the origin is used for relative domain names until a `$ORIGIN` directive
+    /// is found.
+    pub fn new(origin: Option<DomainName>) -> ZoneParser<'r> {
+        ZoneParser {
+            origin,
+            default_ttl: None,
+            last_owner: None,
+            last_ttl: None,
+            last_class: Class::IN,
+            resolver: None,
+            depth: 0,
+        }
+    }
+
+    /// Set the TTL, which is used if the TTL is omitted and there is no `$TTL` directive.
+    pub fn set_default_ttl(&mut self, ttl: u32) {
+        self.default_ttl = Some(ttl);
+    }
+
+    /// Set the resolver, which is used to read the files of `$INCLUDE` directives.
+    ///
+    /// Without a resolver an `$INCLUDE` directive is an error.
+    pub fn set_include_resolver(&mut self, resolver: &'r mut dyn IncludeResolver) {
+        self.resolver = Some(resolver);
+    }
+
+    /// Parse the zone file and return the resource records in the order of the file.
+    pub fn parse(&mut self, input: &str) -> ZoneResult<Vec<RR>> {
+        let mut rrs = Vec::new();
+        self.parse_into(input, &mut rrs)?;
+        Ok(rrs)
+    }
+
+    fn parse_into(&mut self, input: &str, rrs: &mut Vec<RR>) -> ZoneResult<()> {
+        let mut lexer = Lexer::new(input);
+        while let Some(entry) = lexer.entry()? {
+            let first = &entry.tokens[0];
+            if !entry.blank && !first.quoted && first.text.starts_with('$') {
+                self.directive(&entry, rrs)?;
+            } else {
+                rrs.push(self.rr(&entry)?);
+            }
+        }
+        Ok(())
+    }
+
+    fn directive(&mut self, entry: &Entry, rrs: &mut Vec<RR>) -> ZoneResult<()> {
+        let origin = self.origin.clone();
+        let mut scanner = Scanner::new(
+            &entry.tokens,
+            origin.as_ref(),
+            entry.end_line,
+            entry.end_column,
+        );
+        let directive = scanner.next("directive")?;
+        if directive.is("$ORIGIN") {
+            let origin = scanner.domain_name("origin")?;
+            scanner.finished()?;
+            self.origin = Some(origin);
+        } else if directive.is("$TTL") {
+            let ttl = scanner.ttl("TTL")?;
+            scanner.finished()?;
+            self.default_ttl = Some(ttl);
+        } else if directive.is("$INCLUDE") {
+            let file_name = scanner.parse("file name", |token| token.unescape_string())?;
+            let origin = if scanner.is_finished() {
+                None
+            } else {
+                Some(scanner.domain_name("origin")?)
+            };
+            scanner.finished()?;
+            self.include(directive, file_name, origin, rrs)?;
+        } else {
+            return Err(directive.error(ZoneErrorKind::Directive(directive.text.to_owned())));
+        }
+        Ok(())
+    }
+
+    /// Parse the included file, the origin and the depth are restored afterwards.
+    fn include(
+        &mut self,
+        directive: &Token,
+        file_name: String,
+        origin: Option<DomainName>,
+        rrs: &mut Vec<RR>,
+    ) -> ZoneResult<()> {
+        if self.depth >= INCLUDE_MAX_DEPTH {
+            return Err(directive.error(ZoneErrorKind::IncludeDepth(self.depth)));
+        }
+        let resolver = match self.resolver.as_mut() {
+            Some(resolver) => resolver,
+            None => return Err(directive.error(ZoneErrorKind::IncludeResolver)),
+        };
+        let input = resolver.resolve(&file_name).map_err(|e| {
+            directive.error(ZoneErrorKind::Include(file_name.clone(), e.to_string()))
+        })?;
+
+        let parent_origin = self.origin.clone();
+        if origin.is_some() {
+            self.origin = origin;
+        }
+        self.depth += 1;
+        let result = self.parse_into(&input, rrs);
+        self.depth -= 1;
+        self.origin = parent_origin;
+        result.map_err(|mut e| {
+            if e.file.is_none() {
+                e.file = Some(file_name);
+            }
+            e
+        })
+    }
+
+    /// Parse the OPT record, which has no TTL and class in the format of the `Display` trait.
+    fn rr_opt(&self, mut scanner: Scanner, domain_name: DomainName) -> ZoneResult<RR> {
+        if !domain_name.is_root() {
+            return Err(scanner.error(ZoneErrorKind::OPTDomainName(domain_name)));
+        }
+        scanner.next("type")?;
+        let opt = scanner.rr_opt()?;
+        scanner.finished()?;
+        Ok(RR::OPT(opt))
+    }
+
+    pub(super) fn rr(&mut self, entry: &Entry) -> ZoneResult<RR> {
+        let first = &entry.tokens[0];
+        let mut scanner = Scanner::new(
+            &entry.tokens,
+            self.origin.as_ref(),
+            entry.end_line,
+            entry.end_column,
+        );
+        let domain_name = if entry.blank {
+            match &self.last_owner {
+                Some(last_owner) => last_owner.clone(),
+                None => return Err(first.error(ZoneErrorKind::Owner)),
+            }
+        } else {
+            scanner.domain_name("owner")?
+        };
+        if matches!(scanner.peek(), Some(token) if token.is("OPT")) {
+            return self.rr_opt(scanner, domain_name);
+        }
+
+        // The TTL and the class can be in any order.
+        let mut rr_ttl = None;
+        let mut rr_class = None;
+        while let Some(token) = scanner.peek() {
+            if let (None, Some(class)) = (rr_class, class(token)) {
+                rr_class = Some(class.map_err(|e| token.error(e))?);
+            } else if rr_ttl.is_none() && token.text.starts_with(|c: char| c.is_ascii_digit()) {
+                rr_ttl = Some(ttl(token).map_err(|e| token.error(e))?);
+            } else {
+                break;
+            }
+            scanner.next("TTL or class")?;
+        }
+        let type_code = scanner.parse("type", type_code)?;
+
+        let rr_ttl = match rr_ttl.or(self.default_ttl).or(self.last_ttl) {
+            Some(rr_ttl) => rr_ttl,
+            None => return Err(first.error(ZoneErrorKind::TTL)),
+        };
+        let rr_class = rr_class.unwrap_or(self.last_class);
+        let header = Header {
+            domain_name: domain_name.clone(),
+            ttl: rr_ttl,
+            class: rr_class,
+        };
+        let rr = scanner.rr_rdata(type_code, header)?;
+
+        self.last_owner = Some(domain_name);
+        self.last_ttl = Some(rr_ttl);
+        self.last_class = rr_class;
+        Ok(rr)
+    }
+}
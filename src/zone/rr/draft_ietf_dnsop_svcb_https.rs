@@ -0,0 +1,142 @@
+use super::super::lexer::unescape;
+use super::super::{ZoneErrorKind, ZoneResult};
+use super::Header;
+use crate::rr::{ServiceBinding, ServiceParameter};
+use crate::zone::scanner::Scanner;
+use base64::{engine::general_purpose::STANDARD as Base64Standard, Engine};
+use std::collections::BTreeSet;
+
+/// Parse the name of a service parameter key and return its number.
+fn service_parameter_key(key: &str) -> Option<u16> {
+    let number = match key {
+        "mandatory" => 0,
+        "alpn" => 1,
+        "no-default-alpn" => 2,
+        "port" => 3,
+        "ipv4hint" => 4,
+        "ech" => 5,
+        "ipv6hint" => 6,
+        "reserved" => 65535,
+        key => {
+            let number = key.strip_prefix("key")?;
+            // Leading zeros are not allowed.
+            if number.len() > 1 && number.starts_with('0') {
+                return None;
+            }
+            number.parse().ok()?
+        }
+    };
+    Some(number)
+}
+
+/// Parse the comma separated list of the value, see [RFC 9460 Appendix A.1].
+///
+/// The escape sequences of the presentation format are resolved first, then the value is split
+/// at the commas, which are not escaped by a backslash.
+///
+/// [RFC 9460 Appendix A.1]: https://tools.ietf.org/html/rfc9460#appendix-A.1
+fn value_list<T, F>(value: &str, f: F) -> Result<Vec<T>, ()>
+where
+    F: Fn(Vec<u8>) -> Option<T>,
+{
+    let value = unescape(value).map_err(|_| ())?;
+    let mut items = Vec::new();
+    let mut item = Vec::new();
+    let mut bytes = value.into_iter();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'\\' => item.push(bytes.next().ok_or(())?),
+            b',' => items.push(std::mem::take(&mut item)),
+            byte => item.push(byte),
+        }
+    }
+    items.push(item);
+    items.into_iter().map(|item| f(item).ok_or(())).collect()
+}
+
+fn service_parameter(key: &str, value: Option<&str>) -> Result<ServiceParameter, ZoneErrorKind> {
+    let key_error = || ZoneErrorKind::Value("service parameter key", key.to_owned());
+    let value_error = || ZoneErrorKind::Value("service parameter value", key.to_owned());
+    let number = service_parameter_key(key).ok_or_else(key_error)?;
+    let service_parameter = match (number, value) {
+        (2, None) => ServiceParameter::NO_DEFAULT_ALPN,
+        (65535, None) => ServiceParameter::KEY_65535,
+        (2, Some(_)) | (65535, Some(_)) => return Err(value_error()),
+        (_, None) => return Err(value_error()),
+        (0, Some(value)) => {
+            let key_ids = value_list(value, |key| {
+                service_parameter_key(std::str::from_utf8(&key).ok()?)
+            })
+            .map_err(|_| value_error())?;
+            ServiceParameter::MANDATORY { key_ids }
+        }
+        (1, Some(value)) => {
+            let alpn_ids = value_list(value, |alpn| String::from_utf8(alpn).ok())
+                .map_err(|_| value_error())?;
+            ServiceParameter::ALPN { alpn_ids }
+        }
+        (3, Some(value)) => ServiceParameter::PORT {
+            port: value.parse().map_err(|_| value_error())?,
+        },
+        (4, Some(value)) => {
+            let hints = value_list(value, |hint| std::str::from_utf8(&hint).ok()?.parse().ok())
+                .map_err(|_| value_error())?;
+            ServiceParameter::IPV4_HINT { hints }
+        }
+        (5, Some(value)) => ServiceParameter::ECH {
+            config_list: Base64Standard.decode(value)?,
+        },
+        (6, Some(value)) => {
+            let hints = value_list(value, |hint| std::str::from_utf8(&hint).ok()?.parse().ok())
+                .map_err(|_| value_error())?;
+            ServiceParameter::IPV6_HINT { hints }
+        }
+        (number, Some(value)) => ServiceParameter::PRIVATE {
+            number,
+            wire_data: unescape(value)?,
+        },
+    };
+    Ok(service_parameter)
+}
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    /// Parse a service parameter in the format `key`, `key=value` or `key="value"`.
+    fn rr_service_parameter(&mut self) -> ZoneResult<ServiceParameter> {
+        let token = self.next("service parameter")?;
+        let (key, value) = match token.text.find('=') {
+            Some(index) if index + 1 == token.text.len() => {
+                // The value is quoted, therefore it is the next token.
+                let value = self.next("service parameter value")?;
+                if !value.quoted {
+                    return Err(value.error(ZoneErrorKind::Unexpected(value.text.to_owned())));
+                }
+                (&token.text[..index], Some(value.text))
+            }
+            Some(index) => (&token.text[..index], Some(&token.text[index + 1..])),
+            None => (token.text, None),
+        };
+        service_parameter(key, value).map_err(|e| token.error(e))
+    }
+
+    /// Parse a Service Binding (SVCB or HTTPS) resource record.
+    pub(super) fn rr_service_binding(
+        &mut self,
+        header: Header,
+        https: bool,
+    ) -> ZoneResult<ServiceBinding> {
+        let priority = self.u16("priority")?;
+        let target_name = self.domain_name("target name")?;
+        let mut parameters = BTreeSet::new();
+        while !self.is_finished() {
+            parameters.insert(self.rr_service_parameter()?);
+        }
+        Ok(ServiceBinding {
+            name: header.domain_name,
+            ttl: header.ttl,
+            priority,
+            target_name,
+            parameters,
+            https,
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use crate::rr::edns::NSID;
+use crate::zone::scanner::Scanner;
+use crate::zone::ZoneResult;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    /// Parse the NSID option in the format of the `Display` trait.
+    ///
+    /// The identifier is taken from the hex bytes, the quoted ASCII string is skipped.
+    pub(super) fn rr_edns_nsid(&mut self) -> ZoneResult<NSID> {
+        let mut nsid = Vec::new();
+        while let Some(token) = self.peek() {
+            if token.quoted
+                || token.text.len() != 2
+                || !token.text.bytes().all(|b| b.is_ascii_hexdigit())
+            {
+                break;
+            }
+            let b = hex::decode(token.text).map_err(|e| token.error(e.into()))?;
+            nsid.extend(b);
+            self.next("NSID")?;
+        }
+        if matches!(self.peek(), Some(token) if token.quoted) {
+            self.next("NSID")?;
+        }
+        Ok(NSID(nsid))
+    }
+}
@@ -0,0 +1,100 @@
+use crate::rr::edns::EDNSOption;
+use crate::rr::OPT;
+use crate::zone::lexer::Token;
+use crate::zone::scanner::{integer, Scanner};
+use crate::zone::{ZoneErrorKind, ZoneResult};
+
+/// Returns the code of an EDNS option in the format `OPTION<code>`.
+fn edns_option_unknown_code(token: &Token) -> Option<u16> {
+    match token.text.get(..6) {
+        Some(prefix) if !token.quoted && prefix.eq_ignore_ascii_case("OPTION") => {
+            token.text[6..].parse().ok()
+        }
+        _ => None,
+    }
+}
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    fn rr_edns_option(&mut self) -> ZoneResult<EDNSOption> {
+        let token = self.next("EDNS option")?;
+        let edns_option = if token.is("NSID") {
+            EDNSOption::NSID(self.rr_edns_nsid()?)
+        } else if token.is("DAU") {
+            EDNSOption::DAU(self.rr_edns_dau()?)
+        } else if token.is("DHU") {
+            EDNSOption::DHU(self.rr_edns_dhu()?)
+        } else if token.is("N3U") {
+            EDNSOption::N3U(self.rr_edns_n3u()?)
+        } else if token.is("ECS") {
+            EDNSOption::ECS(self.rr_edns_ecs()?)
+        } else if token.is("Expire") {
+            EDNSOption::Expire(self.rr_edns_expire()?)
+        } else if token.is("Cookie") {
+            EDNSOption::Cookie(self.rr_edns_cookie()?)
+        } else if token.is("TcpKeepalive") {
+            EDNSOption::TcpKeepalive(self.rr_edns_tcp_keepalive()?)
+        } else if token.is("Padding") {
+            EDNSOption::Padding(self.rr_edns_padding()?)
+        } else if token.is("Chain") {
+            EDNSOption::Chain(self.rr_edns_chain()?)
+        } else if token.is("Extended") {
+            EDNSOption::ExtendedDNSErrors(self.rr_edns_extended_dns_errors()?)
+        } else if let Some(code) = edns_option_unknown_code(token) {
+            self.rr_edns_unknown(token, code)?
+        } else {
+            return Err(token.error(ZoneErrorKind::EDNSOption(token.text.to_owned())));
+        };
+        Ok(edns_option)
+    }
+
+    /// Parse the data of an unknown EDNS option in the format `\# <length> <hex>`.
+    fn rr_edns_unknown(&mut self, token: &Token, code: u16) -> ZoneResult<EDNSOption> {
+        self.parse("\\#", |token| {
+            if token.is("\\#") {
+                Ok(())
+            } else {
+                Err(ZoneErrorKind::Unexpected(token.text.to_owned()))
+            }
+        })?;
+        let length: u16 = self.parse("option length", integer)?;
+        let length = length as usize;
+        let data = if length == 0 {
+            Vec::new()
+        } else {
+            self.parse("option data", |token| Ok(hex::decode(token.text)?))?
+        };
+        if data.len() != length {
+            return Err(token.error(ZoneErrorKind::RDataLength(length, data.len())));
+        }
+        Ok(EDNSOption::Unknown { code, data })
+    }
+
+    /// Parse the OPT record in the format of the `Display` trait, which is
+    /// `<payload size> <extended RCODE> <version> <DNSSEC OK> <options>...`.
+    pub(crate) fn rr_opt(&mut self) -> ZoneResult<OPT> {
+        let requestor_payload_size = self.u16("requestor payload size")?;
+        let extend_rcode = self.u8("extended RCODE")?;
+        let version = self.u8("version")?;
+        let dnssec = self.parse("DNSSEC OK", |token| {
+            if token.is("true") {
+                Ok(true)
+            } else if token.is("false") {
+                Ok(false)
+            } else {
+                Err(ZoneErrorKind::Value("DNSSEC OK", token.text.to_owned()))
+            }
+        })?;
+        let mut edns_options = Vec::new();
+        while !self.is_finished() {
+            edns_options.push(self.rr_edns_option()?);
+        }
+        let opt = OPT {
+            requestor_payload_size,
+            extend_rcode,
+            version,
+            dnssec,
+            edns_options,
+        };
+        Ok(opt)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::rr::edns::{DAU, DHU, N3U};
+use crate::zone::scanner::Scanner;
+use crate::zone::ZoneResult;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    /// Parse the following tokens, which are integers.
+    fn rr_edns_integers(&mut self, expected: &'static str) -> ZoneResult<Vec<u8>> {
+        let mut values = Vec::new();
+        while let Some(token) = self.peek() {
+            if token.quoted || !token.text.bytes().all(|b| b.is_ascii_digit()) {
+                break;
+            }
+            values.push(self.u8(expected)?);
+        }
+        Ok(values)
+    }
+
+    pub(super) fn rr_edns_dau(&mut self) -> ZoneResult<DAU> {
+        Ok(DAU(self.rr_edns_integers("algorithm")?))
+    }
+
+    pub(super) fn rr_edns_dhu(&mut self) -> ZoneResult<DHU> {
+        Ok(DHU(self.rr_edns_integers("digest type")?))
+    }
+
+    pub(super) fn rr_edns_n3u(&mut self) -> ZoneResult<N3U> {
+        Ok(N3U(self.rr_edns_integers("hash algorithm")?))
+    }
+}
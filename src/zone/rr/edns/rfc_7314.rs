@@ -0,0 +1,13 @@
+use crate::zone::scanner::Scanner;
+use crate::zone::ZoneResult;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_edns_expire(&mut self) -> ZoneResult<Option<u32>> {
+        match self.peek() {
+            Some(token) if !token.quoted && token.text.bytes().all(|b| b.is_ascii_digit()) => {
+                Ok(Some(self.u32("expire")?))
+            }
+            _ => Ok(None),
+        }
+    }
+}
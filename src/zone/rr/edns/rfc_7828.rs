@@ -0,0 +1,15 @@
+use crate::rr::edns::TcpKeepalive;
+use crate::zone::scanner::Scanner;
+use crate::zone::ZoneResult;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_edns_tcp_keepalive(&mut self) -> ZoneResult<TcpKeepalive> {
+        let timeout = match self.peek() {
+            Some(token) if !token.quoted && token.text.bytes().all(|b| b.is_ascii_digit()) => {
+                Some(self.u16("timeout")?)
+            }
+            _ => None,
+        };
+        Ok(TcpKeepalive(timeout))
+    }
+}
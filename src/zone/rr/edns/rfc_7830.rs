@@ -0,0 +1,10 @@
+use crate::rr::edns::Padding;
+use crate::zone::scanner::Scanner;
+use crate::zone::ZoneResult;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_edns_padding(&mut self) -> ZoneResult<Padding> {
+        let padding = self.u16("padding length")?;
+        Ok(Padding(padding))
+    }
+}
@@ -0,0 +1,28 @@
+use crate::rr::edns::ECS;
+use crate::rr::Address;
+use crate::zone::scanner::Scanner;
+use crate::zone::{ZoneErrorKind, ZoneResult};
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_edns_ecs(&mut self) -> ZoneResult<ECS> {
+        let source_prefix_length = self.u8("source prefix length")?;
+        let scope_prefix_length = self.u8("scope prefix length")?;
+        let token = self.next("address")?;
+        let address = if token.text.contains(':') {
+            token
+                .text
+                .parse()
+                .map(Address::Ipv6)
+                .map_err(|_| ZoneErrorKind::Ipv6Addr(token.text.to_owned()))
+        } else {
+            token
+                .text
+                .parse()
+                .map(Address::Ipv4)
+                .map_err(|_| ZoneErrorKind::Ipv4Addr(token.text.to_owned()))
+        }
+        .map_err(|e| token.error(e))?;
+        ECS::new(source_prefix_length, scope_prefix_length, address)
+            .map_err(|e| token.error(e.into()))
+    }
+}
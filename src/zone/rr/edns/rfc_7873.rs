@@ -0,0 +1,27 @@
+use crate::rr::edns::{Cookie, CLIENT_COOKIE_LENGTH};
+use crate::zone::scanner::Scanner;
+use crate::zone::{ZoneErrorKind, ZoneResult};
+use std::convert::TryInto;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    /// Parse the client cookie and the optional server cookie, which are hex strings.
+    ///
+    /// The server cookie is present if the next token is a hex string.
+    pub(super) fn rr_edns_cookie(&mut self) -> ZoneResult<Cookie> {
+        let token = self.next("client cookie")?;
+        let client_cookie = hex::decode(token.text).map_err(|e| token.error(e.into()))?;
+        let client_cookie: [u8; CLIENT_COOKIE_LENGTH] = client_cookie.try_into().map_err(|_| {
+            token.error(ZoneErrorKind::Value("client cookie", token.text.to_owned()))
+        })?;
+
+        let server_cookie = match self.peek() {
+            Some(token) if !token.quoted && token.text.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                self.next("server cookie")?;
+                let server_cookie = hex::decode(token.text).map_err(|e| token.error(e.into()))?;
+                Some(server_cookie)
+            }
+            _ => None,
+        };
+        Cookie::new(client_cookie, server_cookie).map_err(|e| token.error(e.into()))
+    }
+}
@@ -0,0 +1,9 @@
+use crate::zone::scanner::Scanner;
+use crate::zone::ZoneResult;
+use crate::DomainName;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_edns_chain(&mut self) -> ZoneResult<DomainName> {
+        self.domain_name("closest trust point")
+    }
+}
@@ -0,0 +1,31 @@
+use crate::rr::edns::{ExtendedDNSErrorCodes, ExtendedDNSErrorExtraText, ExtendedDNSErrors};
+use crate::zone::scanner::Scanner;
+use crate::zone::{ZoneErrorKind, ZoneResult};
+use std::convert::TryFrom;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    /// Parse the Extended DNS Errors option, the word `Extended` is already consumed.
+    ///
+    /// The extra text is not quoted, therefore all remaining tokens are joined by a space.
+    pub(super) fn rr_edns_extended_dns_errors(&mut self) -> ZoneResult<ExtendedDNSErrors> {
+        for expected in ["DNS", "Errors"].iter() {
+            let token = self.next(expected)?;
+            if !token.is(expected) {
+                return Err(token.error(ZoneErrorKind::Unexpected(token.text.to_owned())));
+            }
+        }
+        let info_code = self.mnemonic_enum::<u16, ExtendedDNSErrorCodes>("info code")?;
+        let mut texts = Vec::new();
+        while let Some(token) = self.peek() {
+            texts.push(token.unescape_string().map_err(|e| token.error(e))?);
+            self.next("extra text")?;
+        }
+        let extra_text = ExtendedDNSErrorExtraText::try_from(texts.join(" "))
+            .map_err(|e| self.error(e.into()))?;
+        let extended_dns_errors = ExtendedDNSErrors {
+            info_code,
+            extra_text,
+        };
+        Ok(extended_dns_errors)
+    }
+}
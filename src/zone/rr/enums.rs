@@ -0,0 +1,130 @@
+use super::super::scanner::{integer, Scanner};
+use super::super::{ZoneErrorKind, ZoneResult};
+use crate::rr::{Class, Type, Unknown, RR};
+use crate::DomainName;
+use std::convert::TryFrom;
+
+pub(crate) struct Header {
+    pub(crate) domain_name: DomainName,
+    pub(crate) ttl: u32,
+    pub(crate) class: Class,
+}
+
+impl Header {
+    /// Returns an error if the class is not IN, which is required by some types.
+    pub(super) fn check_class_in(&self, type_: Type) -> Result<(), ZoneErrorKind> {
+        match self.class {
+            Class::IN => Ok(()),
+            class => Err(ZoneErrorKind::TypeClass(type_, class)),
+        }
+    }
+}
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    /// Parse the RDATA in the [generic format] `\# <length> <hex>`.
+    ///
+    /// If the type is known then the RDATA is decoded as this type.
+    ///
+    /// [generic format]: https://tools.ietf.org/html/rfc3597#section-5
+    fn rr_generic(&mut self, type_code: u16, header: Header) -> ZoneResult<RR> {
+        let token = self.next("\\#")?;
+        let length: u16 = self.parse("RDATA length", integer)?;
+        let length = length as usize;
+        let rdata = if length == 0 {
+            Vec::new()
+        } else {
+            self.hex("RDATA")?
+        };
+        if rdata.len() != length {
+            return Err(token.error(ZoneErrorKind::RDataLength(length, rdata.len())));
+        }
+        let class = header.class;
+        let unknown = Unknown {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class,
+            type_code,
+            rdata,
+        };
+        unknown
+            .decode_rdata(class)
+            .map_err(|e| token.error(e.into()))
+    }
+
+    /// Parse the RDATA of the type and check that all tokens are used.
+    pub(crate) fn rr_rdata(&mut self, type_code: u16, header: Header) -> ZoneResult<RR> {
+        let rr = self.rr_rdata_typed(type_code, header)?;
+        self.finished()?;
+        Ok(rr)
+    }
+
+    fn rr_rdata_typed(&mut self, type_code: u16, header: Header) -> ZoneResult<RR> {
+        if matches!(self.peek(), Some(token) if token.is("\\#")) {
+            return self.rr_generic(type_code, header);
+        }
+
+        let type_ = match Type::try_from(type_code) {
+            Ok(type_) => type_,
+            Err(_) => return Err(self.error(ZoneErrorKind::Missing("\\#"))),
+        };
+        let rr = match type_ {
+            Type::A => RR::A(self.rr_a(header)?),
+            Type::NS => RR::NS(self.rr_ns(header)?),
+            Type::MD => RR::MD(self.rr_md(header)?),
+            Type::MF => RR::MF(self.rr_mf(header)?),
+            Type::CNAME => RR::CNAME(self.rr_cname(header)?),
+            Type::SOA => RR::SOA(self.rr_soa(header)?),
+            Type::MB => RR::MB(self.rr_mb(header)?),
+            Type::MG => RR::MG(self.rr_mg(header)?),
+            Type::MR => RR::MR(self.rr_mr(header)?),
+            Type::NULL => RR::NULL(self.rr_null(header)?),
+            Type::WKS => RR::WKS(self.rr_wks(header)?),
+            Type::PTR => RR::PTR(self.rr_ptr(header)?),
+            Type::HINFO => RR::HINFO(self.rr_hinfo(header)?),
+            Type::MINFO => RR::MINFO(self.rr_minfo(header)?),
+            Type::MX => RR::MX(self.rr_mx(header)?),
+            Type::TXT => RR::TXT(self.rr_txt(header)?),
+            Type::RP => RR::RP(self.rr_rp(header)?),
+            Type::AFSDB => RR::AFSDB(self.rr_afsdb(header)?),
+            Type::X25 => RR::X25(self.rr_x25(header)?),
+            Type::ISDN => RR::ISDN(self.rr_isdn(header)?),
+            Type::RT => RR::RT(self.rr_rt(header)?),
+            Type::NSAP => RR::NSAP(self.rr_nsap(header)?),
+            Type::GPOS => RR::GPOS(self.rr_gpos(header)?),
+            Type::LOC => RR::LOC(self.rr_loc(header)?),
+            Type::PX => RR::PX(self.rr_px(header)?),
+            Type::KX => RR::KX(self.rr_kx(header)?),
+            Type::SRV => RR::SRV(self.rr_srv(header)?),
+            Type::AAAA => RR::AAAA(self.rr_aaaa(header)?),
+            Type::SSHFP => RR::SSHFP(self.rr_sshfp(header)?),
+            Type::DNAME => RR::DNAME(self.rr_dname(header)?),
+            Type::APL => RR::APL(self.rr_apl(header)?),
+            Type::NID => RR::NID(self.rr_nid(header)?),
+            Type::L32 => RR::L32(self.rr_l32(header)?),
+            Type::L64 => RR::L64(self.rr_l64(header)?),
+            Type::LP => RR::LP(self.rr_lp(header)?),
+            Type::EUI48 => RR::EUI48(self.rr_eui48(header)?),
+            Type::EUI64 => RR::EUI64(self.rr_eui64(header)?),
+            Type::URI => RR::URI(self.rr_uri(header)?),
+            Type::EID => RR::EID(self.rr_eid(header)?),
+            Type::NIMLOC => RR::NIMLOC(self.rr_nimloc(header)?),
+            Type::DNSKEY => RR::DNSKEY(self.rr_dnskey(header)?),
+            Type::DS => RR::DS(self.rr_ds(header)?),
+            Type::CAA => RR::CAA(self.rr_caa(header)?),
+            Type::SVCB => RR::SVCB(self.rr_service_binding(header, false)?),
+            Type::HTTPS => RR::HTTPS(self.rr_service_binding(header, true)?),
+            Type::RRSIG => RR::RRSIG(self.rr_rrsig(header)?),
+            Type::NSEC => RR::NSEC(self.rr_nsec(header)?),
+            Type::NSEC3 => RR::NSEC3(self.rr_nsec3(header)?),
+            Type::NSEC3PARAM => RR::NSEC3PARAM(self.rr_nsec3param(header)?),
+            Type::TLSA => RR::TLSA(self.rr_tlsa(header)?),
+            Type::SMIMEA => RR::SMIMEA(self.rr_smimea(header)?),
+            Type::NAPTR => RR::NAPTR(self.rr_naptr(header)?),
+            Type::CDS => RR::CDS(self.rr_ds(header)?),
+            Type::CDNSKEY => RR::CDNSKEY(self.rr_dnskey(header)?),
+            Type::TSIG => RR::TSIG(self.rr_tsig(header)?),
+            type_ => return Err(self.error(ZoneErrorKind::NotYetImplemented(type_))),
+        };
+        Ok(rr)
+    }
+}
@@ -0,0 +1,95 @@
+macro_rules! impl_parse_rr_domain_name {
+    ($i:ident, $n:ident, $m:ident) => {
+        pub(super) fn $m(
+            &mut self,
+            header: super::enums::Header,
+        ) -> crate::zone::ZoneResult<crate::rr::$i> {
+            let $n = self.domain_name(stringify!($n))?;
+            let v = crate::rr::$i {
+                domain_name: header.domain_name,
+                ttl: header.ttl,
+                class: header.class,
+                $n,
+            };
+            Ok(v)
+        }
+    };
+}
+
+macro_rules! impl_parse_rr_u16_domain_name {
+    ($i:ident, $p:ident, $n:ident, $m:ident) => {
+        pub(super) fn $m(
+            &mut self,
+            header: super::enums::Header,
+        ) -> crate::zone::ZoneResult<crate::rr::$i> {
+            let $p = self.u16(stringify!($p))?;
+            let $n = self.domain_name(stringify!($n))?;
+            let v = crate::rr::$i {
+                domain_name: header.domain_name,
+                ttl: header.ttl,
+                class: header.class,
+                $p,
+                $n,
+            };
+            Ok(v)
+        }
+    };
+}
+
+macro_rules! impl_parse_rr_u16_u64 {
+    ($i:ident, $p:ident, $n:ident, $m:ident) => {
+        pub(super) fn $m(
+            &mut self,
+            header: super::enums::Header,
+        ) -> crate::zone::ZoneResult<crate::rr::$i> {
+            let $p = self.u16(stringify!($p))?;
+            let $n = self.parse(stringify!($n), super::subtypes::locator_64)?;
+            let v = crate::rr::$i {
+                domain_name: header.domain_name,
+                ttl: header.ttl,
+                class: header.class,
+                $p,
+                $n,
+            };
+            Ok(v)
+        }
+    };
+}
+
+macro_rules! impl_parse_rr_domain_name_domain_name {
+    ($i:ident, $n:ident, $o:ident, $m:ident) => {
+        pub(super) fn $m(
+            &mut self,
+            header: super::enums::Header,
+        ) -> crate::zone::ZoneResult<crate::rr::$i> {
+            let $n = self.domain_name(stringify!($n))?;
+            let $o = self.domain_name(stringify!($o))?;
+            let v = crate::rr::$i {
+                domain_name: header.domain_name,
+                ttl: header.ttl,
+                class: header.class,
+                $n,
+                $o,
+            };
+            Ok(v)
+        }
+    };
+}
+
+macro_rules! impl_parse_rr_vec {
+    ($i:ident, $n:ident, $m:ident) => {
+        pub(super) fn $m(
+            &mut self,
+            header: super::enums::Header,
+        ) -> crate::zone::ZoneResult<crate::rr::$i> {
+            let $n = self.hex(stringify!($n))?;
+            let v = crate::rr::$i {
+                domain_name: header.domain_name,
+                ttl: header.ttl,
+                class: header.class,
+                $n,
+            };
+            Ok(v)
+        }
+    };
+}
@@ -0,0 +1,130 @@
+use super::super::scanner::{integer, Scanner};
+use super::super::{ZoneErrorKind, ZoneResult};
+use super::Header;
+use crate::rr::{Type, A, HINFO, SOA, TXT, WKS};
+use std::convert::TryInto;
+
+/// Parse the protocol of a WKS record, which is either a number or `tcp` or `udp`.
+fn wks_protocol(token: &super::super::lexer::Token) -> Result<u8, ZoneErrorKind> {
+    if token.is("tcp") {
+        Ok(6)
+    } else if token.is("udp") {
+        Ok(17)
+    } else {
+        integer(token)
+    }
+}
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_a(&mut self, header: Header) -> ZoneResult<A> {
+        header.check_class_in(Type::A).map_err(|e| self.error(e))?;
+        let ipv4_addr = self.ipv4_addr("IPv4 address")?;
+        let a = A {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            ipv4_addr,
+        };
+        Ok(a)
+    }
+
+    impl_parse_rr_domain_name!(NS, ns_d_name, rr_ns);
+
+    impl_parse_rr_domain_name!(MD, mad_name, rr_md);
+
+    impl_parse_rr_domain_name!(MF, mad_name, rr_mf);
+
+    impl_parse_rr_domain_name!(CNAME, c_name, rr_cname);
+
+    pub(super) fn rr_soa(&mut self, header: Header) -> ZoneResult<SOA> {
+        let m_name = self.domain_name("m_name")?;
+        let r_name = self.domain_name("r_name")?;
+        let serial = self.u32("serial")?;
+        let refresh = self.ttl("refresh")?;
+        let retry = self.ttl("retry")?;
+        let expire = self.ttl("expire")?;
+        let min_ttl = self.ttl("min_ttl")?;
+
+        let soa = SOA {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            m_name,
+            r_name,
+            serial,
+            refresh,
+            retry,
+            expire,
+            min_ttl,
+        };
+
+        Ok(soa)
+    }
+
+    impl_parse_rr_domain_name!(MB, mad_name, rr_mb);
+
+    impl_parse_rr_domain_name!(MG, mgm_name, rr_mg);
+
+    impl_parse_rr_domain_name!(MR, new_name, rr_mr);
+
+    impl_parse_rr_vec!(NULL, data, rr_null);
+
+    pub(super) fn rr_wks(&mut self, header: Header) -> ZoneResult<WKS> {
+        header
+            .check_class_in(Type::WKS)
+            .map_err(|e| self.error(e))?;
+        let ipv4_addr = self.ipv4_addr("IPv4 address")?;
+        let protocol = self.parse("protocol", wks_protocol)?;
+        let mut bit_map = Vec::new();
+        while !self.is_finished() {
+            let port: u16 = self.parse("port", integer)?;
+            let index = (port / 8) as usize;
+            if bit_map.len() <= index {
+                bit_map.resize(index + 1, 0);
+            }
+            bit_map[index] |= 0b1000_0000 >> (port % 8);
+        }
+        let wks = WKS {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            ipv4_addr,
+            protocol,
+            bit_map,
+        };
+        Ok(wks)
+    }
+
+    impl_parse_rr_domain_name!(PTR, ptr_d_name, rr_ptr);
+
+    pub(super) fn rr_hinfo(&mut self, header: Header) -> ZoneResult<HINFO> {
+        let cpu = self.string("cpu")?;
+        let os = self.string("os")?;
+        let hinfo = HINFO {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            cpu,
+            os,
+        };
+        Ok(hinfo)
+    }
+
+    impl_parse_rr_domain_name_domain_name!(MINFO, r_mail_bx, e_mail_bx, rr_minfo);
+
+    impl_parse_rr_u16_domain_name!(MX, preference, exchange, rr_mx);
+
+    pub(super) fn rr_txt(&mut self, header: Header) -> ZoneResult<TXT> {
+        let mut strings = vec![self.string("string")?];
+        while !self.is_finished() {
+            strings.push(self.string("string")?);
+        }
+        // The vector contains at least one string.
+        let strings = strings.try_into().unwrap();
+        let txt = TXT {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            strings,
+        };
+        Ok(txt)
+    }
+}
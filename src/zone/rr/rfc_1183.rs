@@ -0,0 +1,62 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::{ISDNAddress, PSDNAddress, AFSDB, ISDN, SA, X25};
+use crate::zone::scanner::Scanner;
+use std::convert::TryFrom;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    impl_parse_rr_domain_name_domain_name!(RP, mbox_dname, txt_dname, rr_rp);
+
+    pub(super) fn rr_afsdb(&mut self, header: Header) -> ZoneResult<AFSDB> {
+        let subtype = self.integer_enum::<u16, _>("subtype")?;
+        let hostname = self.domain_name("hostname")?;
+        let afs_db = AFSDB {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            subtype,
+            hostname,
+        };
+        Ok(afs_db)
+    }
+
+    pub(super) fn rr_x25(&mut self, header: Header) -> ZoneResult<X25> {
+        let psdn_address = self.parse("PSDN address", |token| {
+            Ok(PSDNAddress::try_from(token.unescape_string()?)?)
+        })?;
+
+        let x25 = X25 {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            psdn_address,
+        };
+        Ok(x25)
+    }
+
+    pub(super) fn rr_isdn(&mut self, header: Header) -> ZoneResult<ISDN> {
+        let isdn_address = self.parse("ISDN address", |token| {
+            Ok(ISDNAddress::try_from(token.unescape_string()?)?)
+        })?;
+
+        let sa = if self.is_finished() {
+            None
+        } else {
+            let sa = self.parse("subaddress", |token| {
+                Ok(SA::try_from(token.unescape_string()?)?)
+            })?;
+            Some(sa)
+        };
+
+        let isdn = ISDN {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            isdn_address,
+            sa,
+        };
+        Ok(isdn)
+    }
+
+    impl_parse_rr_u16_domain_name!(RT, preference, intermediate_host, rr_rt);
+}
@@ -0,0 +1,29 @@
+use super::super::{ZoneErrorKind, ZoneResult};
+use super::Header;
+use crate::rr::NSAP;
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    /// Parse the NSAP address, which is a hex string with an optional `0x` prefix and optional
+    /// dots, see [RFC 1706 Section 5].
+    ///
+    /// [RFC 1706 Section 5]: https://tools.ietf.org/html/rfc1706#section-5
+    pub(super) fn rr_nsap(&mut self, header: Header) -> ZoneResult<NSAP> {
+        let data = self.parse("data", |token| {
+            let text = token.text;
+            let text = match text.get(..2) {
+                Some(prefix) if prefix.eq_ignore_ascii_case("0x") => &text[2..],
+                _ => text,
+            };
+            let text: String = text.chars().filter(|c| *c != '.').collect();
+            hex::decode(text).map_err(ZoneErrorKind::from)
+        })?;
+        let nsap = NSAP {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            data,
+        };
+        Ok(nsap)
+    }
+}
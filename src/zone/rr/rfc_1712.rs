@@ -0,0 +1,21 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::GPOS;
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_gpos(&mut self, header: Header) -> ZoneResult<GPOS> {
+        let longitude = self.string("longitude")?;
+        let latitude = self.string("latitude")?;
+        let altitude = self.string("altitude")?;
+        let gpos = GPOS {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            longitude,
+            latitude,
+            altitude,
+        };
+        Ok(gpos)
+    }
+}
@@ -0,0 +1,152 @@
+use super::super::lexer::Token;
+use super::super::scanner::{integer, Scanner};
+use super::super::{ZoneErrorKind, ZoneResult};
+use super::Header;
+use crate::rr::LOC;
+
+/// The value of the equator and the prime meridian, see [RFC 1876 Section 2].
+///
+/// [RFC 1876 Section 2]: https://tools.ietf.org/html/rfc1876#section-2
+const LOC_EQUATOR: u32 = 1 << 31;
+/// The altitude is relative to 100,000m below the WGS 84 reference spheroid.
+const LOC_ALTITUDE_BASE: i64 = 10_000_000;
+
+/// Parse a decimal number with at most `fraction_digits` digits after the point and return it
+/// multiplied by `10^fraction_digits`.
+fn decimal(text: &str, fraction_digits: usize) -> Option<i64> {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (integer, fraction) = match text.find('.') {
+        Some(index) => (&text[..index], &text[index + 1..]),
+        None => (text, ""),
+    };
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if integer.is_empty() || fraction.len() > fraction_digits {
+        return None;
+    }
+    if !all_digits(integer) || !all_digits(fraction) {
+        return None;
+    }
+    let mut value: i64 = integer.parse().ok()?;
+    for index in 0..fraction_digits {
+        let digit = fraction.as_bytes().get(index).map_or(0, |b| b - b'0');
+        value = value.checked_mul(10)?.checked_add(digit as i64)?;
+    }
+    if negative {
+        Some(-value)
+    } else {
+        Some(value)
+    }
+}
+
+/// Parse a length in meters with an optional `m` suffix and return it in centimeters.
+fn meters(token: &Token, name: &'static str) -> Result<i64, ZoneErrorKind> {
+    let text = token.text.strip_suffix('m').unwrap_or(token.text);
+    decimal(text, 2).ok_or_else(|| ZoneErrorKind::Value(name, token.text.to_owned()))
+}
+
+/// Encode the size or the precision in centimeters as mantissa and exponent.
+fn precision(token: &Token, name: &'static str) -> Result<u8, ZoneErrorKind> {
+    let mut value = meters(token, name)?;
+    if !(0..=9_000_000_000).contains(&value) {
+        return Err(ZoneErrorKind::Value(name, token.text.to_owned()));
+    }
+    let mut exponent = 0;
+    while value >= 10 {
+        value /= 10;
+        exponent += 1;
+    }
+    Ok(((value as u8) << 4) | exponent)
+}
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    /// Parse a latitude or a longitude and return it in thousandths of a second of arc relative to
+    /// the equator or the prime meridian.
+    fn rr_loc_coordinate(
+        &mut self,
+        name: &'static str,
+        max_degrees: u32,
+        hemispheres: (&str, &str),
+    ) -> ZoneResult<u32> {
+        let first = self.next(name)?;
+        let value_error = || first.error(ZoneErrorKind::Value(name, first.text.to_owned()));
+        let degrees: u32 = integer(first).map_err(|_| value_error())?;
+        let mut value = degrees as u64 * 3_600_000;
+        let mut token = self.next(name)?;
+        if !token.is(hemispheres.0) && !token.is(hemispheres.1) {
+            let minutes: u32 = integer(token).map_err(|_| value_error())?;
+            if minutes > 59 {
+                return Err(value_error());
+            }
+            value += minutes as u64 * 60_000;
+            token = self.next(name)?;
+            if !token.is(hemispheres.0) && !token.is(hemispheres.1) {
+                let seconds = decimal(token.text, 3).ok_or_else(value_error)?;
+                if !(0..60_000).contains(&seconds) {
+                    return Err(value_error());
+                }
+                value += seconds as u64;
+                token = self.next(name)?;
+            }
+        }
+        if value > max_degrees as u64 * 3_600_000 {
+            return Err(value_error());
+        }
+        let value = value as u32;
+        if token.is(hemispheres.0) {
+            Ok(LOC_EQUATOR + value)
+        } else if token.is(hemispheres.1) {
+            Ok(LOC_EQUATOR - value)
+        } else {
+            Err(token.error(ZoneErrorKind::Value(name, token.text.to_owned())))
+        }
+    }
+
+    /// Parse the [presentation format] of the LOC record.
+    ///
+    /// [presentation format]: https://tools.ietf.org/html/rfc1876#section-3
+    pub(super) fn rr_loc(&mut self, header: Header) -> ZoneResult<LOC> {
+        let latitube = self.rr_loc_coordinate("latitude", 90, ("N", "S"))?;
+        let longitube = self.rr_loc_coordinate("longitude", 180, ("E", "W"))?;
+        let altitube = self.parse("altitude", |token| {
+            let altitude = meters(token, "altitude")? + LOC_ALTITUDE_BASE;
+            if !(0..=u32::MAX as i64).contains(&altitude) {
+                return Err(ZoneErrorKind::Value("altitude", token.text.to_owned()));
+            }
+            Ok(altitude as u32)
+        })?;
+        // The default values are 1m, 10000m and 10m.
+        let mut size = 0x12;
+        let mut horiz_pre = 0x16;
+        let mut vert_pre = 0x13;
+        if !self.is_finished() {
+            size = self.parse("size", |token| precision(token, "size"))?;
+        }
+        if !self.is_finished() {
+            horiz_pre = self.parse("horizontal precision", |token| {
+                precision(token, "horizontal precision")
+            })?;
+        }
+        if !self.is_finished() {
+            vert_pre = self.parse("vertical precision", |token| {
+                precision(token, "vertical precision")
+            })?;
+        }
+
+        let loc = LOC {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            version: 0,
+            size,
+            horiz_pre,
+            vert_pre,
+            latitube,
+            longitube,
+            altitube,
+        };
+        Ok(loc)
+    }
+}
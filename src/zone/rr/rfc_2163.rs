@@ -0,0 +1,21 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::PX;
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_px(&mut self, header: Header) -> ZoneResult<PX> {
+        let preference = self.u16("preference")?;
+        let map822 = self.domain_name("map822")?;
+        let mapx400 = self.domain_name("mapx400")?;
+        let px = PX {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            preference,
+            map822,
+            mapx400,
+        };
+        Ok(px)
+    }
+}
@@ -0,0 +1,5 @@
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    impl_parse_rr_u16_domain_name!(KX, preference, exchanger, rr_kx);
+}
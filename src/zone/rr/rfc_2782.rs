@@ -0,0 +1,23 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::SRV;
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_srv(&mut self, header: Header) -> ZoneResult<SRV> {
+        let priority = self.u16("priority")?;
+        let weight = self.u16("weight")?;
+        let port = self.u16("port")?;
+        let target = self.domain_name("target")?;
+        let srv = SRV {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            priority,
+            weight,
+            port,
+            target,
+        };
+        Ok(srv)
+    }
+}
@@ -0,0 +1,47 @@
+use super::super::lexer::Token;
+use super::super::{ZoneErrorKind, ZoneResult};
+use super::Header;
+use crate::rr::{APItem, Address, Type, APL};
+use crate::zone::scanner::Scanner;
+
+/// Parse an item in the format `[!]afi:address/prefix`, see [RFC 3123 Section 5].
+///
+/// [RFC 3123 Section 5]: https://tools.ietf.org/html/rfc3123#section-5
+fn apitem(token: &Token) -> Result<APItem, ZoneErrorKind> {
+    let apitem_error = || ZoneErrorKind::Value("APL item", token.text.to_owned());
+    let (negation, text) = match token.text.strip_prefix('!') {
+        Some(text) => (true, text),
+        None => (false, token.text),
+    };
+    let colon = text.find(':').ok_or_else(apitem_error)?;
+    let slash = text.rfind('/').ok_or_else(apitem_error)?;
+    if slash < colon {
+        return Err(apitem_error());
+    }
+    let address_text = &text[colon + 1..slash];
+    let address = match &text[..colon] {
+        "1" => Address::Ipv4(address_text.parse().map_err(|_| apitem_error())?),
+        "2" => Address::Ipv6(address_text.parse().map_err(|_| apitem_error())?),
+        _ => return Err(apitem_error()),
+    };
+    let prefix = text[slash + 1..].parse().map_err(|_| apitem_error())?;
+    Ok(APItem::new(prefix, negation, address)?)
+}
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_apl(&mut self, header: Header) -> ZoneResult<APL> {
+        header
+            .check_class_in(Type::APL)
+            .map_err(|e| self.error(e))?;
+        let mut apitems = Vec::new();
+        while !self.is_finished() {
+            apitems.push(self.parse("APL item", apitem)?);
+        }
+        let apl = APL {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            apitems,
+        };
+        Ok(apl)
+    }
+}
@@ -0,0 +1,27 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::NAPTR;
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_naptr(&mut self, header: Header) -> ZoneResult<NAPTR> {
+        let order = self.u16("order")?;
+        let preference = self.u16("preference")?;
+        let flags = self.string("flags")?;
+        let services = self.string("services")?;
+        let regexp = self.string("regexp")?;
+        let replacement = self.domain_name("replacement")?;
+        let naptr = NAPTR {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            order,
+            preference,
+            flags,
+            services,
+            regexp,
+            replacement,
+        };
+        Ok(naptr)
+    }
+}
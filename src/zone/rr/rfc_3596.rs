@@ -0,0 +1,19 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::{Type, AAAA};
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_aaaa(&mut self, header: Header) -> ZoneResult<AAAA> {
+        header
+            .check_class_in(Type::AAAA)
+            .map_err(|e| self.error(e))?;
+        let ipv6_addr = self.ipv6_addr("IPv6 address")?;
+        let aaaa = AAAA {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            ipv6_addr,
+        };
+        Ok(aaaa)
+    }
+}
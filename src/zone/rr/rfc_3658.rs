@@ -0,0 +1,21 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::SSHFP;
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_sshfp(&mut self, header: Header) -> ZoneResult<SSHFP> {
+        let algorithm = self.mnemonic_enum::<u8, _>("algorithm")?;
+        let type_ = self.mnemonic_enum::<u8, _>("fingerprint type")?;
+        let fp = self.hex("fingerprint")?;
+        let sshfp = SSHFP {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            algorithm,
+            type_,
+            fp,
+        };
+        Ok(sshfp)
+    }
+}
@@ -0,0 +1,138 @@
+use super::super::lexer::Token;
+use super::super::{ZoneErrorKind, ZoneResult};
+use super::Header;
+use crate::rr::{
+    DNSKEY, DNSKEY_ZERO_MASK, DS, NSEC, RRSIG, SECURE_ENTRY_POINT_FLAG, ZONE_KEY_FLAG,
+};
+use crate::zone::scanner::{integer, type_code, Scanner};
+
+/// Convert a civil date into the days since 1970-01-01.
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Parse a timestamp in the `YYYYMMDDHHmmSS` format or as seconds since 1970-01-01, see
+/// [RFC 4034 Section 3.2].
+///
+/// [RFC 4034 Section 3.2]: https://tools.ietf.org/html/rfc4034#section-3.2
+fn timestamp(token: &Token) -> Result<u32, ZoneErrorKind> {
+    let timestamp_error = || ZoneErrorKind::Timestamp(token.text.to_owned());
+    let text = token.text;
+    if token.quoted || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(timestamp_error());
+    }
+    if text.len() != 14 {
+        return text.parse().map_err(|_| timestamp_error());
+    }
+
+    let field = |range: std::ops::Range<usize>| text[range].parse::<u64>().unwrap();
+    let (year, month, day) = (field(0..4), field(4..6), field(6..8));
+    let (hour, minute, second) = (field(8..10), field(10..12), field(12..14));
+    if year < 1970 || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(timestamp_error());
+    }
+    if hour > 23 || minute > 59 || second > 59 {
+        return Err(timestamp_error());
+    }
+    let days = days_from_civil(year, month, day);
+    let seconds = days * 86400 + hour * 3600 + minute * 60 + second;
+    // The timestamp is a serial number, which wraps around, see RFC 4034 Section 3.1.5.
+    Ok(seconds as u32)
+}
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_dnskey(&mut self, header: Header) -> ZoneResult<DNSKEY> {
+        let flags = self.parse("flags", |token| {
+            let flags: u16 = integer(token)?;
+            if flags & DNSKEY_ZERO_MASK != 0 {
+                return Err(ZoneErrorKind::Value("flags", token.text.to_owned()));
+            }
+            Ok(flags)
+        })?;
+        let zone_key_flag = (flags & ZONE_KEY_FLAG) == ZONE_KEY_FLAG;
+        let secure_entry_point_flag = (flags & SECURE_ENTRY_POINT_FLAG) == SECURE_ENTRY_POINT_FLAG;
+        self.parse("protocol", |token| {
+            let protocol: u8 = integer(token)?;
+            if protocol == 3 {
+                Ok(())
+            } else {
+                Err(ZoneErrorKind::Value("protocol", token.text.to_owned()))
+            }
+        })?;
+        let algorithm_type = self.integer_enum::<u8, _>("algorithm")?;
+        let public_key = self.base64("public key")?;
+        let dnskey = DNSKEY {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            zone_key_flag,
+            secure_entry_point_flag,
+            algorithm_type,
+            public_key,
+        };
+        Ok(dnskey)
+    }
+
+    pub(super) fn rr_ds(&mut self, header: Header) -> ZoneResult<DS> {
+        let key_tag = self.u16("key tag")?;
+        let algorithm_type = self.integer_enum::<u8, _>("algorithm")?;
+        let digest_type = self.integer_enum::<u8, _>("digest type")?;
+        let digest = self.hex("digest")?;
+        let ds = DS {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            key_tag,
+            algorithm_type,
+            digest_type,
+            digest,
+        };
+        Ok(ds)
+    }
+
+    pub(super) fn rr_rrsig(&mut self, header: Header) -> ZoneResult<RRSIG> {
+        let type_covered = self.parse("type covered", type_code)?;
+        let algorithm_type = self.u8("algorithm")?;
+        let labels = self.u8("labels")?;
+        let original_ttl = self.u32("original TTL")?;
+        let signature_expiration = self.parse("signature expiration", timestamp)?;
+        let signature_inception = self.parse("signature inception", timestamp)?;
+        let key_tag = self.u16("key tag")?;
+        let signer_name = self.domain_name("signer name")?;
+        let signature = self.base64("signature")?;
+        let rrsig = RRSIG {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            type_covered,
+            algorithm_type,
+            labels,
+            original_ttl,
+            signature_expiration,
+            signature_inception,
+            key_tag,
+            signer_name,
+            signature,
+        };
+        Ok(rrsig)
+    }
+
+    pub(super) fn rr_nsec(&mut self, header: Header) -> ZoneResult<NSEC> {
+        let next_domain_name = self.domain_name("next domain name")?;
+        let types = self.types()?;
+        let nsec = NSEC {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            next_domain_name,
+            types,
+        };
+        Ok(nsec)
+    }
+}
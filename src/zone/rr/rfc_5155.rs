@@ -0,0 +1,58 @@
+use super::super::lexer::Token;
+use super::super::{ZoneErrorKind, ZoneResult};
+use super::Header;
+use crate::rr::{NSEC3, NSEC3PARAM};
+use crate::zone::scanner::Scanner;
+use data_encoding::BASE32HEX_NOPAD;
+
+/// Parse the salt, which is a hex string or `-` if the salt is empty.
+fn salt(token: &Token) -> Result<Vec<u8>, ZoneErrorKind> {
+    if token.is("-") {
+        Ok(Vec::new())
+    } else {
+        Ok(hex::decode(token.text)?)
+    }
+}
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_nsec3(&mut self, header: Header) -> ZoneResult<NSEC3> {
+        let hash_algorithm = self.u8("hash algorithm")?;
+        let flags = self.u8("flags")?;
+        let iterations = self.u16("iterations")?;
+        let salt = self.parse("salt", salt)?;
+        let next_hashed_owner_name = self.parse("next hashed owner name", |token| {
+            let text = token.text.to_ascii_uppercase();
+            Ok(BASE32HEX_NOPAD.decode(text.as_bytes())?)
+        })?;
+        let types = self.types()?;
+        let nsec3 = NSEC3 {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            hash_algorithm,
+            flags,
+            iterations,
+            salt,
+            next_hashed_owner_name,
+            types,
+        };
+        Ok(nsec3)
+    }
+
+    pub(super) fn rr_nsec3param(&mut self, header: Header) -> ZoneResult<NSEC3PARAM> {
+        let hash_algorithm = self.u8("hash algorithm")?;
+        let flags = self.u8("flags")?;
+        let iterations = self.u16("iterations")?;
+        let salt = self.parse("salt", salt)?;
+        let nsec3param = NSEC3PARAM {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            hash_algorithm,
+            flags,
+            iterations,
+            salt,
+        };
+        Ok(nsec3param)
+    }
+}
@@ -0,0 +1,5 @@
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    impl_parse_rr_domain_name!(DNAME, target, rr_dname);
+}
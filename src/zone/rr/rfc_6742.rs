@@ -0,0 +1,25 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::L32;
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    impl_parse_rr_u16_u64!(NID, preference, node_id, rr_nid);
+
+    pub(super) fn rr_l32(&mut self, header: Header) -> ZoneResult<L32> {
+        let preference = self.u16("preference")?;
+        let locator_32 = self.ipv4_addr("locator")?.into();
+        let l_32 = L32 {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            preference,
+            locator_32,
+        };
+        Ok(l_32)
+    }
+
+    impl_parse_rr_u16_u64!(L64, preference, locator_64, rr_l64);
+
+    impl_parse_rr_u16_domain_name!(LP, preference, fqdn, rr_lp);
+}
@@ -0,0 +1,31 @@
+use super::super::ZoneResult;
+use super::subtypes::eui;
+use super::Header;
+use crate::rr::{EUI48, EUI64};
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_eui48(&mut self, header: Header) -> ZoneResult<EUI48> {
+        let mut eui_48: [u8; 6] = [0; 6];
+        eui_48.copy_from_slice(&self.parse("EUI-48 address", |token| eui(token, 6))?);
+        let eui_48 = EUI48 {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            eui_48,
+        };
+        Ok(eui_48)
+    }
+
+    pub(super) fn rr_eui64(&mut self, header: Header) -> ZoneResult<EUI64> {
+        let mut eui_64: [u8; 8] = [0; 8];
+        eui_64.copy_from_slice(&self.parse("EUI-64 address", |token| eui(token, 8))?);
+        let eui_64 = EUI64 {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            eui_64,
+        };
+        Ok(eui_64)
+    }
+}
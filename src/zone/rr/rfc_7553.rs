@@ -0,0 +1,21 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::URI;
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_uri(&mut self, header: Header) -> ZoneResult<URI> {
+        let priority = self.u16("priority")?;
+        let weight = self.u16("weight")?;
+        let uri = self.parse("target", |token| token.unescape_string())?;
+        let uri = URI {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            priority,
+            weight,
+            uri,
+        };
+        Ok(uri)
+    }
+}
@@ -0,0 +1,23 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::SMIMEA;
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn rr_smimea(&mut self, header: Header) -> ZoneResult<SMIMEA> {
+        let certificate_usage = self.integer_enum::<u8, _>("certificate usage")?;
+        let selector = self.integer_enum::<u8, _>("selector")?;
+        let matching_type = self.integer_enum::<u8, _>("matching type")?;
+        let certificate_association_data = self.hex("certificate association data")?;
+        let smimea = SMIMEA {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            certificate_usage,
+            selector,
+            matching_type,
+            certificate_association_data,
+        };
+        Ok(smimea)
+    }
+}
@@ -0,0 +1,26 @@
+use super::super::ZoneResult;
+use super::Header;
+use crate::rr::{Tag, CAA};
+use crate::zone::scanner::Scanner;
+use std::convert::TryFrom;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    /// Parse the [presentation format] of the CAA record, the value is a quoted or not quoted
+    /// string.
+    ///
+    /// [presentation format]: https://tools.ietf.org/html/rfc8659#section-4.1.1
+    pub(super) fn rr_caa(&mut self, header: Header) -> ZoneResult<CAA> {
+        let flags = self.u8("flags")?;
+        let tag = self.parse("tag", |token| Ok(Tag::try_from(token.unescape_string()?)?))?;
+        let value = self.parse("value", |token| token.unescape())?;
+        let caa = CAA {
+            domain_name: header.domain_name,
+            ttl: header.ttl,
+            class: header.class,
+            flags,
+            tag,
+            value,
+        };
+        Ok(caa)
+    }
+}
@@ -0,0 +1,49 @@
+use super::super::{ZoneErrorKind, ZoneResult};
+use super::Header;
+use crate::rr::{Class, Type, TSIG};
+use crate::zone::scanner::Scanner;
+use base64::{engine::general_purpose::STANDARD as Base64Standard, Engine};
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    /// Parse the length and the base64 encoded data, which is omitted if the length is zero.
+    fn rr_tsig_vec(&mut self, name: &'static str) -> ZoneResult<Vec<u8>> {
+        let length = self.u16(name)? as usize;
+        if length == 0 {
+            return Ok(Vec::new());
+        }
+        self.parse(name, |token| {
+            let data = Base64Standard.decode(token.text)?;
+            if data.len() != length {
+                return Err(ZoneErrorKind::RDataLength(length, data.len()));
+            }
+            Ok(data)
+        })
+    }
+
+    pub(super) fn rr_tsig(&mut self, header: Header) -> ZoneResult<TSIG> {
+        if header.class != Class::ANY {
+            return Err(self.error(ZoneErrorKind::TypeClass(Type::TSIG, header.class)));
+        }
+        if header.ttl != 0 {
+            return Err(self.error(ZoneErrorKind::Value("TTL", header.ttl.to_string())));
+        }
+        let algorithm_name = self.domain_name("algorithm name")?;
+        let time_signed = self.u64("time signed")?;
+        let fudge = self.u16("fudge")?;
+        let mac = self.rr_tsig_vec("MAC")?;
+        let original_id = self.u16("original ID")?;
+        let error = self.u16("error")?;
+        let other_data = self.rr_tsig_vec("other data")?;
+        let tsig = TSIG {
+            domain_name: header.domain_name,
+            algorithm_name,
+            time_signed,
+            fudge,
+            mac,
+            original_id,
+            error,
+            other_data,
+        };
+        Ok(tsig)
+    }
+}
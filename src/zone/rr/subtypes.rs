@@ -0,0 +1,45 @@
+use super::super::lexer::Token;
+use super::super::ZoneErrorKind;
+
+/// Parse groups of hex digits, which are separated by the separator and have a fixed length.
+fn hex_groups(
+    token: &Token,
+    name: &'static str,
+    separator: char,
+    groups: usize,
+    group_length: usize,
+) -> Result<Vec<u8>, ZoneErrorKind> {
+    let value_error = || ZoneErrorKind::Value(name, token.text.to_owned());
+    let mut bytes = Vec::with_capacity(groups * group_length / 2);
+    let mut count = 0;
+    for group in token.text.split(separator) {
+        if group.len() != group_length {
+            return Err(value_error());
+        }
+        bytes.extend(hex::decode(group).map_err(|_| value_error())?);
+        count += 1;
+    }
+    if count != groups {
+        return Err(value_error());
+    }
+    Ok(bytes)
+}
+
+/// Parse a 64-bit locator or node identifier in the format `xxxx:xxxx:xxxx:xxxx`, see
+/// [RFC 6742 Section 2.1].
+///
+/// [RFC 6742 Section 2.1]: https://tools.ietf.org/html/rfc6742#section-2.1
+pub(super) fn locator_64(token: &Token) -> Result<u64, ZoneErrorKind> {
+    let bytes = hex_groups(token, "locator", ':', 4, 4)?;
+    let mut buffer = [0; 8];
+    buffer.copy_from_slice(&bytes);
+    Ok(u64::from_be_bytes(buffer))
+}
+
+/// Parse an EUI-48 or an EUI-64 address in the format `xx-xx-xx-xx-xx-xx`, see
+/// [RFC 7043 Section 3.2].
+///
+/// [RFC 7043 Section 3.2]: https://tools.ietf.org/html/rfc7043#section-3.2
+pub(super) fn eui(token: &Token, groups: usize) -> Result<Vec<u8>, ZoneErrorKind> {
+    hex_groups(token, "EUI address", '-', groups, 2)
+}
@@ -0,0 +1,7 @@
+use crate::zone::scanner::Scanner;
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    impl_parse_rr_vec!(EID, data, rr_eid);
+
+    impl_parse_rr_vec!(NIMLOC, data, rr_nimloc);
+}
@@ -0,0 +1,334 @@
+use super::lexer::{split_labels, Token};
+use super::{ZoneError, ZoneErrorKind, ZoneResult};
+use crate::rr::{Class, Type, TypeBitMap};
+use crate::{DomainName, Label};
+use base64::{engine::general_purpose::STANDARD as Base64Standard, Engine};
+use std::convert::TryFrom;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// The maximum length of a [character string].
+///
+/// [character string]: https://tools.ietf.org/html/rfc1035#section-3.3
+const CHARACTER_STRING_MAX_LENGTH: usize = 255;
+
+/// Parse an integer, which is not quoted.
+pub(super) fn integer<T: FromStr>(token: &Token) -> Result<T, ZoneErrorKind> {
+    if token.quoted {
+        return Err(ZoneErrorKind::Integer(token.text.to_owned()));
+    }
+    token
+        .text
+        .parse()
+        .map_err(|_| ZoneErrorKind::Integer(token.text.to_owned()))
+}
+
+/// Parse a TTL, which is either a number of seconds or a sequence of numbers with the units
+/// `w`, `d`, `h`, `m` and `s`, for example `1h30m`.
+pub(super) fn ttl(token: &Token) -> Result<u32, ZoneErrorKind> {
+    let ttl_error = || ZoneErrorKind::TTLValue(token.text.to_owned());
+    if token.quoted || token.text.is_empty() {
+        return Err(ttl_error());
+    }
+    if let Ok(ttl) = token.text.parse() {
+        return Ok(ttl);
+    }
+
+    let mut ttl: u32 = 0;
+    let mut value: Option<u32> = None;
+    for c in token.text.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            let new_value = value
+                .unwrap_or(0)
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(digit))
+                .ok_or_else(ttl_error)?;
+            value = Some(new_value);
+        } else {
+            let unit = match c.to_ascii_lowercase() {
+                'w' => 604_800,
+                'd' => 86_400,
+                'h' => 3_600,
+                'm' => 60,
+                's' => 1,
+                _ => return Err(ttl_error()),
+            };
+            let seconds = value
+                .take()
+                .ok_or_else(ttl_error)?
+                .checked_mul(unit)
+                .ok_or_else(ttl_error)?;
+            ttl = ttl.checked_add(seconds).ok_or_else(ttl_error)?;
+        }
+    }
+    if value.is_some() {
+        return Err(ttl_error());
+    }
+    Ok(ttl)
+}
+
+/// Parse a type mnemonic or the generic `TYPE` notation of [RFC 3597 Section 5] and return the
+/// type code.
+///
+/// [RFC 3597 Section 5]: https://tools.ietf.org/html/rfc3597#section-5
+pub(super) fn type_code(token: &Token) -> Result<u16, ZoneErrorKind> {
+    let type_error = || ZoneErrorKind::Type(token.text.to_owned());
+    if token.quoted {
+        return Err(type_error());
+    }
+    if let Ok(type_) = Type::from_str(token.text) {
+        return Ok(type_ as u16);
+    }
+    match token.text.get(..4) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("TYPE") => {
+            token.text[4..].parse().map_err(|_| type_error())
+        }
+        _ => Err(type_error()),
+    }
+}
+
+/// Parse a class mnemonic or the generic `CLASS` notation of [RFC 3597 Section 5].
+///
+/// Returns `None` if the token is not a class.
+///
+/// [RFC 3597 Section 5]: https://tools.ietf.org/html/rfc3597#section-5
+pub(super) fn class(token: &Token) -> Option<Result<Class, ZoneErrorKind>> {
+    if token.quoted {
+        return None;
+    }
+    if let Ok(class) = Class::from_str(token.text) {
+        return Some(Ok(class));
+    }
+    match token.text.get(..5) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("CLASS") => {
+            let class = integer::<u16>(&Token {
+                text: &token.text[5..],
+                ..token.clone()
+            })
+            .ok()
+            .and_then(|class| Class::try_from(class).ok())
+            .ok_or_else(|| ZoneErrorKind::Class(token.text.to_owned()));
+            Some(class)
+        }
+        _ => None,
+    }
+}
+
+/// Parse the text of a domain name, which can be relative to the origin.
+pub(super) fn domain_name(
+    token: &Token,
+    origin: Option<&DomainName>,
+) -> Result<DomainName, ZoneErrorKind> {
+    if token.is("@") {
+        return origin
+            .cloned()
+            .ok_or_else(|| ZoneErrorKind::Origin(token.text.to_owned()));
+    }
+
+    let (labels, absolute) = split_labels(token.text)?;
+    let mut domain_name = DomainName::default();
+    for label in labels {
+        let label = Label::try_from(label)?;
+        domain_name.append_label(label)?;
+    }
+    if !absolute {
+        let origin = origin.ok_or_else(|| ZoneErrorKind::Origin(token.text.to_owned()))?;
+        for label in origin.0.iter() {
+            domain_name.append_label(label.clone())?;
+        }
+    }
+    Ok(domain_name)
+}
+
+/// Iterate over the tokens of an entry.
+pub(super) struct Scanner<'a, 'b> {
+    tokens: &'b [Token<'a>],
+    index: usize,
+    pub(super) origin: Option<&'b DomainName>,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl<'a, 'b> Scanner<'a, 'b> {
+    pub(super) fn new(
+        tokens: &'b [Token<'a>],
+        origin: Option<&'b DomainName>,
+        end_line: usize,
+        end_column: usize,
+    ) -> Scanner<'a, 'b> {
+        Scanner {
+            tokens,
+            index: 0,
+            origin,
+            end_line,
+            end_column,
+        }
+    }
+
+    #[inline]
+    pub(super) fn peek(&self) -> Option<&'b Token<'a>> {
+        self.tokens.get(self.index)
+    }
+
+    #[inline]
+    pub(super) fn is_finished(&self) -> bool {
+        self.index >= self.tokens.len()
+    }
+
+    pub(super) fn finished(&self) -> ZoneResult<()> {
+        match self.peek() {
+            Some(token) => Err(token.error(ZoneErrorKind::Unexpected(token.text.to_owned()))),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error at the current token or at the end of the entry.
+    pub(super) fn error(&self, kind: ZoneErrorKind) -> ZoneError {
+        match self.peek() {
+            Some(token) => token.error(kind),
+            None => ZoneError {
+                file: None,
+                line: self.end_line,
+                column: self.end_column,
+                kind,
+            },
+        }
+    }
+
+    pub(super) fn next(&mut self, expected: &'static str) -> ZoneResult<&'b Token<'a>> {
+        match self.tokens.get(self.index) {
+            Some(token) => {
+                self.index += 1;
+                Ok(token)
+            }
+            None => Err(self.error(ZoneErrorKind::Missing(expected))),
+        }
+    }
+
+    /// Parse the next token with the function and add the position of the token to the error.
+    pub(super) fn parse<T, F>(&mut self, expected: &'static str, f: F) -> ZoneResult<T>
+    where
+        F: FnOnce(&Token<'a>) -> Result<T, ZoneErrorKind>,
+    {
+        let token = self.next(expected)?;
+        f(token).map_err(|kind| token.error(kind))
+    }
+
+    pub(super) fn u8(&mut self, expected: &'static str) -> ZoneResult<u8> {
+        self.parse(expected, integer)
+    }
+
+    pub(super) fn u16(&mut self, expected: &'static str) -> ZoneResult<u16> {
+        self.parse(expected, integer)
+    }
+
+    pub(super) fn u32(&mut self, expected: &'static str) -> ZoneResult<u32> {
+        self.parse(expected, integer)
+    }
+
+    pub(super) fn u64(&mut self, expected: &'static str) -> ZoneResult<u64> {
+        self.parse(expected, integer)
+    }
+
+    /// Parse an integer and convert it into an enum, which has a variant with this value.
+    pub(super) fn integer_enum<I, E>(&mut self, expected: &'static str) -> ZoneResult<E>
+    where
+        I: FromStr,
+        E: TryFrom<I>,
+    {
+        self.parse(expected, |token| {
+            let value: I = integer(token)?;
+            E::try_from(value).map_err(|_| ZoneErrorKind::Value(expected, token.text.to_owned()))
+        })
+    }
+
+    /// Parse the mnemonic of an enum or an integer, which is converted into the enum.
+    pub(super) fn mnemonic_enum<I, E>(&mut self, expected: &'static str) -> ZoneResult<E>
+    where
+        I: FromStr,
+        E: TryFrom<I> + FromStr,
+    {
+        if let Some(Ok(value)) = self.peek().map(|token| E::from_str(token.text)) {
+            self.index += 1;
+            return Ok(value);
+        }
+        self.integer_enum::<I, E>(expected)
+    }
+
+    pub(super) fn ttl(&mut self, expected: &'static str) -> ZoneResult<u32> {
+        self.parse(expected, ttl)
+    }
+
+    pub(super) fn domain_name(&mut self, expected: &'static str) -> ZoneResult<DomainName> {
+        let origin = self.origin;
+        self.parse(expected, |token| domain_name(token, origin))
+    }
+
+    pub(super) fn ipv4_addr(&mut self, expected: &'static str) -> ZoneResult<Ipv4Addr> {
+        self.parse(expected, |token| {
+            token
+                .text
+                .parse()
+                .map_err(|_| ZoneErrorKind::Ipv4Addr(token.text.to_owned()))
+        })
+    }
+
+    pub(super) fn ipv6_addr(&mut self, expected: &'static str) -> ZoneResult<Ipv6Addr> {
+        self.parse(expected, |token| {
+            token
+                .text
+                .parse()
+                .map_err(|_| ZoneErrorKind::Ipv6Addr(token.text.to_owned()))
+        })
+    }
+
+    /// Parse a [character string], which is quoted or not quoted.
+    ///
+    /// [character string]: https://tools.ietf.org/html/rfc1035#section-5.1
+    pub(super) fn string(&mut self, expected: &'static str) -> ZoneResult<String> {
+        self.parse(expected, |token| {
+            let string = token.unescape_string()?;
+            if string.len() > CHARACTER_STRING_MAX_LENGTH {
+                return Err(ZoneErrorKind::CharacterString(string.len()));
+            }
+            Ok(string)
+        })
+    }
+
+    /// Concatenate the text of the remaining tokens, which must contain at least one token.
+    ///
+    /// This is used for hex and base64 strings, which can contain whitespaces.
+    fn remaining_text(&mut self, expected: &'static str) -> ZoneResult<(&'b Token<'a>, String)> {
+        let token = self.next(expected)?;
+        let mut text = token.text.to_owned();
+        while let Some(token) = self.peek() {
+            text.push_str(token.text);
+            self.index += 1;
+        }
+        Ok((token, text))
+    }
+
+    pub(super) fn hex(&mut self, expected: &'static str) -> ZoneResult<Vec<u8>> {
+        let (token, text) = self.remaining_text(expected)?;
+        hex::decode(text).map_err(|e| token.error(e.into()))
+    }
+
+    pub(super) fn base64(&mut self, expected: &'static str) -> ZoneResult<Vec<u8>> {
+        let (token, text) = self.remaining_text(expected)?;
+        Base64Standard
+            .decode(text)
+            .map_err(|e| token.error(e.into()))
+    }
+
+    /// Parse the remaining tokens as type codes, which can be in the generic `TYPE` notation,
+    /// see [RFC 4034 Section 4.2].
+    ///
+    /// [RFC 4034 Section 4.2]: https://tools.ietf.org/html/rfc4034#section-4.2
+    pub(super) fn types(&mut self) -> ZoneResult<TypeBitMap> {
+        let mut types = TypeBitMap::new();
+        while !self.is_finished() {
+            types.insert_code(self.parse("type", type_code)?);
+        }
+        Ok(types)
+    }
+}
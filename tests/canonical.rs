@@ -0,0 +1,119 @@
+use dns_message_parser::rr::{sort_rrset, DNSKEY, RR};
+use dns_message_parser::DomainName;
+
+fn rr(s: &str) -> RR {
+    s.parse().unwrap()
+}
+
+fn dnskey(s: &str) -> DNSKEY {
+    match rr(s) {
+        RR::DNSKEY(dnskey) => dnskey,
+        rr => panic!("Expected DNSKEY: {}", rr),
+    }
+}
+
+#[test]
+fn key_tag() {
+    // The examples of RFC 4034 Section 5.4 and RFC 6605 Section 6.
+    let dskey_example_com = dnskey(
+        "dskey.example.com. 86400 IN DNSKEY 256 3 5 \
+        AQOeiiR0GOMYkDshWoSKz9XzfwJr1AYtsmx3TGkJaNXVbfi/2pHm822aJ5iI9BMzNXxeYCmZDRD99WYwYqUSdjMm\
+        mAphXdvxegXd/M5+X7OrzKBaMbCVdFLUUh6DhweJBjEVv5f2wwjM9XzcnOf+EPbtG9DMBmADjFDc2w/rljwvFw==",
+    );
+    let example_net_p256 = dnskey(
+        "example.net. 3600 IN DNSKEY 257 3 13 \
+        GojIhhXUN/u4v54ZQqGSnyhWJwaubCvTmeexv7bR6edbkrSqQpF64cYbcB7wNcP+e+MAnLr+Wi9xMWyQLc8NAA==",
+    );
+    let example_net_p384 = dnskey(
+        "example.net. 3600 IN DNSKEY 257 3 14 \
+        xKYaNhWdGOfJ+nPrL8/arkwf2EY3MDJ+SErKivBVSum1w/egsXvSADtNJhyem5RCOpgQ6K8X1DRSEkrbYQ+OB+v8\
+        /uX45NBwY8rp65F6Glur8I/mlVNgF6W/qTI37m40",
+    );
+    assert_eq!(dskey_example_com.key_tag(), 60485);
+    assert_eq!(example_net_p256.key_tag(), 55648);
+    assert_eq!(example_net_p384.key_tag(), 10771);
+}
+
+#[test]
+fn key_tag_rsa_md5() {
+    let dnskey = dnskey("example.com. 86400 IN DNSKEY 256 3 1 AQIDBAUG");
+    assert_eq!(dnskey.key_tag(), 0x0405);
+}
+
+#[test]
+fn encode_canonical_domain_name() {
+    let domain_name: DomainName = "Example.ORG".parse().unwrap();
+    let bytes = domain_name.encode_canonical().unwrap();
+    assert_eq!(bytes.as_ref(), b"\x07example\x03org\x00");
+}
+
+#[test]
+fn encode_canonical_lowercase() {
+    let rr_1 = rr("Example.ORG. 3600 IN MX 10 MAIL.Example.ORG.");
+    let rr_2 = rr("example.org. 3600 IN MX 10 mail.example.org.");
+    let bytes = rr_1.encode_canonical().unwrap();
+    assert_eq!(bytes, rr_2.encode_canonical().unwrap());
+    assert_eq!(
+        bytes.as_ref(),
+        &b"\x07example\x03org\x00\x00\x0f\x00\x01\x00\x00\x0e\x10\x00\x14\x00\x0a\
+        \x04mail\x07example\x03org\x00"[..]
+    );
+}
+
+#[test]
+fn encode_canonical_preserve_case() {
+    let rr = rr("Example.ORG. 3600 IN LP 10 L64.Example.ORG.");
+    let bytes = rr.encode_canonical().unwrap();
+    assert_eq!(
+        bytes.as_ref(),
+        &b"\x07example\x03org\x00\x00\x6b\x00\x01\x00\x00\x0e\x10\x00\x13\x00\x0a\
+        \x03L64\x07Example\x03ORG\x00"[..]
+    );
+}
+
+#[test]
+fn encode_canonical_nsec_preserve_case() {
+    let rr = rr("Example.ORG. 3600 IN NSEC Host.Example.ORG. A");
+    let bytes = rr.encode_canonical().unwrap();
+    assert_eq!(
+        bytes.as_ref(),
+        &b"\x07example\x03org\x00\x00\x2f\x00\x01\x00\x00\x0e\x10\x00\x15\x04Host\
+        \x07Example\x03ORG\x00\x00\x01\x40"[..]
+    );
+}
+
+#[test]
+fn sort_rrset_canonical() {
+    let mut rrset = vec![
+        rr("example.org. 3600 IN A 10.0.0.2"),
+        rr("example.org. 3600 IN A 10.0.0.10"),
+        rr("example.org. 300 IN A 10.0.0.2"),
+        rr("example.org. 3600 IN A 10.0.0.1"),
+    ];
+    sort_rrset(&mut rrset).unwrap();
+    assert_eq!(
+        rrset,
+        vec![
+            rr("example.org. 3600 IN A 10.0.0.1"),
+            rr("example.org. 3600 IN A 10.0.0.2"),
+            rr("example.org. 3600 IN A 10.0.0.10"),
+        ]
+    );
+}
+
+#[test]
+fn sort_rrset_canonical_domain_names() {
+    let mut rrset = vec![
+        rr("example.org. 3600 IN NS b.example.org."),
+        rr("example.org. 3600 IN NS A.example.org."),
+        rr("example.org. 3600 IN NS a.example.org."),
+    ];
+    sort_rrset(&mut rrset).unwrap();
+    assert_eq!(
+        rrset,
+        vec![
+            rr("example.org. 3600 IN NS A.example.org."),
+            rr("example.org. 3600 IN NS b.example.org."),
+        ]
+    );
+}
@@ -1,8 +1,8 @@
 use dns_message_parser::rr::{
-    AFSDBSubtype, Class, ISDNAddress, PSDNAddress, SSHFPAlgorithm, SSHFPType, ServiceBinding, A,
-    AAAA, AFSDB, APL, CNAME, DNAME, EID, EUI48, EUI64, GPOS, HINFO, ISDN, KX, L32, L64, LP, MB, MD,
-    MF, MG, MINFO, MR, MX, NID, NIMLOC, NS, OPT, PTR, PX, RP, RR, RT, SA, SOA, SRV, SSHFP, TXT,
-    URI, X25,
+    AFSDBSubtype, Class, ISDNAddress, PSDNAddress, SSHFPAlgorithm, SSHFPType, ServiceBinding,
+    Unknown, A, AAAA, AFSDB, APL, CNAME, DNAME, EID, EUI48, EUI64, GPOS, HINFO, ISDN, KX, L32, L64,
+    LP, MB, MD, MF, MG, MINFO, MR, MX, NID, NIMLOC, NS, OPT, PTR, PX, RP, RR, RT, SA, SOA, SRV,
+    SSHFP, TXT, URI, X25,
 };
 use std::{
     collections::BTreeSet,
@@ -633,3 +633,22 @@ fn rr_https() {
     // then
     assert_eq!(class, Some(Class::IN));
 }
+
+#[test]
+fn unknown() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let rr = RR::Unknown(Unknown {
+        domain_name,
+        ttl: 100,
+        class: Class::CH,
+        type_code: 0xff00,
+        rdata: vec![0x01, 0x02],
+    });
+
+    // when
+    let class = rr.get_class();
+
+    // then
+    assert_eq!(class, Some(Class::CH));
+}
@@ -47,7 +47,7 @@ fn example_net_edns_ede_forged() {
                 ra: true,
                 ad: false,
                 cd: false,
-                rcode: dns_message_parser::RCode::NXDomain
+                rcode: dns_message_parser::RCode::NXDomain,
             },
             questions: vec![dns_message_parser::question::Question {
                 domain_name: dns_message_parser::DomainName::from_str("example.net").unwrap(),
@@ -89,3 +89,382 @@ fn example_net_edns_ede_forged() {
         }
     );
 }
+
+#[test]
+fn opt_nsid_example_org_response() {
+    let msg = b"\x46\x55\x85\x80\x00\x01\x00\x01\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x0a\x00\
+    \x00\x0a\x00\x00\x29\x04\xd0\x00\x00\x00\x00\x00\x0d\x00\x03\x00\x09\x67\x70\x64\x6e\x73\x2d\
+    \x61\x6d\x73";
+    let dns = decode_msg(&msg[..]);
+    assert_eq!(
+        dns.additionals,
+        vec![dns_message_parser::rr::RR::OPT(
+            dns_message_parser::rr::OPT {
+                requestor_payload_size: 1232,
+                extend_rcode: 0,
+                version: 0,
+                dnssec: false,
+                edns_options: vec![dns_message_parser::rr::edns::EDNSOption::NSID(
+                    dns_message_parser::rr::edns::NSID(b"gpdns-ams".to_vec())
+                )]
+            }
+        )]
+    );
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn opt_tcp_keepalive_example_org_response() {
+    let msg = b"\x46\x57\x85\x80\x00\x01\x00\x01\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\
+    \x0a\x00\x0a\x00\x00\x00\x29\x04\xd0\x00\x00\x00\x00\x00\x06\x00\x0b\x00\x02\x00\x96";
+    let dns = decode_msg(&msg[..]);
+    assert_eq!(
+        dns.additionals,
+        vec![dns_message_parser::rr::RR::OPT(
+            dns_message_parser::rr::OPT {
+                requestor_payload_size: 1232,
+                extend_rcode: 0,
+                version: 0,
+                dnssec: false,
+                edns_options: vec![dns_message_parser::rr::edns::EDNSOption::TcpKeepalive(
+                    dns_message_parser::rr::edns::TcpKeepalive(Some(150))
+                )]
+            }
+        )]
+    );
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn opt_dau_dhu_n3u_example_org_request() {
+    let msg = b"\x46\x58\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x80\x00\x00\x13\x00\
+    \x05\x00\x03\x08\x0d\x0f\x00\x06\x00\x03\x01\x02\x04\x00\x07\x00\x01\x01";
+    let dns = decode_msg(&msg[..]);
+    assert_eq!(
+        dns.additionals,
+        vec![dns_message_parser::rr::RR::OPT(
+            dns_message_parser::rr::OPT {
+                requestor_payload_size: 4096,
+                extend_rcode: 0,
+                version: 0,
+                dnssec: true,
+                edns_options: vec![
+                    dns_message_parser::rr::edns::EDNSOption::DAU(
+                        dns_message_parser::rr::edns::DAU(vec![
+                            dns_message_parser::rr::AlgorithmType::RsaSha256 as u8,
+                            dns_message_parser::rr::AlgorithmType::EcDsaP256 as u8,
+                            dns_message_parser::rr::AlgorithmType::Ed25519 as u8,
+                        ])
+                    ),
+                    dns_message_parser::rr::edns::EDNSOption::DHU(
+                        dns_message_parser::rr::edns::DHU(vec![
+                            dns_message_parser::rr::DigestType::Sha1 as u8,
+                            dns_message_parser::rr::DigestType::Sha256 as u8,
+                            dns_message_parser::rr::DigestType::Sha384 as u8,
+                        ])
+                    ),
+                    dns_message_parser::rr::edns::EDNSOption::N3U(
+                        dns_message_parser::rr::edns::N3U(vec![
+                            dns_message_parser::rr::NSEC3HashAlgorithm::Sha1 as u8
+                        ])
+                    ),
+                ]
+            }
+        )]
+    );
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn opt_dau_unknown_example_org_request() {
+    let msg = b"\x46\x58\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x80\x00\x00\x07\x00\
+    \x05\x00\x03\x08\x09\x17";
+    let dns = decode_msg(&msg[..]);
+    assert_eq!(
+        dns.additionals,
+        vec![dns_message_parser::rr::RR::OPT(
+            dns_message_parser::rr::OPT {
+                requestor_payload_size: 4096,
+                extend_rcode: 0,
+                version: 0,
+                dnssec: true,
+                edns_options: vec![dns_message_parser::rr::edns::EDNSOption::DAU(
+                    dns_message_parser::rr::edns::DAU(vec![8, 9, 23])
+                )]
+            }
+        )]
+    );
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn opt_expire_example_org_response() {
+    let msg = b"\x46\x59\x84\x00\x00\x01\x00\x01\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\xc0\x0c\x00\x06\x00\x01\x00\x00\x0e\x10\x00\x1e\
+    \xc0\x0c\x05\x65\x6d\x61\x69\x6c\xc0\x0c\x00\x00\x00\x02\x00\x09\x3a\x80\x00\x01\x51\
+    \x80\x00\x24\xea\x00\x00\x09\x3a\x80\x00\x00\x29\x04\xd0\x00\x00\x00\x00\x00\x08\x00\
+    \x09\x00\x04\x00\x24\x9f\x00";
+    let dns = decode_msg(&msg[..]);
+    assert_eq!(
+        dns.additionals,
+        vec![dns_message_parser::rr::RR::OPT(
+            dns_message_parser::rr::OPT {
+                requestor_payload_size: 1232,
+                extend_rcode: 0,
+                version: 0,
+                dnssec: false,
+                edns_options: vec![dns_message_parser::rr::edns::EDNSOption::Expire(Some(
+                    2400000
+                ))]
+            }
+        )]
+    );
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn opt_chain_example_org_request() {
+    let msg = b"\x46\x5a\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x80\x00\x00\x11\x00\
+    \x0d\x00\x0d\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00";
+    let dns = decode_msg(&msg[..]);
+    assert_eq!(
+        dns.additionals,
+        vec![dns_message_parser::rr::RR::OPT(
+            dns_message_parser::rr::OPT {
+                requestor_payload_size: 4096,
+                extend_rcode: 0,
+                version: 0,
+                dnssec: true,
+                edns_options: vec![dns_message_parser::rr::edns::EDNSOption::Chain(
+                    dns_message_parser::DomainName::from_str("example.org").unwrap()
+                )]
+            }
+        )]
+    );
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn opt_unknown_example_org_request() {
+    let msg = b"\x46\x54\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x00\x00\x00\x0c\xfd\xe9\x00\
+    \x04\xde\xad\xbe\xef\xfd\xea\x00\x00";
+    let dns = decode_msg(&msg[..]);
+    assert_eq!(
+        dns.additionals,
+        vec![dns_message_parser::rr::RR::OPT(
+            dns_message_parser::rr::OPT {
+                requestor_payload_size: 4096,
+                extend_rcode: 0,
+                version: 0,
+                dnssec: false,
+                edns_options: vec![
+                    dns_message_parser::rr::edns::EDNSOption::Unknown {
+                        code: 0xfde9,
+                        data: vec![0xde, 0xad, 0xbe, 0xef],
+                    },
+                    dns_message_parser::rr::edns::EDNSOption::Unknown {
+                        code: 0xfdea,
+                        data: Vec::new(),
+                    },
+                ]
+            }
+        )]
+    );
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn unknown_example_org_response() {
+    let msg = b"\x1b\x2c\x85\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07\x75\x6e\x6b\x6e\x6f\x77\x6e\
+    \x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\xff\x00\x01\xc0\x0c\xff\
+    \x00\x00\x01\x00\x00\x0e\x10\x00\x04\x01\x02\x03\x04";
+    let dns = decode_msg(&msg[..]);
+    assert_eq!(
+        dns.answers,
+        vec![dns_message_parser::rr::RR::Unknown(
+            dns_message_parser::rr::Unknown {
+                domain_name: dns_message_parser::DomainName::from_str("unknown.example.org")
+                    .unwrap(),
+                ttl: 3600,
+                class: dns_message_parser::rr::Class::IN,
+                type_code: 0xff00,
+                rdata: vec![0x01, 0x02, 0x03, 0x04],
+            }
+        )]
+    );
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn rrsig_unknown_type_covered_example_org_response() {
+    let msg = b"\x5a\x6b\x85\x80\x00\x01\x00\x02\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\
+    \x04\x0a\x00\x00\x0a\xc0\x0c\x00\x2e\x00\x01\x00\x00\x0e\x10\x00\x5f\xff\x00\x0d\
+    \x02\x00\x00\x0e\x10\x65\x7b\x7e\x00\x65\x53\xf1\x00\x30\x39\x07\x65\x78\x61\x6d\
+    \x70\x6c\x65\x03\x6f\x72\x67\x00\x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1a\x1b\
+    \x1c\x1d\x1e\x1f\x20\x21\x22\x23\x24\x25\x26\x27\x28\x29\x2a\x2b\x2c\x2d\x2e\x2f\
+    \x30\x31\x32\x33\x34\x35\x36\x37\x38\x39\x3a\x3b\x3c\x3d\x3e\x3f\x40\x41\x42\x43\
+    \x44\x45\x46\x47\x48\x49\x4a\x4b\x4c\x4d\x4e\x4f";
+    let dns = decode_msg(&msg[..]);
+    match &dns.answers[1] {
+        dns_message_parser::rr::RR::RRSIG(rrsig) => {
+            assert_eq!(rrsig.type_covered, 0xff00);
+            assert_eq!(rrsig.algorithm_type, 13);
+        }
+        rr => panic!("Expected RRSIG: {:?}", rr),
+    }
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn rrsig_unknown_algorithm_example_org_response() {
+    let msg = b"\x5a\x6b\x85\x80\x00\x01\x00\x02\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\
+    \x04\x0a\x00\x00\x0a\xc0\x0c\x00\x2e\x00\x01\x00\x00\x0e\x10\x00\x5f\x00\x01\xfa\
+    \x02\x00\x00\x0e\x10\x65\x7b\x7e\x00\x65\x53\xf1\x00\x30\x39\x07\x65\x78\x61\x6d\
+    \x70\x6c\x65\x03\x6f\x72\x67\x00\x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1a\x1b\
+    \x1c\x1d\x1e\x1f\x20\x21\x22\x23\x24\x25\x26\x27\x28\x29\x2a\x2b\x2c\x2d\x2e\x2f\
+    \x30\x31\x32\x33\x34\x35\x36\x37\x38\x39\x3a\x3b\x3c\x3d\x3e\x3f\x40\x41\x42\x43\
+    \x44\x45\x46\x47\x48\x49\x4a\x4b\x4c\x4d\x4e\x4f";
+    let dns = decode_msg(&msg[..]);
+    match &dns.answers[1] {
+        dns_message_parser::rr::RR::RRSIG(rrsig) => {
+            assert_eq!(rrsig.type_covered, 1);
+            assert_eq!(rrsig.algorithm_type, 250);
+        }
+        rr => panic!("Expected RRSIG: {:?}", rr),
+    }
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn nsec_example_org_response() {
+    let msg = b"\x1a\x2b\x81\x83\x00\x01\x00\x00\x00\x01\x00\x00\x01\x61\x07\x65\x78\x61\x6d\x70\
+    \x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0e\x00\x2f\x00\x01\x00\x00\x0e\
+    \x10\x00\x1e\x04\x68\x6f\x73\x74\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\
+    \x00\x00\x07\x62\x01\x00\x00\x00\x03\x80\x01\x01\x40";
+    let dns = decode_msg(&msg[..]);
+    let types = [
+        dns_message_parser::rr::Type::A,
+        dns_message_parser::rr::Type::NS,
+        dns_message_parser::rr::Type::SOA,
+        dns_message_parser::rr::Type::MX,
+        dns_message_parser::rr::Type::RRSIG,
+        dns_message_parser::rr::Type::NSEC,
+        dns_message_parser::rr::Type::DNSKEY,
+        dns_message_parser::rr::Type::CAA,
+    ];
+    assert_eq!(
+        dns.authorities,
+        vec![dns_message_parser::rr::RR::NSEC(
+            dns_message_parser::rr::NSEC {
+                domain_name: dns_message_parser::DomainName::from_str("example.org").unwrap(),
+                ttl: 3600,
+                class: dns_message_parser::rr::Class::IN,
+                next_domain_name: dns_message_parser::DomainName::from_str("host.example.org")
+                    .unwrap(),
+                types: types.iter().copied().collect(),
+            }
+        )]
+    );
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn nsec_private_type_example_org_response() {
+    let msg = b"\x1a\x2b\x81\x83\x00\x01\x00\x00\x00\x01\x00\x00\x01\x61\x07\x65\x78\x61\x6d\x70\
+    \x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0e\x00\x2f\x00\x01\x00\x00\x0e\x10\
+    \x00\x37\x04\x68\x6f\x73\x74\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\
+    \x01\x40\xff\x20\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02";
+    let dns = decode_msg(&msg[..]);
+    match &dns.authorities[..] {
+        [dns_message_parser::rr::RR::NSEC(nsec)] => {
+            assert!(nsec.types.contains(dns_message_parser::rr::Type::A));
+            assert!(!nsec.types.contains(dns_message_parser::rr::Type::MX));
+            assert!(nsec.types.contains_code(65534));
+            assert_eq!(
+                nsec.types.iter_types().collect::<Vec<_>>(),
+                vec![dns_message_parser::rr::Type::A]
+            );
+            assert_eq!(nsec.types.iter_codes().collect::<Vec<_>>(), vec![1, 65534]);
+        }
+        authorities => panic!("Expected one NSEC record: {:?}", authorities),
+    }
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn nsec3_unknown_hash_algorithm_example_response() {
+    let msg = b"\x3c\x4d\x85\x80\x00\x01\x00\x01\x00\x01\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x00\x00\x33\x00\x01\xc0\x0c\x00\x33\x00\x01\x00\x00\x0e\x10\x00\x09\x01\x00\x00\
+    \x0c\x04\xaa\xbb\xcc\xdd\x20\x30\x70\x39\x6d\x68\x61\x76\x65\x71\x76\x6d\x36\x74\
+    \x37\x76\x62\x6c\x35\x6c\x6f\x70\x32\x75\x33\x74\x32\x72\x70\x33\x74\x6f\x6d\x07\
+    \x65\x78\x61\x6d\x70\x6c\x65\x00\x00\x32\x00\x01\x00\x00\x0e\x10\x00\x27\x02\x01\
+    \x00\x0c\x04\xaa\xbb\xcc\xdd\x14\x17\x4e\xb2\x40\x9f\xe2\x8b\xcb\x48\x87\xa1\x83\
+    \x6f\x95\x7f\x0a\x84\x25\xe2\x7b\x00\x07\x22\x01\x00\x00\x00\x02\x90";
+    let dns = decode_msg(&msg[..]);
+    match &dns.authorities[..] {
+        [dns_message_parser::rr::RR::NSEC3(nsec3)] => {
+            assert_eq!(nsec3.hash_algorithm, 2);
+            assert_eq!(nsec3.flags, 1);
+            assert!(nsec3.is_opt_out());
+        }
+        authorities => panic!("Expected one NSEC3 record: {:?}", authorities),
+    }
+}
+
+#[test]
+fn nsec3_unknown_flags_example_response() {
+    let msg = b"\x3c\x4d\x85\x80\x00\x01\x00\x01\x00\x01\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x00\x00\x33\x00\x01\xc0\x0c\x00\x33\x00\x01\x00\x00\x0e\x10\x00\x09\x01\x00\x00\
+    \x0c\x04\xaa\xbb\xcc\xdd\x20\x30\x70\x39\x6d\x68\x61\x76\x65\x71\x76\x6d\x36\x74\
+    \x37\x76\x62\x6c\x35\x6c\x6f\x70\x32\x75\x33\x74\x32\x72\x70\x33\x74\x6f\x6d\x07\
+    \x65\x78\x61\x6d\x70\x6c\x65\x00\x00\x32\x00\x01\x00\x00\x0e\x10\x00\x27\x01\x03\
+    \x00\x0c\x04\xaa\xbb\xcc\xdd\x14\x17\x4e\xb2\x40\x9f\xe2\x8b\xcb\x48\x87\xa1\x83\
+    \x6f\x95\x7f\x0a\x84\x25\xe2\x7b\x00\x07\x22\x01\x00\x00\x00\x02\x90";
+    let dns = decode_msg(&msg[..]);
+    match &dns.authorities[..] {
+        [dns_message_parser::rr::RR::NSEC3(nsec3)] => {
+            assert_eq!(
+                nsec3.hash_algorithm,
+                dns_message_parser::rr::NSEC3HashAlgorithm::Sha1 as u8
+            );
+            assert_eq!(nsec3.flags, 3);
+            assert!(nsec3.is_opt_out());
+        }
+        authorities => panic!("Expected one NSEC3 record: {:?}", authorities),
+    }
+}
+
+#[test]
+fn cds_delete_example_org_response() {
+    let msg = b"\x6a\x7d\x81\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x3b\x00\x01\xc0\x0c\x00\x3b\x00\x01\x00\x00\x0e\x10\x00\
+    \x05\x00\x00\x00\x00\x00";
+    let dns = decode_msg(&msg[..]);
+    match &dns.answers[..] {
+        [dns_message_parser::rr::RR::CDS(cds)] => assert!(cds.is_delete()),
+        answers => panic!("Expected one CDS record: {:?}", answers),
+    }
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
+
+#[test]
+fn cdnskey_delete_example_org_response() {
+    let msg = b"\x6a\x7c\x81\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x3c\x00\x01\xc0\x0c\x00\x3c\x00\x01\x00\x00\x0e\x10\x00\
+    \x05\x00\x00\x03\x00\x00";
+    let dns = decode_msg(&msg[..]);
+    match &dns.answers[..] {
+        [dns_message_parser::rr::RR::CDNSKEY(cdnskey)] => assert!(cdnskey.is_delete()),
+        answers => panic!("Expected one CDNSKEY record: {:?}", answers),
+    }
+    assert_eq!(dns.encode().unwrap(), &msg[..]);
+}
@@ -1,4 +1,7 @@
 use bytes::Bytes;
+use dns_message_parser::question::Question;
+use dns_message_parser::rr::RR;
+use dns_message_parser::view::{DnsView, RRViewIter};
 use dns_message_parser::{Dns, Flags, Opcode, RCode};
 
 fn decode_msg(msg: &[u8]) -> Dns {
@@ -15,6 +18,46 @@ fn decode_encode_decode(msg: &[u8]) {
     let dns_2 = decode_msg(bytes.as_ref());
     // Check if is equal
     assert_eq!(dns_1, dns_2);
+    display_from_str(&dns_1);
+    view(msg, &dns_1);
+}
+
+fn view_section(rr_views: RRViewIter, rrs: &[RR]) {
+    assert_eq!(rr_views.len(), rrs.len());
+    for (rr_view, rr) in rr_views.zip(rrs.iter()) {
+        assert_eq!(&rr_view.to_rr().unwrap(), rr);
+    }
+}
+
+fn view(msg: &[u8], dns: &Dns) {
+    let bytes = Bytes::copy_from_slice(msg);
+    let dns_view = DnsView::decode(&bytes).unwrap();
+    assert_eq!(dns_view.id(), dns.id);
+    assert_eq!(dns_view.flags(), &dns.flags);
+    assert_eq!(dns_view.questions().len(), dns.questions.len());
+    for (question_view, question) in dns_view.questions().zip(dns.questions.iter()) {
+        assert!(question_view.domain_name() == question.domain_name);
+        assert_eq!(&question_view.to_question().unwrap(), question);
+    }
+    view_section(dns_view.answers(), &dns.answers);
+    view_section(dns_view.authorities(), &dns.authorities);
+    view_section(dns_view.additionals(), &dns.additionals);
+}
+
+fn display_from_str(dns: &Dns) {
+    for question in dns.questions.iter() {
+        let question_parsed: Question = question.to_string().parse().unwrap();
+        assert_eq!(question, &question_parsed);
+    }
+    let rrs = dns
+        .answers
+        .iter()
+        .chain(dns.authorities.iter())
+        .chain(dns.additionals.iter());
+    for rr in rrs {
+        let rr_parsed: RR = rr.to_string().parse().unwrap();
+        assert_eq!(rr, &rr_parsed);
+    }
 }
 
 #[test]
@@ -649,6 +692,89 @@ fn opt_cookie_example_org_response() {
     decode_encode_decode(&msg[..]);
 }
 
+#[test]
+fn opt_nsid_example_org_request() {
+    let msg = b"\x46\x55\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x00\x00\x00\x04\x00\x03\x00\
+    \x00";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn opt_nsid_example_org_response() {
+    let msg = b"\x46\x55\x85\x80\x00\x01\x00\x01\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x0a\x00\
+    \x00\x0a\x00\x00\x29\x04\xd0\x00\x00\x00\x00\x00\x0d\x00\x03\x00\x09\x67\x70\x64\x6e\x73\x2d\
+    \x61\x6d\x73";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn opt_tcp_keepalive_example_org_request() {
+    let msg = b"\x46\x57\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x00\x00\x00\x04\x00\
+    \x0b\x00\x00";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn opt_tcp_keepalive_example_org_response() {
+    let msg = b"\x46\x57\x85\x80\x00\x01\x00\x01\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\
+    \x0a\x00\x0a\x00\x00\x00\x29\x04\xd0\x00\x00\x00\x00\x00\x06\x00\x0b\x00\x02\x00\x96";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn opt_dau_dhu_n3u_example_org_request() {
+    let msg = b"\x46\x58\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x80\x00\x00\x13\x00\
+    \x05\x00\x03\x08\x0d\x0f\x00\x06\x00\x03\x01\x02\x04\x00\x07\x00\x01\x01";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn opt_dau_unknown_example_org_request() {
+    let msg = b"\x46\x58\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x80\x00\x00\x07\x00\
+    \x05\x00\x03\x08\x09\x17";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn opt_expire_example_org_request() {
+    let msg = b"\x46\x59\x00\x00\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\x00\x00\x29\x10\x00\x00\x00\x00\x00\x00\x04\x00\
+    \x09\x00\x00";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn opt_expire_example_org_response() {
+    let msg = b"\x46\x59\x84\x00\x00\x01\x00\x01\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\xc0\x0c\x00\x06\x00\x01\x00\x00\x0e\x10\x00\x1e\
+    \xc0\x0c\x05\x65\x6d\x61\x69\x6c\xc0\x0c\x00\x00\x00\x02\x00\x09\x3a\x80\x00\x01\x51\
+    \x80\x00\x24\xea\x00\x00\x09\x3a\x80\x00\x00\x29\x04\xd0\x00\x00\x00\x00\x00\x08\x00\
+    \x09\x00\x04\x00\x24\x9f\x00";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn opt_chain_example_org_request() {
+    let msg = b"\x46\x5a\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x80\x00\x00\x11\x00\
+    \x0d\x00\x0d\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn opt_unknown_example_org_request() {
+    let msg = b"\x46\x54\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x00\x00\x00\x0c\xfd\xe9\x00\
+    \x04\xde\xad\xbe\xef\xfd\xea\x00\x00";
+    decode_encode_decode(&msg[..]);
+}
+
 #[test]
 fn opt_dnssec_example_org_request() {
     let msg = b"\x46\x53\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
@@ -768,3 +894,211 @@ fn example_net_edns_ede_forged() {
     \xea\x00\x00\x09\x3a\x80\x00\x00\x29\x04\xd0\x00\x00\x00\x00\x00\x06\x00\x0f\x00\x02\x00\x12";
     decode_encode_decode(&msg[..]);
 }
+
+#[test]
+fn unknown_example_org_request() {
+    let msg = b"\x1b\x2c\x01\x20\x00\x01\x00\x00\x00\x00\x00\x00\x07\x75\x6e\x6b\x6e\x6f\x77\x6e\
+    \x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\xff\x00\x01";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn unknown_example_org_response() {
+    let msg = b"\x1b\x2c\x85\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07\x75\x6e\x6b\x6e\x6f\x77\x6e\
+    \x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\xff\x00\x01\xc0\x0c\xff\
+    \x00\x00\x01\x00\x00\x0e\x10\x00\x04\x01\x02\x03\x04";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn unknown_hip_example_org_response() {
+    let msg = b"\x3c\x4d\x85\x80\x00\x01\x00\x01\x00\x00\x00\x00\x03\x68\x69\x70\x07\x65\x78\x61\
+    \x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\x37\x00\x01\xc0\x0c\x00\x37\x00\x01\x00\
+    \x00\x0e\x10\x00\x00";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn rrsig_example_org_response() {
+    let msg = b"\x5a\x6b\x85\x80\x00\x01\x00\x02\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\
+    \x04\x0a\x00\x00\x0a\xc0\x0c\x00\x2e\x00\x01\x00\x00\x0e\x10\x00\x5f\x00\x01\x0d\
+    \x02\x00\x00\x0e\x10\x65\x7b\x7e\x00\x65\x53\xf1\x00\x30\x39\x07\x65\x78\x61\x6d\
+    \x70\x6c\x65\x03\x6f\x72\x67\x00\x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1a\x1b\
+    \x1c\x1d\x1e\x1f\x20\x21\x22\x23\x24\x25\x26\x27\x28\x29\x2a\x2b\x2c\x2d\x2e\x2f\
+    \x30\x31\x32\x33\x34\x35\x36\x37\x38\x39\x3a\x3b\x3c\x3d\x3e\x3f\x40\x41\x42\x43\
+    \x44\x45\x46\x47\x48\x49\x4a\x4b\x4c\x4d\x4e\x4f";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn rrsig_rsa_sha512_example_org_response() {
+    let msg = b"\x5a\x6b\x85\x80\x00\x01\x00\x02\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\
+    \x04\x0a\x00\x00\x0a\xc0\x0c\x00\x2e\x00\x01\x00\x00\x0e\x10\x00\x5f\x00\x01\x0a\
+    \x02\x00\x00\x0e\x10\x65\x7b\x7e\x00\x65\x53\xf1\x00\x30\x39\x07\x65\x78\x61\x6d\
+    \x70\x6c\x65\x03\x6f\x72\x67\x00\x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1a\x1b\
+    \x1c\x1d\x1e\x1f\x20\x21\x22\x23\x24\x25\x26\x27\x28\x29\x2a\x2b\x2c\x2d\x2e\x2f\
+    \x30\x31\x32\x33\x34\x35\x36\x37\x38\x39\x3a\x3b\x3c\x3d\x3e\x3f\x40\x41\x42\x43\
+    \x44\x45\x46\x47\x48\x49\x4a\x4b\x4c\x4d\x4e\x4f";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn rrsig_unknown_example_org_response() {
+    let msg = b"\x5a\x6b\x85\x80\x00\x01\x00\x02\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\
+    \x04\x0a\x00\x00\x0a\xc0\x0c\x00\x2e\x00\x01\x00\x00\x0e\x10\x00\x5f\xff\x00\x0d\
+    \x02\x00\x00\x0e\x10\x65\x7b\x7e\x00\x65\x53\xf1\x00\x30\x39\x07\x65\x78\x61\x6d\
+    \x70\x6c\x65\x03\x6f\x72\x67\x00\x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1a\x1b\
+    \x1c\x1d\x1e\x1f\x20\x21\x22\x23\x24\x25\x26\x27\x28\x29\x2a\x2b\x2c\x2d\x2e\x2f\
+    \x30\x31\x32\x33\x34\x35\x36\x37\x38\x39\x3a\x3b\x3c\x3d\x3e\x3f\x40\x41\x42\x43\
+    \x44\x45\x46\x47\x48\x49\x4a\x4b\x4c\x4d\x4e\x4f";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn nsec_example_org_response() {
+    let msg = b"\x1a\x2b\x81\x83\x00\x01\x00\x00\x00\x01\x00\x00\x01\x61\x07\x65\x78\x61\x6d\x70\
+    \x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0e\x00\x2f\x00\x01\x00\x00\x0e\
+    \x10\x00\x1e\x04\x68\x6f\x73\x74\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\
+    \x00\x00\x07\x62\x01\x00\x00\x00\x03\x80\x01\x01\x40";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn nsec_private_type_example_org_response() {
+    let msg = b"\x1a\x2b\x81\x83\x00\x01\x00\x00\x00\x01\x00\x00\x01\x61\x07\x65\x78\x61\x6d\x70\
+    \x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0e\x00\x2f\x00\x01\x00\x00\x0e\x10\
+    \x00\x37\x04\x68\x6f\x73\x74\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\
+    \x01\x40\xff\x20\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+    \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn nsec3_example_response() {
+    let msg = b"\x3c\x4d\x85\x80\x00\x01\x00\x01\x00\x01\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x00\x00\x33\x00\x01\xc0\x0c\x00\x33\x00\x01\x00\x00\x0e\x10\x00\x09\x01\x00\x00\
+    \x0c\x04\xaa\xbb\xcc\xdd\x20\x30\x70\x39\x6d\x68\x61\x76\x65\x71\x76\x6d\x36\x74\
+    \x37\x76\x62\x6c\x35\x6c\x6f\x70\x32\x75\x33\x74\x32\x72\x70\x33\x74\x6f\x6d\x07\
+    \x65\x78\x61\x6d\x70\x6c\x65\x00\x00\x32\x00\x01\x00\x00\x0e\x10\x00\x27\x01\x01\
+    \x00\x0c\x04\xaa\xbb\xcc\xdd\x14\x17\x4e\xb2\x40\x9f\xe2\x8b\xcb\x48\x87\xa1\x83\
+    \x6f\x95\x7f\x0a\x84\x25\xe2\x7b\x00\x07\x22\x01\x00\x00\x00\x02\x90";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn nsec3_unknown_hash_algorithm_example_response() {
+    let msg = b"\x3c\x4d\x85\x80\x00\x01\x00\x01\x00\x01\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x00\x00\x33\x00\x01\xc0\x0c\x00\x33\x00\x01\x00\x00\x0e\x10\x00\x09\x01\x00\x00\
+    \x0c\x04\xaa\xbb\xcc\xdd\x20\x30\x70\x39\x6d\x68\x61\x76\x65\x71\x76\x6d\x36\x74\
+    \x37\x76\x62\x6c\x35\x6c\x6f\x70\x32\x75\x33\x74\x32\x72\x70\x33\x74\x6f\x6d\x07\
+    \x65\x78\x61\x6d\x70\x6c\x65\x00\x00\x32\x00\x01\x00\x00\x0e\x10\x00\x27\x02\x01\
+    \x00\x0c\x04\xaa\xbb\xcc\xdd\x14\x17\x4e\xb2\x40\x9f\xe2\x8b\xcb\x48\x87\xa1\x83\
+    \x6f\x95\x7f\x0a\x84\x25\xe2\x7b\x00\x07\x22\x01\x00\x00\x00\x02\x90";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn nsec3_unknown_flags_example_response() {
+    let msg = b"\x3c\x4d\x85\x80\x00\x01\x00\x01\x00\x01\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x00\x00\x33\x00\x01\xc0\x0c\x00\x33\x00\x01\x00\x00\x0e\x10\x00\x09\x01\x00\x00\
+    \x0c\x04\xaa\xbb\xcc\xdd\x20\x30\x70\x39\x6d\x68\x61\x76\x65\x71\x76\x6d\x36\x74\
+    \x37\x76\x62\x6c\x35\x6c\x6f\x70\x32\x75\x33\x74\x32\x72\x70\x33\x74\x6f\x6d\x07\
+    \x65\x78\x61\x6d\x70\x6c\x65\x00\x00\x32\x00\x01\x00\x00\x0e\x10\x00\x27\x01\x03\
+    \x00\x0c\x04\xaa\xbb\xcc\xdd\x14\x17\x4e\xb2\x40\x9f\xe2\x8b\xcb\x48\x87\xa1\x83\
+    \x6f\x95\x7f\x0a\x84\x25\xe2\x7b\x00\x07\x22\x01\x00\x00\x00\x02\x90";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn tlsa_example_org_response() {
+    let msg = b"\x4e\x5f\x85\x80\x00\x01\x00\x01\x00\x00\x00\x00\x03\x5f\x32\x35\x04\x5f\x74\x63\
+    \x70\x04\x6d\x61\x69\x6c\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\
+    \x34\x00\x01\xc0\x0c\x00\x34\x00\x01\x00\x00\x0e\x10\x00\x23\x03\x01\x01\xa0\xa1\
+    \xa2\xa3\xa4\xa5\xa6\xa7\xa8\xa9\xaa\xab\xac\xad\xae\xaf\xb0\xb1\xb2\xb3\xb4\xb5\
+    \xb6\xb7\xb8\xb9\xba\xbb\xbc\xbd\xbe\xbf";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn smimea_example_org_response() {
+    let msg = b"\x4e\x5f\x85\x80\x00\x01\x00\x01\x00\x00\x00\x00\x06\x61\x62\x63\x64\x65\x66\x0a\
+    \x5f\x73\x6d\x69\x6d\x65\x63\x65\x72\x74\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\
+    \x72\x67\x00\x00\x35\x00\x01\xc0\x0c\x00\x35\x00\x01\x00\x00\x0e\x10\x00\x23\x03\
+    \x01\x01\xa0\xa1\xa2\xa3\xa4\xa5\xa6\xa7\xa8\xa9\xaa\xab\xac\xad\xae\xaf\xb0\xb1\
+    \xb2\xb3\xb4\xb5\xb6\xb7\xb8\xb9\xba\xbb\xbc\xbd\xbe\xbf";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn naptr_e164_arpa_response() {
+    let msg = b"\x5f\x60\x85\x80\x00\x01\x00\x02\x00\x00\x00\x00\x01\x34\x01\x33\x01\x32\x01\x31\
+    \x01\x35\x01\x35\x01\x35\x01\x30\x01\x30\x01\x38\x01\x31\x04\x65\x31\x36\x34\x04\
+    \x61\x72\x70\x61\x00\x00\x23\x00\x01\xc0\x0c\x00\x23\x00\x01\x00\x00\x0e\x10\x00\
+    \x33\x00\x64\x00\x0a\x01\x75\x07\x45\x32\x55\x2b\x73\x69\x70\x23\x21\x5e\x2e\x2a\
+    \x24\x21\x73\x69\x70\x3a\x69\x6e\x66\x6f\x72\x6d\x61\x74\x69\x6f\x6e\x40\x65\x78\
+    \x61\x6d\x70\x6c\x65\x2e\x6f\x72\x67\x21\x69\x00\xc0\x0c\x00\x23\x00\x01\x00\x00\
+    \x0e\x10\x00\x38\x00\x66\x00\x0a\x01\x75\x09\x45\x32\x55\x2b\x65\x6d\x61\x69\x6c\
+    \x26\x21\x5e\x2e\x2a\x24\x21\x6d\x61\x69\x6c\x74\x6f\x3a\x69\x6e\x66\x6f\x72\x6d\
+    \x61\x74\x69\x6f\x6e\x40\x65\x78\x61\x6d\x70\x6c\x65\x2e\x6f\x72\x67\x21\x69\x00";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn cds_example_org_response() {
+    let msg = b"\x6a\x7b\x81\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x3b\x00\x01\xc0\x0c\x00\x3b\x00\x01\x00\x00\x0e\x10\x00\
+    \x24\x30\x39\x0d\x02\x20\x21\x22\x23\x24\x25\x26\x27\x28\x29\x2a\x2b\x2c\x2d\x2e\
+    \x2f\x30\x31\x32\x33\x34\x35\x36\x37\x38\x39\x3a\x3b\x3c\x3d\x3e\x3f";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn cds_delete_example_org_response() {
+    let msg = b"\x6a\x7d\x81\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x3b\x00\x01\xc0\x0c\x00\x3b\x00\x01\x00\x00\x0e\x10\x00\
+    \x05\x00\x00\x00\x00\x00";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn cdnskey_delete_example_org_response() {
+    let msg = b"\x6a\x7c\x81\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x3c\x00\x01\xc0\x0c\x00\x3c\x00\x01\x00\x00\x0e\x10\x00\
+    \x05\x00\x00\x03\x00\x00";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn tsig_example_org_request() {
+    let msg = b"\x12\x34\x01\x00\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\x03\x6b\x65\x79\x07\x65\x78\x61\x6d\x70\x6c\
+    \x65\x03\x6f\x72\x67\x00\x00\xfa\x00\xff\x00\x00\x00\x00\x00\x3d\x0b\x68\x6d\x61\
+    \x63\x2d\x73\x68\x61\x32\x35\x36\x00\x00\x00\x65\x53\xf1\x00\x01\x2c\x00\x20\x12\
+    \x4e\x8a\x77\xca\xa7\x10\x32\x69\x9c\xac\x8d\x36\x8f\x6f\xc5\x7e\x7e\xc1\x2e\x55\
+    \x23\x75\x2e\x81\x30\x82\xbc\x59\x4b\x23\x5d\x12\x34\x00\x00\x00\x00";
+    decode_encode_decode(&msg[..]);
+}
+
+#[test]
+fn update_example_org_request() {
+    let msg = b"\x12\x34\x28\x00\x00\x01\x00\x04\x00\x05\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\x03\x77\x77\x77\x07\x65\x78\x61\x6d\x70\x6c\
+    \x65\x03\x6f\x72\x67\x00\x00\xff\x00\xff\x00\x00\x00\x00\x00\x00\x04\x6d\x61\x69\
+    \x6c\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\xfe\x00\x00\
+    \x00\x00\x00\x00\x03\x77\x77\x77\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\
+    \x00\x00\x1c\x00\xff\x00\x00\x00\x00\x00\x00\x04\x6d\x61\x69\x6c\x07\x65\x78\x61\
+    \x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\xff\x00\xfe\x00\x00\x00\x00\x00\x00\x03\
+    \x77\x77\x77\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\xff\
+    \x00\x00\x00\x00\x00\x00\x03\x77\x77\x77\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\
+    \x72\x67\x00\x00\x01\x00\xfe\x00\x00\x00\x00\x00\x04\x0a\x00\x00\x01\x03\x77\x77\
+    \x77\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\
+    \x0e\x10\x00\x04\x0a\x00\x00\x02\x04\x6d\x61\x69\x6c\x07\x65\x78\x61\x6d\x70\x6c\
+    \x65\x03\x6f\x72\x67\x00\x00\xff\x00\xff\x00\x00\x00\x00\x00\x00\x04\x6d\x61\x69\
+    \x6c\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\x0f\x00\xfe\x00\x00\
+    \x00\x00\x00\x13\x00\x0a\x03\x77\x77\x77\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\
+    \x72\x67\x00";
+    decode_encode_decode(&msg[..]);
+}
@@ -1,8 +1,9 @@
 use bytes::Bytes;
 use dns_message_parser::{
     question::{QClass, QType, Question},
-    rr::{AddressError, Class, TagError},
-    DecodeError, Dns, DomainName, Flags, Opcode, RCode, MAXIMUM_DNS_PACKET_SIZE,
+    rr::{AddressError, Class, TagError, RR},
+    DecodeError, DecodeErrorContext, Dns, DomainName, Flags, Opcode, RCode, Section,
+    MAXIMUM_DNS_PACKET_SIZE,
 };
 
 fn decode_msg_error(msg: &[u8], e: DecodeError) {
@@ -10,8 +11,8 @@ fn decode_msg_error(msg: &[u8], e: DecodeError) {
     let bytes = Bytes::copy_from_slice(msg);
     // Decode the DNS message
     let dns = Dns::decode(bytes);
-    // Check the result
-    assert_eq!(dns, Err(e))
+    // Check the result without the context of the question or resource record
+    assert_eq!(dns.map_err(DecodeError::into_inner), Err(e))
 }
 
 fn decode_flags_error(msg: &[u8], e: DecodeError) {
@@ -585,6 +586,22 @@ fn opt_padding_example_org_request() {
     decode_msg_error(&msg[..], DecodeError::PaddingZero(240));
 }
 
+#[test]
+fn opt_tcp_keepalive_example_org_request() {
+    let msg = b"\x46\x57\x01\x20\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\x29\x10\x00\x00\x00\x00\x00\x00\x05\x00\
+    \x0b\x00\x01\x00";
+    decode_msg_error(&msg[..], DecodeError::TcpKeepaliveLength(1));
+}
+
+#[test]
+fn opt_expire_example_org_request() {
+    let msg = b"\x46\x59\x00\x00\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\x00\x00\x29\x10\x00\x00\x00\x00\x00\x00\x06\x00\
+    \x09\x00\x02\x00\x00";
+    decode_msg_error(&msg[..], DecodeError::ExpireLength(2));
+}
+
 #[test]
 fn apl_example_org_response() {
     let msg = b"\x75\xc4\x85\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
@@ -644,3 +661,92 @@ fn caa_example_org_response_2() {
     \x00\x0a\x80\x03\x74\x2e\x67\x56\x41\x4c\x55\x45";
     decode_msg_error(&msg[..], DecodeError::TagError(TagError::IllegalChar('.')));
 }
+
+#[test]
+fn nsec_example_org_response_window_order() {
+    let msg = b"\x1a\x2b\x81\x83\x00\x01\x00\x00\x00\x01\x00\x00\x01\x61\x07\x65\x78\x61\x6d\x70\
+    \x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0e\x00\x2f\x00\x01\x00\x00\x0e\
+    \x10\x00\x18\x04\x68\x6f\x73\x74\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\
+    \x00\x01\x01\x40\x00\x01\x40";
+    decode_msg_error(&msg[..], DecodeError::TypeBitMapWindowOrder(1, 0));
+}
+
+#[test]
+fn nsec_example_org_response_bitmap_length() {
+    let msg = b"\x1a\x2b\x81\x83\x00\x01\x00\x00\x00\x01\x00\x00\x01\x61\x07\x65\x78\x61\x6d\x70\
+    \x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0e\x00\x2f\x00\x01\x00\x00\x0e\
+    \x10\x00\x14\x04\x68\x6f\x73\x74\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\
+    \x00\x00\x00";
+    decode_msg_error(&msg[..], DecodeError::TypeBitMapLength(0));
+}
+
+#[test]
+fn tlsa_example_org_response() {
+    let msg = b"\x4e\x5f\x85\x80\x00\x01\x00\x01\x00\x00\x00\x00\x03\x5f\x32\x35\x04\x5f\x74\x63\
+    \x70\x04\x6d\x61\x69\x6c\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\
+    \x34\x00\x01\xc0\x0c\x00\x34\x00\x01\x00\x00\x0e\x10\x00\x23\x04\x01\x01\xa0\xa1\
+    \xa2\xa3\xa4\xa5\xa6\xa7\xa8\xa9\xaa\xab\xac\xad\xae\xaf\xb0\xb1\xb2\xb3\xb4\xb5\
+    \xb6\xb7\xb8\xb9\xba\xbb\xbc\xbd\xbe\xbf";
+    decode_msg_error(&msg[..], DecodeError::TLSACertificateUsage(4));
+}
+
+#[test]
+fn tsig_example_org_request_class() {
+    let msg = b"\x12\x34\x01\x00\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\x03\x6b\x65\x79\x07\x65\x78\x61\x6d\x70\x6c\
+    \x65\x03\x6f\x72\x67\x00\x00\xfa\x00\x01\x00\x00\x00\x00\x00\x3d\x0b\x68\x6d\x61\
+    \x63\x2d\x73\x68\x61\x32\x35\x36\x00\x00\x00\x65\x53\xf1\x00\x01\x2c\x00\x20\x12\
+    \x4e\x8a\x77\xca\xa7\x10\x32\x69\x9c\xac\x8d\x36\x8f\x6f\xc5\x7e\x7e\xc1\x2e\x55\
+    \x23\x75\x2e\x81\x30\x82\xbc\x59\x4b\x23\x5d\x12\x34\x00\x00\x00\x00";
+    decode_msg_error(&msg[..], DecodeError::TSIGClass(1));
+}
+
+#[test]
+fn context_answer() {
+    let msg = b"\x03\x78\x85\x80\x00\x01\x00\x02\x00\x00\x00\x02\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x02\x00\x01\xc0\x0c\x00\x02\x00\x01\x00\x00\x0e\x10\x00\x06\x03\x6e\
+    \x73\x31\xc0\x0c\xc0\x0c\x00\x02\x00\x01\x00\x00\x0e\x10\x00\x07\x03\x6e\x73\x32\xc0\x0c\x00\
+    \xc0\x29\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x7f\x00\x00\x01\xc0\x3b\x00\x01\x00\x01\x00\
+    \x00\x0e\x10\x00\x04\x0a\x00\x00\x01";
+    let error = Dns::decode(Bytes::copy_from_slice(&msg[..])).unwrap_err();
+    let context = DecodeErrorContext {
+        offset: 65,
+        section: Some(Section::Answer),
+        index: Some(1),
+    };
+    assert_eq!(error.context(), Some(&context));
+    assert_eq!(error.inner(), &DecodeError::TooManyBytes(7, 6));
+    assert_eq!(
+        error.to_string(),
+        "Too many bytes to decode: got 7 parsed 6 (answer section index 1 offset 65)"
+    );
+}
+
+#[test]
+fn context_question() {
+    let msg = b"\xdb\x1c\x01\x20\x00\x01\x00\x00\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x12\x34";
+    let error = Dns::decode(Bytes::copy_from_slice(&msg[..])).unwrap_err();
+    let context = DecodeErrorContext {
+        offset: 29,
+        section: Some(Section::Question),
+        index: Some(0),
+    };
+    assert_eq!(
+        error,
+        DecodeError::Context(context, Box::new(DecodeError::QClass(0x1234)))
+    );
+}
+
+#[test]
+fn context_rr() {
+    let msg = b"\x00\x00\x01\x00\x12\x00\x00\x0e\x10\x00\x04\x0a\x00\x00\x0a";
+    let error = RR::decode(Bytes::copy_from_slice(&msg[..])).unwrap_err();
+    let context = DecodeErrorContext {
+        offset: 11,
+        section: None,
+        index: None,
+    };
+    assert_eq!(error.context(), Some(&context));
+    assert_eq!(error.into_inner(), DecodeError::Class(0x12));
+}
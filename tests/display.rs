@@ -3,13 +3,15 @@ use dns_message_parser::{
     rr::{
         edns::{
             Cookie, EDNSOption, ExtendedDNSErrorCodes, ExtendedDNSErrorExtraText,
-            ExtendedDNSErrors, Padding, ECS,
+            ExtendedDNSErrors, Padding, TcpKeepalive, DAU, DHU, ECS, N3U, NSID,
         },
-        APItem, Address, AlgorithmType, Class, DigestType, ISDNAddress, PSDNAddress,
-        SSHFPAlgorithm, SSHFPType, ServiceBinding, ServiceParameter, Tag, A, AAAA, APL, CAA, CNAME,
-        DNAME, DNSKEY, DS, EID, EUI48, EUI64, GPOS, HINFO, ISDN, KX, L32, L64, LP, MB, MD, MF, MG,
-        MINFO, MR, MX, NID, NIMLOC, NS, OPT, PTR, PX, RP, RR, RT, SA, SOA, SRV, SSHFP, TXT, URI,
-        X25,
+        APItem, Address, AlgorithmType, Class, DigestType, ISDNAddress, NSEC3HashAlgorithm,
+        PSDNAddress, SSHFPAlgorithm, SSHFPType, ServiceBinding, ServiceParameter,
+        TLSACertificateUsage, TLSAMatchingType, TLSASelector, Tag, Type, Unknown, A, AAAA, APL,
+        CAA, CNAME, DNAME, DNSKEY, DS, EID, EUI48, EUI64, GPOS, HINFO, ISDN, KX, L32, L64, LOC, LP,
+        MB, MD, MF, MG, MINFO, MR, MX, NAPTR, NID, NIMLOC, NS, NSEC, NSEC3, NSEC3PARAM,
+        NSEC3_OPT_OUT_FLAG, OPT, PTR, PX, RP, RR, RRSIG, RT, SA, SMIMEA, SOA, SRV, SSHFP, TLSA,
+        TSIG, TXT, URI, WKS, X25,
     },
     Dns, Flags, Opcode, RCode,
 };
@@ -195,7 +197,105 @@ fn rr_hinfo() {
         cpu,
         os,
     });
-    check_output(&rr, "example.org. 100 HS HINFO TEST Linux");
+    check_output(&rr, "example.org. 100 HS HINFO \"TEST\" \"Linux\"");
+}
+
+#[test]
+fn rr_wks() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let ipv4_addr = "10.0.0.1".parse().unwrap();
+    let mut bit_map = vec![0; 11];
+    bit_map[3] = 0b0100_0000;
+    bit_map[10] = 0b1000_0000;
+    let wks = WKS {
+        domain_name,
+        ttl: 100,
+        ipv4_addr,
+        protocol: 6,
+        bit_map,
+    };
+
+    // when
+    let rr = RR::WKS(wks);
+
+    // then
+    check_output(&rr, "example.org. 100 IN WKS 10.0.0.1 6 25 80");
+}
+
+#[test]
+fn rr_wks_trailing_zeros() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let ipv4_addr = "10.0.0.1".parse().unwrap();
+    let wks = WKS {
+        domain_name,
+        ttl: 100,
+        ipv4_addr,
+        protocol: 6,
+        bit_map: vec![0b0100_0000, 0],
+    };
+
+    // when
+    let rr = RR::WKS(wks);
+
+    // then
+    check_output(&rr, "example.org. 100 IN WKS \\# 7 0a000001064000");
+}
+
+#[test]
+fn rr_loc() {
+    // given
+    let domain_name = "cambridge-net.kei.com".parse().unwrap();
+    let loc = LOC {
+        domain_name,
+        ttl: 100,
+        class: Class::IN,
+        version: 0,
+        size: 0x33,
+        horiz_pre: 0x16,
+        vert_pre: 0x13,
+        latitube: 2_299_997_648,
+        longitube: 1_891_505_648,
+        altitube: 9_997_600,
+    };
+
+    // when
+    let rr = RR::LOC(loc);
+
+    // then
+    check_output(
+        &rr,
+        "cambridge-net.kei.com. 100 IN LOC 42 21 54.000 N 71 6 18.000 W -24.00m 30.00m \
+        10000.00m 10.00m",
+    );
+}
+
+#[test]
+fn rr_loc_unknown_version() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let loc = LOC {
+        domain_name,
+        ttl: 100,
+        class: Class::IN,
+        version: 1,
+        size: 0x12,
+        horiz_pre: 0x16,
+        vert_pre: 0x13,
+        latitube: 0x8000_0000,
+        longitube: 0x8000_0000,
+        altitube: 10_000_000,
+    };
+
+    // when
+    let rr = RR::LOC(loc);
+
+    // then
+    check_output(
+        &rr,
+        "example.org. 100 IN LOC \\# 16 01121613800000008000000000989680",
+    );
 }
 
 #[test]
@@ -244,7 +344,7 @@ fn rr_txt() {
         class,
         strings,
     });
-    check_output(&rr, "example.org. 100 CH TXT \"Text\\n\"");
+    check_output(&rr, "example.org. 100 CH TXT \"Text\\010\"");
 }
 
 #[test]
@@ -584,7 +684,7 @@ fn rr_uri() {
         weight: 10,
         uri,
     });
-    check_output(&rr, "example.org. 100 IN URI 1 10 https://example.org/");
+    check_output(&rr, "example.org. 100 IN URI 1 10 \"https://example.org/\"");
 }
 
 #[test]
@@ -662,6 +762,165 @@ fn rr_opt_padding() {
     check_output(&rr, ". OPT 1024 0 0 false Padding 6");
 }
 
+#[test]
+fn rr_opt_nsid() {
+    // given
+    let opt = OPT {
+        requestor_payload_size: 1024,
+        dnssec: false,
+        version: 0,
+        extend_rcode: 0,
+        edns_options: vec![EDNSOption::NSID(NSID(b"gpdns-ams".to_vec()))],
+    };
+
+    // when
+    let rr = RR::OPT(opt);
+
+    // then
+    check_output(
+        &rr,
+        ". OPT 1024 0 0 false NSID 67 70 64 6e 73 2d 61 6d 73 (\"gpdns-ams\")",
+    );
+}
+
+#[test]
+fn rr_opt_nsid_not_printable() {
+    // given
+    let data = b"a\"\\\x00\xff".to_vec();
+
+    // when
+    let nsid = NSID(data);
+
+    // then
+    check_output(&nsid, "NSID 61 22 5c 00 ff (\"a\\\"\\\\..\")");
+}
+
+#[test]
+fn rr_opt_nsid_empty() {
+    // given
+    let data = Vec::new();
+
+    // when
+    let nsid = NSID(data);
+
+    // then
+    check_output(&nsid, "NSID (\"\")");
+}
+
+#[test]
+fn rr_opt_tcp_keepalive() {
+    // given
+    let opt = OPT {
+        requestor_payload_size: 1024,
+        dnssec: false,
+        version: 0,
+        extend_rcode: 0,
+        edns_options: vec![
+            EDNSOption::TcpKeepalive(TcpKeepalive(None)),
+            EDNSOption::TcpKeepalive(TcpKeepalive(Some(150))),
+        ],
+    };
+
+    // when
+    let rr = RR::OPT(opt);
+
+    // then
+    check_output(&rr, ". OPT 1024 0 0 false TcpKeepalive TcpKeepalive 150");
+}
+
+#[test]
+fn rr_opt_dau_dhu_n3u() {
+    // given
+    let opt = OPT {
+        requestor_payload_size: 1024,
+        dnssec: true,
+        version: 0,
+        extend_rcode: 0,
+        edns_options: vec![
+            EDNSOption::DAU(DAU(vec![
+                AlgorithmType::RsaSha256 as u8,
+                AlgorithmType::EcDsaP256 as u8,
+                AlgorithmType::Ed25519 as u8,
+                23,
+            ])),
+            EDNSOption::DHU(DHU(vec![DigestType::Sha1 as u8, DigestType::Sha256 as u8])),
+            EDNSOption::N3U(N3U(vec![NSEC3HashAlgorithm::Sha1 as u8])),
+        ],
+    };
+
+    // when
+    let rr = RR::OPT(opt);
+
+    // then
+    check_output(&rr, ". OPT 1024 0 0 true DAU 8 13 15 23 DHU 1 2 N3U 1");
+}
+
+#[test]
+fn rr_opt_expire() {
+    // given
+    let opt = OPT {
+        requestor_payload_size: 1024,
+        dnssec: false,
+        version: 0,
+        extend_rcode: 0,
+        edns_options: vec![EDNSOption::Expire(None), EDNSOption::Expire(Some(2400000))],
+    };
+
+    // when
+    let rr = RR::OPT(opt);
+
+    // then
+    check_output(&rr, ". OPT 1024 0 0 false Expire Expire 2400000");
+}
+
+#[test]
+fn rr_opt_chain() {
+    // given
+    let opt = OPT {
+        requestor_payload_size: 1024,
+        dnssec: true,
+        version: 0,
+        extend_rcode: 0,
+        edns_options: vec![EDNSOption::Chain("example.org".parse().unwrap())],
+    };
+
+    // when
+    let rr = RR::OPT(opt);
+
+    // then
+    check_output(&rr, ". OPT 1024 0 0 true Chain example.org.");
+}
+
+#[test]
+fn rr_opt_unknown() {
+    // given
+    let opt = OPT {
+        requestor_payload_size: 1024,
+        dnssec: false,
+        version: 0,
+        extend_rcode: 0,
+        edns_options: vec![
+            EDNSOption::Unknown {
+                code: 65001,
+                data: vec![0xde, 0xad, 0xbe, 0xef],
+            },
+            EDNSOption::Unknown {
+                code: 65002,
+                data: Vec::new(),
+            },
+        ],
+    };
+
+    // when
+    let rr = RR::OPT(opt);
+
+    // then
+    check_output(
+        &rr,
+        ". OPT 1024 0 0 false OPTION65001 \\# 4 deadbeef OPTION65002 \\# 0",
+    );
+}
+
 #[test]
 fn rr_apl_1() {
     let domain_name = "example.org".parse().unwrap();
@@ -727,7 +986,7 @@ fn rr_dnskey() {
     });
     check_output(
         &rr,
-        "example.org. 1234 IN DNSKEY 257 3 15 de9f0d53f01de1948d954c7baf5b25135419c26b8fc2d196cd5a5ed9fb6b9907",
+        "example.org. 1234 IN DNSKEY 257 3 15 3p8NU/Ad4ZSNlUx7r1slE1QZwmuPwtGWzVpe2ftrmQc=",
     );
 }
 
@@ -757,6 +1016,317 @@ fn rr_ds() {
     );
 }
 
+#[test]
+fn rr_rrsig() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let signer_name = "example.org".parse().unwrap();
+    let signature = (0x10..0x50).collect();
+    let rrsig = RRSIG {
+        domain_name,
+        ttl: 3600,
+        class: Class::IN,
+        type_covered: Type::A as u16,
+        algorithm_type: AlgorithmType::EcDsaP256 as u8,
+        labels: 2,
+        original_ttl: 3600,
+        signature_expiration: 1702592000,
+        signature_inception: 1700000000,
+        key_tag: 12345,
+        signer_name,
+        signature,
+    };
+
+    // when
+    let rr = RR::RRSIG(rrsig);
+
+    // then
+    check_output(
+        &rr,
+        "example.org. 3600 IN RRSIG A 13 2 3600 20231214221320 20231114221320 12345 example.org. \
+        EBESExQVFhcYGRobHB0eHyAhIiMkJSYnKCkqKywtLi8wMTIzNDU2Nzg5Ojs8PT4/QEFCQ0RFRkdISUpLTE1OTw==",
+    );
+}
+
+#[test]
+fn rr_rrsig_unknown_type_covered() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let signer_name = "example.org".parse().unwrap();
+    let rrsig = RRSIG {
+        domain_name,
+        ttl: 3600,
+        class: Class::IN,
+        type_covered: 0xff00,
+        algorithm_type: 250,
+        labels: 2,
+        original_ttl: 3600,
+        signature_expiration: 1702592000,
+        signature_inception: 1700000000,
+        key_tag: 12345,
+        signer_name,
+        signature: vec![0x10, 0x11, 0x12],
+    };
+
+    // when
+    let rr = RR::RRSIG(rrsig);
+
+    // then
+    check_output(
+        &rr,
+        "example.org. 3600 IN RRSIG TYPE65280 250 2 3600 20231214221320 20231114221320 12345 \
+        example.org. EBES",
+    );
+}
+
+#[test]
+fn rr_nsec() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let next_domain_name = "host.example.org".parse().unwrap();
+    let types = [Type::NSEC, Type::A, Type::RRSIG, Type::CAA, Type::MX];
+    let nsec = NSEC {
+        domain_name,
+        ttl: 3600,
+        class: Class::IN,
+        next_domain_name,
+        types: types.iter().copied().collect(),
+    };
+
+    // when
+    let rr = RR::NSEC(nsec);
+
+    // then
+    check_output(
+        &rr,
+        "example.org. 3600 IN NSEC host.example.org. A MX RRSIG NSEC CAA",
+    );
+}
+
+#[test]
+fn rr_nsec_unknown_type() {
+    // given
+    let nsec = NSEC {
+        domain_name: "example.org".parse().unwrap(),
+        ttl: 3600,
+        class: Class::IN,
+        next_domain_name: "host.example.org".parse().unwrap(),
+        types: [Type::A as u16, 65534].iter().copied().collect(),
+    };
+
+    // when
+    let rr = RR::NSEC(nsec);
+
+    // then
+    check_output(
+        &rr,
+        "example.org. 3600 IN NSEC host.example.org. A TYPE65534",
+    );
+}
+
+#[test]
+fn rr_nsec3() {
+    // given
+    let domain_name = "0p9mhaveqvm6t7vbl5lop2u3t2rp3tom.example".parse().unwrap();
+    let types = [
+        Type::MX,
+        Type::DNSKEY,
+        Type::NS,
+        Type::SOA,
+        Type::NSEC3PARAM,
+        Type::RRSIG,
+    ];
+    let nsec3 = NSEC3 {
+        domain_name,
+        ttl: 3600,
+        class: Class::IN,
+        hash_algorithm: NSEC3HashAlgorithm::Sha1 as u8,
+        flags: NSEC3_OPT_OUT_FLAG,
+        iterations: 12,
+        salt: vec![0xaa, 0xbb, 0xcc, 0xdd],
+        next_hashed_owner_name: vec![
+            0x17, 0x4e, 0xb2, 0x40, 0x9f, 0xe2, 0x8b, 0xcb, 0x48, 0x87, 0xa1, 0x83, 0x6f, 0x95,
+            0x7f, 0x0a, 0x84, 0x25, 0xe2, 0x7b,
+        ],
+        types: types.iter().copied().collect(),
+    };
+
+    // when
+    let rr = RR::NSEC3(nsec3);
+
+    // then
+    check_output(
+        &rr,
+        "0p9mhaveqvm6t7vbl5lop2u3t2rp3tom.example. 3600 IN NSEC3 1 1 12 aabbccdd \
+        2T7B4G4VSA5SMI47K61MV5BV1A22BOJR NS SOA MX RRSIG DNSKEY NSEC3PARAM",
+    );
+}
+
+#[test]
+fn rr_nsec3param() {
+    // given
+    let domain_name = "example".parse().unwrap();
+    let nsec3param = NSEC3PARAM {
+        domain_name,
+        ttl: 3600,
+        class: Class::IN,
+        hash_algorithm: NSEC3HashAlgorithm::Sha1 as u8,
+        flags: 0,
+        iterations: 0,
+        salt: Vec::new(),
+    };
+
+    // when
+    let rr = RR::NSEC3PARAM(nsec3param);
+
+    // then
+    check_output(&rr, "example. 3600 IN NSEC3PARAM 1 0 0 -");
+}
+
+#[test]
+fn rr_tlsa() {
+    // given
+    let domain_name = "_25._tcp.mail.example.org".parse().unwrap();
+    let tlsa = TLSA {
+        domain_name,
+        ttl: 3600,
+        class: Class::IN,
+        certificate_usage: TLSACertificateUsage::DaneEe,
+        selector: TLSASelector::Spki,
+        matching_type: TLSAMatchingType::Sha256,
+        certificate_association_data: vec![0xa0, 0xa1, 0xa2, 0xa3],
+    };
+
+    // when
+    let rr = RR::TLSA(tlsa);
+
+    // then
+    check_output(
+        &rr,
+        "_25._tcp.mail.example.org. 3600 IN TLSA 3 1 1 a0a1a2a3",
+    );
+}
+
+#[test]
+fn rr_smimea() {
+    // given
+    let domain_name = "abcdef._smimecert.example.org".parse().unwrap();
+    let smimea = SMIMEA {
+        domain_name,
+        ttl: 3600,
+        class: Class::IN,
+        certificate_usage: TLSACertificateUsage::PkixEe,
+        selector: TLSASelector::Cert,
+        matching_type: TLSAMatchingType::Full,
+        certificate_association_data: vec![0x30, 0x82],
+    };
+
+    // when
+    let rr = RR::SMIMEA(smimea);
+
+    // then
+    check_output(
+        &rr,
+        "abcdef._smimecert.example.org. 3600 IN SMIMEA 1 0 0 3082",
+    );
+}
+
+#[test]
+fn rr_naptr() {
+    // given
+    let domain_name = "4.3.2.1.5.5.5.0.0.8.1.e164.arpa".parse().unwrap();
+    let replacement = Default::default();
+    let naptr = NAPTR {
+        domain_name,
+        ttl: 3600,
+        class: Class::IN,
+        order: 100,
+        preference: 10,
+        flags: "u".to_string(),
+        services: "E2U+sip".to_string(),
+        regexp: "!^\\+(.*)$!sip:\\1@example.org!".to_string(),
+        replacement,
+    };
+
+    // when
+    let rr = RR::NAPTR(naptr);
+
+    // then
+    check_output(
+        &rr,
+        "4.3.2.1.5.5.5.0.0.8.1.e164.arpa. 3600 IN NAPTR 100 10 \"u\" \"E2U+sip\" \
+        \"!^\\\\+(.*)$!sip:\\\\1@example.org!\" .",
+    );
+}
+
+#[test]
+fn rr_cds() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let ds = DS {
+        domain_name,
+        class: Class::IN,
+        ttl: 3600,
+        key_tag: 12345,
+        algorithm_type: AlgorithmType::EcDsaP256,
+        digest_type: DigestType::Sha256,
+        digest: vec![0x20, 0x21, 0x22, 0x23],
+    };
+
+    // when
+    let rr = RR::CDS(ds);
+
+    // then
+    check_output(&rr, "example.org. 3600 IN CDS 12345 13 2 20212223");
+}
+
+#[test]
+fn rr_cdnskey_delete() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let dnskey = DNSKEY {
+        domain_name,
+        class: Class::IN,
+        ttl: 3600,
+        zone_key_flag: false,
+        secure_entry_point_flag: false,
+        algorithm_type: AlgorithmType::Reserved,
+        public_key: vec![0x00],
+    };
+
+    // when
+    let rr = RR::CDNSKEY(dnskey);
+
+    // then
+    check_output(&rr, "example.org. 3600 IN CDNSKEY 0 3 0 AA==");
+}
+
+#[test]
+fn rr_tsig() {
+    // given
+    let domain_name = "key.example.org".parse().unwrap();
+    let algorithm_name = "hmac-sha256".parse().unwrap();
+    let tsig = TSIG {
+        domain_name,
+        algorithm_name,
+        time_signed: 1700000000,
+        fudge: 300,
+        mac: vec![0x12, 0x4e, 0x8a, 0x77],
+        original_id: 0x1234,
+        error: 0,
+        other_data: Vec::new(),
+    };
+
+    // when
+    let rr = RR::TSIG(tsig);
+
+    // then
+    check_output(
+        &rr,
+        "key.example.org. 0 ANY TSIG hmac-sha256. 1700000000 300 4 Ek6Kdw== 4660 0 0",
+    );
+}
+
 #[test]
 fn rr_caa() {
     let domain_name = "caa.example.org".parse().unwrap();
@@ -772,7 +1342,7 @@ fn rr_caa() {
         tag,
         value,
     });
-    check_output(&rr, "caa.example.org. 1234 IN CAA 128 tag 56414c5545");
+    check_output(&rr, "caa.example.org. 1234 IN CAA 128 tag \"VALUE\"");
 }
 
 #[test]
@@ -799,6 +1369,34 @@ fn rr_svcb_alias() {
     );
 }
 
+#[test]
+fn rr_svcb_private_key() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let target_name = "svc.example.org".parse().unwrap();
+    let private = ServiceParameter::PRIVATE {
+        number: 65000,
+        wire_data: b"a\x01\"".to_vec(),
+    };
+    let service_binding = ServiceBinding {
+        name: domain_name,
+        ttl: 100,
+        priority: 1,
+        target_name,
+        parameters: vec![private].into_iter().collect(),
+        https: false,
+    };
+
+    // when
+    let rr = RR::SVCB(service_binding);
+
+    // then
+    check_output(
+        &rr,
+        "example.org. 100 IN SVCB 1 svc.example.org. key65000=\"a\\001\\034\"",
+    );
+}
+
 #[test]
 fn rr_svcb_service() {
     // given
@@ -945,6 +1543,45 @@ fn rr_https_h2_to_tcp() {
     );
 }
 
+#[test]
+fn rr_unknown() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let rdata = b"\x0a\x00\x00\x01".to_vec();
+    let unknown = Unknown {
+        domain_name,
+        ttl: 100,
+        class: Class::IN,
+        type_code: 731,
+        rdata,
+    };
+
+    // when
+    let rr = RR::Unknown(unknown);
+
+    // then
+    check_output(&rr, "example.org. 100 IN TYPE731 \\# 4 0a000001");
+}
+
+#[test]
+fn rr_unknown_known_type() {
+    // given
+    let domain_name = "example.org".parse().unwrap();
+    let unknown = Unknown {
+        domain_name,
+        ttl: 100,
+        class: Class::CH,
+        type_code: 55,
+        rdata: Vec::new(),
+    };
+
+    // when
+    let rr = RR::Unknown(unknown);
+
+    // then
+    check_output(&rr, "example.org. 100 CH HIP \\# 0");
+}
+
 #[test]
 fn q_type_type() {
     let q_type = QType::A;
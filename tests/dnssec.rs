@@ -0,0 +1,374 @@
+use dns_message_parser::dnssec::{verify_rrsig, DNSSECError};
+use dns_message_parser::rr::{AlgorithmType, DigestType, DNSKEY, DS, RR, RRSIG};
+
+fn dnskey(s: &str) -> DNSKEY {
+    match s.parse().unwrap() {
+        RR::DNSKEY(dnskey) => dnskey,
+        rr => panic!("Expected DNSKEY: {}", rr),
+    }
+}
+
+fn ds(s: &str) -> DS {
+    match s.parse().unwrap() {
+        RR::DS(ds) => ds,
+        rr => panic!("Expected DS: {}", rr),
+    }
+}
+
+/// The example of RFC 4034 Section 5.4.
+fn dskey_example_com() -> DNSKEY {
+    dnskey(
+        "dskey.example.com. 86400 IN DNSKEY 256 3 5 \
+        AQOeiiR0GOMYkDshWoSKz9XzfwJr1AYtsmx3TGkJaNXVbfi/2pHm822aJ5iI9BMzNXxeYCmZDRD99WYwYqUSdjMm\
+        mAphXdvxegXd/M5+X7OrzKBaMbCVdFLUUh6DhweJBjEVv5f2wwjM9XzcnOf+EPbtG9DMBmADjFDc2w/rljwvFw==",
+    )
+}
+
+/// The P-256 example of RFC 6605 Section 6.1.
+fn example_net_p256() -> DNSKEY {
+    dnskey(
+        "example.net. 3600 IN DNSKEY 257 3 13 \
+        GojIhhXUN/u4v54ZQqGSnyhWJwaubCvTmeexv7bR6edbkrSqQpF64cYbcB7wNcP+e+MAnLr+Wi9xMWyQLc8NAA==",
+    )
+}
+
+/// The P-384 example of RFC 6605 Section 6.2.
+fn example_net_p384() -> DNSKEY {
+    dnskey(
+        "example.net. 3600 IN DNSKEY 257 3 14 \
+        xKYaNhWdGOfJ+nPrL8/arkwf2EY3MDJ+SErKivBVSum1w/egsXvSADtNJhyem5RCOpgQ6K8X1DRSEkrbYQ+OB+v8\
+        /uX45NBwY8rp65F6Glur8I/mlVNgF6W/qTI37m40",
+    )
+}
+
+#[test]
+fn to_ds_sha1() {
+    let ds_expected =
+        ds("dskey.example.com. 86400 IN DS 60485 5 1 2BB183AF5F22588179A53B0A98631FAD1A292118");
+    assert_eq!(dskey_example_com().to_ds(DigestType::Sha1), Ok(ds_expected));
+}
+
+#[test]
+fn to_ds_sha256() {
+    let ds_expected = ds("dskey.example.com. 86400 IN DS 60485 5 2 \
+        D4B7D520E7BB5F0F67674A0CCEB1E3E0614B93C4F9E99B8383F6A1E4469DA50A");
+    assert_eq!(
+        dskey_example_com().to_ds(DigestType::Sha256),
+        Ok(ds_expected)
+    );
+
+    let ds_expected = ds("example.net. 3600 IN DS 55648 13 2 \
+        b4c8c1fe2e7477127b27115656ad6256f424625bf5c1e2770ce6d6e37df61d17");
+    assert_eq!(
+        example_net_p256().to_ds(DigestType::Sha256),
+        Ok(ds_expected)
+    );
+}
+
+#[test]
+fn to_ds_sha384() {
+    let ds_expected = ds("example.net. 3600 IN DS 10771 14 4 \
+        72d7b62976ce06438e9c0bf319013cf801f09ecc84b8d7e9495f27e305c6a9b0563a9b5f4d288405c3008a946df983d6");
+    assert_eq!(
+        example_net_p384().to_ds(DigestType::Sha384),
+        Ok(ds_expected)
+    );
+}
+
+#[test]
+fn to_ds_case_insensitive() {
+    let dnskey_1 = dskey_example_com();
+    let mut dnskey_2 = dnskey_1.clone();
+    dnskey_2.domain_name = "DSKEY.Example.COM".parse().unwrap();
+    assert_eq!(
+        dnskey_1.to_ds(DigestType::Sha256).unwrap().digest,
+        dnskey_2.to_ds(DigestType::Sha256).unwrap().digest
+    );
+}
+
+#[test]
+fn to_ds_unsupported() {
+    assert_eq!(
+        dskey_example_com().to_ds(DigestType::GostR),
+        Err(DNSSECError::DigestType(DigestType::GostR))
+    );
+}
+
+fn rr(s: &str) -> RR {
+    s.parse().unwrap()
+}
+
+fn rrsig(s: &str) -> RRSIG {
+    match s.parse().unwrap() {
+        RR::RRSIG(rrsig) => rrsig,
+        rr => panic!("Expected RRSIG: {}", rr),
+    }
+}
+
+/// 2010-08-20T00:00:00Z, which is in the validity period of the RFC 6605 examples.
+const NOW_RFC_6605: u64 = 1_282_262_400;
+
+/// 2026-06-01T00:00:00Z
+const NOW: u64 = 1_780_272_000;
+
+/// The P-256 example of RFC 6605 Section 6.1.
+fn www_example_net_p256() -> (Vec<RR>, RRSIG) {
+    let rrset = vec![rr("www.example.net. 3600 IN A 192.0.2.1")];
+    let rrsig = rrsig(
+        "www.example.net. 3600 IN RRSIG A 13 3 3600 20100909100439 20100812100439 55648 \
+        example.net. \
+        qx6wLYqmh+l9oCKTN6qIc+bw6ya+KJ8oMz0YP107epXAyGmt+3SNruPFKG7tZoLBLlUzGGus7ZwmwWep666VCw==",
+    );
+    (rrset, rrsig)
+}
+
+/// An ECDSA P-384 signature over an RRset, whose RDATA is not in the canonical form.
+fn example_com_p384() -> (DNSKEY, Vec<RR>, RRSIG) {
+    let dnskey = dnskey(
+        "example.com. 3600 IN DNSKEY 257 3 14 \
+        Ro2/dF5vyWseNk9R7biyD28sgnBPKWmwatLXMB/RmhiWL89MF85xQlpLCHy3B73PEHnORBv6Os3pmu8ZmK6O5cvf\
+        fNp0fLzJjxxnni8wdjW/EXwWGPp9SJYySWgr6zoC",
+    );
+    let rrset = vec![
+        rr("example.com. 3600 IN NS ns2.example.com."),
+        rr("example.com. 3600 IN NS NS1.Example.COM."),
+    ];
+    let rrsig = rrsig(
+        "example.com. 3600 IN RRSIG NS 14 2 3600 1798761600 1767225600 3758 example.com. \
+        JorQbAYbzWbgIArVspIkr1W2GvkQkFkTrNkCN31rUsCIt6fdVMMr1oayKACGuXZLZ++QF7mhAucbhtYCaQiFXNWg\
+        iPkNsfOei3I2/M6PQXZp2xQlmB8o5pCF138BGUdP",
+    );
+    (dnskey, rrset, rrsig)
+}
+
+/// The example of RFC 8080 Section 6.1.
+fn example_com_ed25519() -> (DNSKEY, Vec<RR>, RRSIG) {
+    let dnskey =
+        dnskey("example.com. 3600 IN DNSKEY 257 3 15 l02Woi0iS8Aa25FQkUd9RMzZHJpBoRQwAQEX1SxZJA4=");
+    let rrset = vec![rr("example.com. 3600 IN MX 10 mail.example.com.")];
+    let rrsig = rrsig(
+        "example.com. 3600 IN RRSIG MX 15 2 3600 1440021600 1438207200 3613 example.com. \
+        oL9krJun7xfBOIWcGHi7mag5/hdZrKWw15jPGrHpjQeRAvTdszaPD+QLs3fx8A4M3e23mRZ9VrbpMngwcrqNAg==",
+    );
+    (dnskey, rrset, rrsig)
+}
+
+/// An RSA/SHA-256 signature over the wildcard `*.example.org.`, which was expanded to
+/// `a.b.Example.ORG.`.
+fn example_org_rsa_sha256() -> (DNSKEY, Vec<RR>, RRSIG) {
+    let dnskey = dnskey(
+        "example.org. 3600 IN DNSKEY 257 3 8 \
+        AwEAAbNUNJRP7xxFokIl1hvxsSinWJTqdG4zLlUdUb6pyJg9uhlfF+QiphhO2YArc9UU3G9Xus/Eb3aM2eHJMn+q\
+        Q/EJLXOQVHEY6wqJtMQQHZ58o428OuXY91HMrwQaCAXaCiz9zn1Dw4LKaK0tC/sJuwySYYPcItME1Iv2cw8CVQXj\
+        lPOPPIb+0uEJrUkJOx8L6yLExbwKTUYZY4UPQIBeuIHdvqMSJV/d2LAtKN0K/s5KrEogk7Y0KSULk00RQ15wjDk2\
+        d0LeSLfasRuNRXHyswYGHv94sDOKgYdZsteXDCW0S56e8vu+B6AMmbVG1xMMGp0cUSqw5Sxm7HO1g/UIcZs=",
+    );
+    let rrset = vec![
+        rr("a.b.Example.ORG. 300 IN A 192.0.2.2"),
+        rr("a.b.Example.ORG. 300 IN A 192.0.2.1"),
+    ];
+    let rrsig = rrsig(
+        "a.b.Example.ORG. 300 IN RRSIG A 8 2 3600 1798761600 1767225600 21184 example.org. \
+        Zc/umoGWv8+Wppapuja6siC6bqPODcjDVCHts3wnusXYuEhQ66C4JrXrFKjgjcQ5fk0QxJQT9U/OHfblTbGDunFk\
+        KZNYD1fXinKu7TNegputyvLDQJqiDl6WsvARLE/YiTdixwS5Hk3AYevxxb0LuPpHoDksLJ+75OhFINkjiG/aAcn5\
+        aziMYKyuvLpIB8y03/0lUqAhKNYmlaaH258VzGEdgOy0tH4Ta+8/jeTRO0WwZESZsDPD4iXolf21CinXqcmzP+Eb\
+        6D24l4YfYPL1kE1UgtyA/zh/bYAAdWtkVGGY3j7iROklTdwnghSE/ngTPk8ogGwFaOkRyzCKYJxMKg==",
+    );
+    (dnskey, rrset, rrsig)
+}
+
+#[test]
+fn verify_rrsig_ecdsa_p256() {
+    let (rrset, rrsig) = www_example_net_p256();
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &example_net_p256(), NOW_RFC_6605),
+        Ok(3600)
+    );
+}
+
+#[test]
+fn verify_rrsig_ecdsa_p384() {
+    let (dnskey, rrset, rrsig) = example_com_p384();
+    assert_eq!(verify_rrsig(&rrset, &rrsig, &dnskey, NOW), Ok(3600));
+}
+
+#[test]
+fn verify_rrsig_ed25519() {
+    let (dnskey, rrset, rrsig) = example_com_ed25519();
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, 1_439_000_000),
+        Ok(3600)
+    );
+}
+
+#[test]
+fn verify_rrsig_rsa_sha256_wildcard() {
+    let (dnskey, rrset, rrsig) = example_org_rsa_sha256();
+    assert_eq!(verify_rrsig(&rrset, &rrsig, &dnskey, NOW), Ok(300));
+}
+
+#[test]
+fn verify_rrsig_wildcard_labels() {
+    let (dnskey, rrset, mut rrsig) = example_org_rsa_sha256();
+    rrsig.labels = 3;
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, NOW),
+        Err(DNSSECError::Signature)
+    );
+    rrsig.labels = 5;
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, NOW),
+        Err(DNSSECError::Labels(5, 4))
+    );
+}
+
+#[test]
+fn verify_rrsig_bad_signature() {
+    let (rrset, mut rrsig) = www_example_net_p256();
+    rrsig.signature[0] ^= 0x01;
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &example_net_p256(), NOW_RFC_6605),
+        Err(DNSSECError::Signature)
+    );
+}
+
+#[test]
+fn verify_rrsig_modified_rrset() {
+    let (_, mut rrsig) = www_example_net_p256();
+    let rrset = vec![rr("www.example.net. 3600 IN A 192.0.2.2")];
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &example_net_p256(), NOW_RFC_6605),
+        Err(DNSSECError::Signature)
+    );
+
+    rrsig.domain_name = "example.net.".parse().unwrap();
+    assert!(matches!(
+        verify_rrsig(&rrset, &rrsig, &example_net_p256(), NOW_RFC_6605),
+        Err(DNSSECError::RRsetCovered(_))
+    ));
+}
+
+#[test]
+fn verify_rrsig_rrset_empty() {
+    let (_, rrsig) = www_example_net_p256();
+    assert_eq!(
+        verify_rrsig(&[], &rrsig, &example_net_p256(), NOW_RFC_6605),
+        Err(DNSSECError::RRsetEmpty)
+    );
+}
+
+#[test]
+fn verify_rrsig_validity_period() {
+    let (rrset, rrsig) = www_example_net_p256();
+    let dnskey = example_net_p256();
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, 1_281_000_000),
+        Err(DNSSECError::SignatureInception(
+            1_281_607_479,
+            1_281_000_000
+        ))
+    );
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, 1_285_000_000),
+        Err(DNSSECError::SignatureExpiration(
+            1_284_026_679,
+            1_285_000_000
+        ))
+    );
+}
+
+#[test]
+fn verify_rrsig_original_ttl() {
+    let (mut rrset, rrsig) = www_example_net_p256();
+    rrset[0] = rr("www.example.net. 300 IN A 192.0.2.1");
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &example_net_p256(), NOW_RFC_6605),
+        Ok(300)
+    );
+
+    rrset[0] = rr("www.example.net. 7200 IN A 192.0.2.1");
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &example_net_p256(), NOW_RFC_6605),
+        Ok(3600)
+    );
+}
+
+#[test]
+fn verify_rrsig_signature_expiration_ttl() {
+    let (rrset, rrsig) = www_example_net_p256();
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &example_net_p256(), 1_284_026_619),
+        Ok(60)
+    );
+}
+
+#[test]
+fn verify_rrsig_dnskey_mismatch() {
+    let (rrset, rrsig) = www_example_net_p256();
+    let (dnskey, _, _) = example_com_p384();
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, NOW_RFC_6605),
+        Err(DNSSECError::SignerName(
+            "example.net.".parse().unwrap(),
+            "example.com.".parse().unwrap()
+        ))
+    );
+
+    let mut dnskey = example_net_p256();
+    dnskey.algorithm_type = AlgorithmType::EcDsaP386;
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, NOW_RFC_6605),
+        Err(DNSSECError::AlgorithmTypeMismatch(
+            AlgorithmType::EcDsaP256 as u8,
+            AlgorithmType::EcDsaP386 as u8
+        ))
+    );
+
+    let mut dnskey = example_net_p256();
+    dnskey.public_key[0] ^= 0x01;
+    let key_tag = dnskey.key_tag();
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, NOW_RFC_6605),
+        Err(DNSSECError::KeyTag(55648, key_tag))
+    );
+}
+
+#[test]
+fn verify_rrsig_zone_key() {
+    let (rrset, mut rrsig) = www_example_net_p256();
+    let mut dnskey = example_net_p256();
+    dnskey.zone_key_flag = false;
+    rrsig.key_tag = dnskey.key_tag();
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, NOW_RFC_6605),
+        Err(DNSSECError::ZoneKey)
+    );
+}
+
+#[test]
+fn verify_rrsig_signer_zone() {
+    let (mut dnskey, rrset, mut rrsig) = example_com_ed25519();
+    rrsig.signer_name = "sub.example.com.".parse().unwrap();
+    dnskey.domain_name = rrsig.signer_name.clone();
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, 1_439_000_000),
+        Err(DNSSECError::SignerZone(
+            "example.com.".parse().unwrap(),
+            "sub.example.com.".parse().unwrap()
+        ))
+    );
+}
+
+#[test]
+fn verify_rrsig_unsupported_algorithm() {
+    let dnskey = dskey_example_com();
+    let rrset = vec![rr("dskey.example.com. 3600 IN A 192.0.2.1")];
+    let rrsig = rrsig(
+        "dskey.example.com. 3600 IN RRSIG A 5 3 3600 20100909100439 20100812100439 60485 \
+        dskey.example.com. AQIDBA==",
+    );
+    assert_eq!(
+        verify_rrsig(&rrset, &rrsig, &dnskey, NOW_RFC_6605),
+        Err(DNSSECError::AlgorithmType(AlgorithmType::RsaSha1))
+    );
+}
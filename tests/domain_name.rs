@@ -1,4 +1,7 @@
+use bytes::Bytes;
 use dns_message_parser::{DomainName, DomainNameError, Label, LabelError};
+use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::convert::TryFrom;
 
 #[test]
@@ -51,3 +54,100 @@ fn domain_name_length_error() {
         Err(DomainNameError::DomainNameLength(label.len() * 4 + 4))
     );
 }
+
+#[test]
+fn label_bytes() {
+    let label = Label::try_from(&b"\x00a.b\xff"[..]).unwrap();
+    assert_eq!(label.as_bytes(), b"\x00a.b\xff");
+    assert_eq!(label.to_string(), "\\000a\\.b\\255");
+}
+
+#[test]
+fn label_escape_error() {
+    let result: Result<Label, LabelError> = "a\\25".parse();
+    assert_eq!(result, Err(LabelError::Escape("\\25".to_string())));
+    let result: Result<Label, LabelError> = "a\\256".parse();
+    assert_eq!(result, Err(LabelError::Escape("\\256".to_string())));
+    let result: Result<Label, LabelError> = "a\\".parse();
+    assert_eq!(result, Err(LabelError::Escape("\\".to_string())));
+}
+
+#[test]
+fn label_eq_ascii_case() {
+    let label_1 = Label::try_from(&b"ABC\xc4"[..]).unwrap();
+    let label_2 = Label::try_from(&b"abc\xc4"[..]).unwrap();
+    let label_3 = Label::try_from(&b"abc\xe4"[..]).unwrap();
+    assert_eq!(label_1, label_2);
+    assert_ne!(label_1, label_3);
+
+    let mut labels = HashSet::new();
+    labels.insert(label_1);
+    assert!(labels.contains(&label_2));
+    assert!(!labels.contains(&label_3));
+}
+
+#[test]
+fn domain_name_escape() {
+    let domain_name: DomainName = "a\\.b\\032c\\\\.example.org.".parse().unwrap();
+    assert_eq!(domain_name.to_string(), "a\\.b\\032c\\\\.example.org.");
+    let labels: DomainName = "example.org".parse().unwrap();
+    assert_eq!(domain_name.len(), labels.len() + 7);
+}
+
+#[test]
+fn domain_name_escape_last_dot() {
+    let domain_name: DomainName = "example\\.".parse().unwrap();
+    assert_eq!(domain_name.to_string(), "example\\..");
+}
+
+#[test]
+fn domain_name_root_from_str() {
+    let domain_name: DomainName = ".".parse().unwrap();
+    assert!(domain_name.is_root());
+}
+
+#[test]
+fn domain_name_decode_binary_label() {
+    let bytes = Bytes::copy_from_slice(&b"\x02\xc3\x28\x07example\x03org\x00"[..]);
+    let domain_name = DomainName::decode(bytes.clone()).unwrap();
+    assert_eq!(domain_name.to_string(), "\\195\\(.example.org.");
+    assert_eq!(domain_name.encode().unwrap(), bytes);
+    assert_eq!(domain_name.to_string().parse(), Ok(domain_name));
+}
+
+#[test]
+fn domain_name_canonical_order() {
+    // The example of RFC 4034 Section 6.1.
+    let expected: Vec<DomainName> = [
+        "example",
+        "a.example",
+        "yljkjljk.a.example",
+        "Z.a.example",
+        "zABC.a.EXAMPLE",
+        "z.example",
+        "\\001.z.example",
+        "*.z.example",
+        "\\200.z.example",
+    ]
+    .iter()
+    .map(|domain_name| domain_name.parse().unwrap())
+    .collect();
+    let mut domain_names = expected.clone();
+    domain_names.reverse();
+    domain_names.sort();
+    assert_eq!(domain_names, expected);
+    for domain_names in expected.windows(2) {
+        assert_eq!(
+            domain_names[0].canonical_cmp(&domain_names[1]),
+            Ordering::Less
+        );
+    }
+}
+
+#[test]
+fn domain_name_canonical_order_case() {
+    let domain_name_1: DomainName = "Example.ORG".parse().unwrap();
+    let domain_name_2: DomainName = "example.org".parse().unwrap();
+    assert_eq!(domain_name_1.canonical_cmp(&domain_name_2), Ordering::Equal);
+    assert_eq!(domain_name_1.cmp(&DomainName::default()), Ordering::Greater);
+}
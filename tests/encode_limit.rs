@@ -0,0 +1,167 @@
+use bytes::Bytes;
+use dns_message_parser::question::{QClass, QType, Question};
+use dns_message_parser::rr::{OPT, RR, TSIG};
+use dns_message_parser::{Dns, EncodeError, Flags, Omitted, Opcode, RCode};
+
+fn rr(s: &str) -> RR {
+    s.parse().unwrap()
+}
+
+fn opt() -> RR {
+    RR::OPT(OPT {
+        requestor_payload_size: 1232,
+        extend_rcode: 0,
+        version: 0,
+        dnssec: false,
+        edns_options: Vec::new(),
+    })
+}
+
+fn dns() -> Dns {
+    let flags = Flags {
+        qr: true,
+        opcode: Opcode::Query,
+        aa: true,
+        tc: false,
+        rd: true,
+        ra: true,
+        ad: false,
+        cd: false,
+        rcode: RCode::NoError,
+    };
+    let question = Question {
+        domain_name: "example.org.".parse().unwrap(),
+        q_class: QClass::IN,
+        q_type: QType::A,
+    };
+    Dns {
+        id: 0x1234,
+        flags,
+        questions: vec![question],
+        answers: vec![
+            rr("example.org. 3600 IN A 10.0.0.1"),
+            rr("www.example.org. 3600 IN A 10.0.0.4"),
+            rr("example.org. 3600 IN A 10.0.0.2"),
+            rr("example.org. 3600 IN A 10.0.0.3"),
+        ],
+        authorities: Vec::new(),
+        additionals: vec![opt(), rr("ns.example.org. 3600 IN A 10.0.0.5")],
+    }
+}
+
+fn decode(bytes: &[u8]) -> Dns {
+    Dns::decode(Bytes::copy_from_slice(bytes)).unwrap()
+}
+
+#[test]
+fn encode_with_limit_fits() {
+    let dns = dns();
+    let (bytes, omitted) = dns.encode_with_limit(512).unwrap();
+    assert_eq!(bytes.len(), 127);
+    assert!(omitted.is_empty());
+
+    let dns_decoded = decode(&bytes);
+    assert!(!dns_decoded.flags.tc);
+    assert_eq!(dns_decoded.answers.len(), 4);
+    assert_eq!(dns_decoded.additionals, dns.additionals);
+    // The records keep their order.
+    assert_eq!(dns_decoded.answers, dns.answers);
+}
+
+#[test]
+fn encode_with_limit_answers() {
+    let dns = dns();
+    let (bytes, omitted) = dns.encode_with_limit(100).unwrap();
+    assert_eq!(bytes.len(), 88);
+    assert_eq!(
+        omitted,
+        Omitted {
+            answers: vec![dns.answers[1].clone()],
+            authorities: Vec::new(),
+            additionals: vec![dns.additionals[1].clone()],
+        }
+    );
+
+    let dns_decoded = decode(&bytes);
+    assert!(dns_decoded.flags.tc);
+    assert_eq!(
+        dns_decoded.answers,
+        vec![
+            dns.answers[0].clone(),
+            dns.answers[2].clone(),
+            dns.answers[3].clone()
+        ]
+    );
+    assert_eq!(dns_decoded.additionals, vec![opt()]);
+}
+
+#[test]
+fn encode_with_limit_additionals() {
+    let dns = dns();
+    let (bytes, omitted) = dns.encode_with_limit(120).unwrap();
+    assert_eq!(bytes.len(), 108);
+    assert_eq!(omitted.additionals, vec![dns.additionals[1].clone()]);
+
+    let dns_decoded = decode(&bytes);
+    assert!(!dns_decoded.flags.tc);
+    assert_eq!(dns_decoded.answers.len(), 4);
+    assert_eq!(dns_decoded.additionals, vec![opt()]);
+}
+
+#[test]
+fn encode_with_limit_error() {
+    let dns = dns();
+    assert_eq!(dns.encode_with_limit(30), Err(EncodeError::Limit(40, 30)));
+}
+
+fn dns_rrsig() -> Dns {
+    let mut dns = dns();
+    dns.answers = vec![
+        rr("example.org. 3600 IN A 10.0.0.1"),
+        rr("example.org. 3600 IN RRSIG A 13 2 3600 1798761600 1767225600 12345 example.org. \
+            AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"),
+        rr("example.org. 3600 IN AAAA 2001:db8::1"),
+        rr("example.org. 3600 IN RRSIG AAAA 13 2 3600 1798761600 1767225600 12345 example.org. \
+            AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"),
+    ];
+    dns.additionals = vec![opt()];
+    dns
+}
+
+#[test]
+fn encode_with_limit_rrsig() {
+    let dns = dns_rrsig();
+    let (bytes, omitted) = dns.encode_with_limit(512).unwrap();
+    assert!(omitted.is_empty());
+    assert_eq!(decode(&bytes).answers, dns.answers);
+
+    let (bytes, omitted) = dns.encode_with_limit(200).unwrap();
+    // The RRSIG record is omitted together with the RRset, which it covers.
+    assert_eq!(omitted.answers, dns.answers[2..].to_vec());
+    let dns_decoded = decode(&bytes);
+    assert!(dns_decoded.flags.tc);
+    assert_eq!(dns_decoded.answers, dns.answers[..2].to_vec());
+}
+
+#[test]
+fn encode_with_limit_signed() {
+    let mut dns = dns_rrsig();
+    dns.additionals.push(RR::TSIG(TSIG {
+        domain_name: "key.example.org".parse().unwrap(),
+        algorithm_name: "hmac-sha256".parse().unwrap(),
+        time_signed: 1_700_000_000,
+        fudge: 300,
+        mac: vec![0; 32],
+        original_id: 0x1234,
+        error: 0,
+        other_data: Vec::new(),
+    }));
+    let (bytes, omitted) = dns.encode_with_limit(512).unwrap();
+    assert!(omitted.is_empty());
+    assert_eq!(decode(&bytes).additionals, dns.additionals);
+
+    assert_eq!(
+        dns.encode_with_limit(200),
+        Err(EncodeError::LimitSigned(200))
+    );
+}
@@ -1,6 +1,8 @@
 use dns_message_parser::rr::edns::{Cookie, CookieError, ECS};
-use dns_message_parser::rr::{Address, AddressError};
+use dns_message_parser::rr::{Address, AddressError, Type};
+use dns_message_parser::ParseNameError;
 use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
 
 #[test]
 fn opt_cookie_server_cookie_length() {
@@ -44,3 +46,14 @@ fn opt_ecs_set_address() {
         Err(AddressError::Ipv6Mask(ipv6_addr, 24))
     )
 }
+
+#[test]
+fn type_from_str_unknown_name() {
+    assert_eq!(
+        Type::from_str("FOO"),
+        Err(ParseNameError {
+            enum_name: "Type",
+            name: "FOO".to_string()
+        })
+    )
+}
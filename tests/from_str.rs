@@ -0,0 +1,315 @@
+use dns_message_parser::question::{QClass, QType, Question};
+use dns_message_parser::rr::edns::{
+    Cookie, EDNSOption, ExtendedDNSErrorCodes, ExtendedDNSErrorExtraText, ExtendedDNSErrors,
+    Padding, TcpKeepalive, DAU, DHU, ECS, N3U, NSID,
+};
+use dns_message_parser::rr::{
+    Address, AlgorithmType, Class, DigestType, NSEC3HashAlgorithm, NonEmptyVec, ServiceBinding,
+    ServiceParameter, A, DNSKEY, HINFO, LOC, OPT, RR, RRSIG, TXT, URI, WKS,
+};
+use dns_message_parser::zone::ZoneErrorKind;
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+fn check_from_str<T>(value: &T)
+where
+    T: Display + FromStr + PartialEq + Debug,
+    <T as FromStr>::Err: Debug,
+{
+    let text = value.to_string();
+    let value_parsed: T = text.parse().unwrap();
+    assert_eq!(value, &value_parsed, "{}", text);
+}
+
+fn new_loc(version: u8, size: u8, latitube: u32, longitube: u32) -> LOC {
+    LOC {
+        domain_name: "example.org".parse().unwrap(),
+        ttl: 3600,
+        class: Class::IN,
+        version,
+        size,
+        horiz_pre: 0x16,
+        vert_pre: 0x13,
+        latitube,
+        longitube,
+        altitube: 9_998_000,
+    }
+}
+
+#[test]
+fn a() {
+    let a: A = "example.org. 100 IN A 10.0.0.1".parse().unwrap();
+    assert_eq!(
+        a,
+        A {
+            domain_name: "example.org".parse().unwrap(),
+            ttl: 100,
+            ipv4_addr: "10.0.0.1".parse().unwrap(),
+        }
+    );
+    check_from_str(&a);
+    check_from_str(&RR::A(a));
+}
+
+#[test]
+fn a_multi_line() {
+    let a: A = "example.org. 100 IN A ( ; comment\n 10.0.0.1 )"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        a.ipv4_addr,
+        "10.0.0.1".parse::<std::net::Ipv4Addr>().unwrap()
+    );
+}
+
+#[test]
+fn rr_type_error() {
+    let error = "example.org. 100 IN AAAA ::1".parse::<A>().unwrap_err();
+    assert_eq!(error.kind, ZoneErrorKind::RRType("A"));
+}
+
+#[test]
+fn rr_relative_error() {
+    let error = "example 100 IN A 10.0.0.1".parse::<RR>().unwrap_err();
+    assert_eq!(error.kind, ZoneErrorKind::Origin("example".to_string()));
+}
+
+#[test]
+fn rr_empty_error() {
+    let error = " ; comment".parse::<RR>().unwrap_err();
+    assert_eq!(error.kind, ZoneErrorKind::Missing("entry"));
+}
+
+#[test]
+fn rr_multiple_error() {
+    let error = "example.org. 100 IN A 10.0.0.1\nexample.org. 100 IN A 10.0.0.2"
+        .parse::<RR>()
+        .unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+}
+
+#[test]
+fn txt_escape() {
+    let strings = vec![
+        "Text\n".to_string(),
+        "\"quote\" \\backslash".to_string(),
+        "ünïcödé".to_string(),
+    ];
+    let txt = TXT {
+        domain_name: "example.org".parse().unwrap(),
+        ttl: 100,
+        class: Class::CH,
+        strings: NonEmptyVec::try_from(strings).unwrap(),
+    };
+    check_from_str(&txt);
+}
+
+#[test]
+fn hinfo_space() {
+    let hinfo = HINFO {
+        domain_name: "example.org".parse().unwrap(),
+        ttl: 100,
+        class: Class::IN,
+        cpu: "Intel x86".to_string(),
+        os: String::new(),
+    };
+    check_from_str(&hinfo);
+}
+
+#[test]
+fn uri() {
+    let uri = URI {
+        domain_name: "_http._tcp.example.org".parse().unwrap(),
+        ttl: 100,
+        class: Class::IN,
+        priority: 1,
+        weight: 10,
+        uri: "https://example.org/a b".to_string(),
+    };
+    check_from_str(&uri);
+}
+
+#[test]
+fn wks() {
+    let mut wks = WKS {
+        domain_name: "example.org".parse().unwrap(),
+        ttl: 100,
+        ipv4_addr: "10.0.0.1".parse().unwrap(),
+        protocol: 6,
+        bit_map: vec![0x00, 0x00, 0x00, 0x04],
+    };
+    assert_eq!(wks.to_string(), "example.org. 100 IN WKS 10.0.0.1 6 29");
+    check_from_str(&wks);
+    wks.bit_map = Vec::new();
+    check_from_str(&wks);
+    // Trailing zero bytes need the generic format.
+    wks.bit_map = vec![0x40, 0x00];
+    assert_eq!(
+        wks.to_string(),
+        "example.org. 100 IN WKS \\# 7 0a000001064000"
+    );
+    check_from_str(&wks);
+}
+
+#[test]
+fn loc() {
+    let loc_1 = new_loc(0, 0x12, 2_299_997_648, 1_891_505_648);
+    assert_eq!(
+        loc_1.to_string(),
+        "example.org. 3600 IN LOC 42 21 54.000 N 71 6 18.000 W -20.00m 1.00m 10000.00m 10.00m"
+    );
+    check_from_str(&loc_1);
+    let loc_2 = new_loc(0, 0x12, 1 << 31, (1 << 31) + 648_000_000);
+    check_from_str(&loc_2);
+}
+
+#[test]
+fn loc_generic() {
+    // The version is not zero.
+    check_from_str(&new_loc(1, 0x12, 1 << 31, 1 << 31));
+    // The mantissa is greater than nine.
+    check_from_str(&new_loc(0, 0xa2, 1 << 31, 1 << 31));
+    // The latitude is greater than 90 degrees.
+    check_from_str(&new_loc(0, 0x12, u32::MAX, 1 << 31));
+}
+
+#[test]
+fn dnskey() {
+    let dnskey: DNSKEY = "example.org. 1234 IN DNSKEY 257 3 15 ( 3p8NU/Ad4ZSNlUx7r1slE1QZ\n\
+        wmuPwtGWzVpe2ftrmQc= )"
+        .parse()
+        .unwrap();
+    assert!(dnskey.secure_entry_point_flag);
+    check_from_str(&dnskey);
+}
+
+#[test]
+fn cdnskey() {
+    let rr: RR = "example.org. 3600 IN CDNSKEY 0 3 0 AA==".parse().unwrap();
+    assert!(matches!(rr, RR::CDNSKEY(_)));
+    check_from_str(&rr);
+}
+
+#[test]
+fn rrsig_unknown_type_covered() {
+    let rrsig: RRSIG = "example.org. 3600 IN RRSIG TYPE65280 250 2 3600 20231214221320 \
+        20231114221320 12345 example.org. EBES"
+        .parse()
+        .unwrap();
+    assert_eq!(rrsig.type_covered, 0xff00);
+    assert_eq!(rrsig.algorithm_type, 250);
+    check_from_str(&rrsig);
+}
+
+#[test]
+fn service_binding() {
+    let parameters = vec![
+        ServiceParameter::ALPN {
+            alpn_ids: vec!["f\\oo,bar".to_string(), "h2".to_string()],
+        },
+        ServiceParameter::PRIVATE {
+            number: 667,
+            wire_data: b"hello \"world\"\xd2".to_vec(),
+        },
+        ServiceParameter::PRIVATE {
+            number: 668,
+            wire_data: b"hello".to_vec(),
+        },
+    ];
+    let service_binding = ServiceBinding {
+        name: "example.org".parse().unwrap(),
+        ttl: 300,
+        priority: 16,
+        target_name: "foo.example.org".parse().unwrap(),
+        parameters: parameters.into_iter().collect::<BTreeSet<_>>(),
+        https: true,
+    };
+    check_from_str(&service_binding);
+    check_from_str(&RR::HTTPS(service_binding));
+}
+
+#[test]
+fn opt() {
+    let ecs = ECS::new(24, 0, Address::Ipv4("10.0.0.0".parse().unwrap())).unwrap();
+    let cookie_1 = Cookie::new(*b"\xd5\xa7\xe3\x00\x4d\x79\x05\x1e", None).unwrap();
+    let cookie_2 = Cookie::new(
+        *b"\xd5\xa7\xe3\x00\x4d\x79\x05\x1e",
+        Some(b"\x01\x00\x00\x00\x5f\xe5\xd6\xb1".to_vec()),
+    )
+    .unwrap();
+    let extended_dns_errors = ExtendedDNSErrors {
+        info_code: ExtendedDNSErrorCodes::DNSSECBogus,
+        extra_text: ExtendedDNSErrorExtraText::try_from("signature expired").unwrap(),
+    };
+    let opt = OPT {
+        requestor_payload_size: 1232,
+        extend_rcode: 1,
+        version: 0,
+        dnssec: true,
+        edns_options: vec![
+            EDNSOption::ECS(ecs),
+            EDNSOption::Cookie(cookie_1),
+            EDNSOption::Cookie(cookie_2),
+            EDNSOption::Padding(Padding(16)),
+            EDNSOption::NSID(NSID(b"gpdns-\"ams\"".to_vec())),
+            EDNSOption::NSID(NSID(Vec::new())),
+            EDNSOption::Expire(None),
+            EDNSOption::Expire(Some(2400000)),
+            EDNSOption::Chain("example.org".parse().unwrap()),
+            EDNSOption::Chain(".".parse().unwrap()),
+            EDNSOption::DAU(DAU(vec![
+                AlgorithmType::RsaSha256 as u8,
+                AlgorithmType::Ed25519 as u8,
+                23,
+            ])),
+            EDNSOption::DAU(DAU(Vec::new())),
+            EDNSOption::DHU(DHU(vec![DigestType::Sha256 as u8])),
+            EDNSOption::N3U(N3U(vec![NSEC3HashAlgorithm::Sha1 as u8])),
+            EDNSOption::TcpKeepalive(TcpKeepalive(None)),
+            EDNSOption::TcpKeepalive(TcpKeepalive(Some(150))),
+            EDNSOption::Unknown {
+                code: 65001,
+                data: vec![0xde, 0xad, 0xbe, 0xef],
+            },
+            EDNSOption::Unknown {
+                code: 65002,
+                data: Vec::new(),
+            },
+            EDNSOption::ExtendedDNSErrors(extended_dns_errors),
+        ],
+    };
+    check_from_str(&opt);
+    check_from_str(&RR::OPT(opt));
+}
+
+#[test]
+fn opt_unknown_length_error() {
+    let error = ". OPT 1232 0 0 false OPTION65001 \\# 2 deadbeef"
+        .parse::<RR>()
+        .unwrap_err();
+    assert_eq!(error.kind, ZoneErrorKind::RDataLength(2, 4));
+}
+
+#[test]
+fn opt_domain_name_error() {
+    let error = "example.org. OPT 1232 0 0 false".parse::<RR>().unwrap_err();
+    assert_eq!(
+        error.kind,
+        ZoneErrorKind::OPTDomainName("example.org".parse().unwrap())
+    );
+}
+
+#[test]
+fn question() {
+    let question = Question {
+        domain_name: "example.org".parse().unwrap(),
+        q_class: QClass::ANY,
+        q_type: QType::ALL,
+    };
+    check_from_str(&question);
+    let question: Question = "example.org. CLASS1 TYPE1".parse().unwrap();
+    assert_eq!(question.q_class, QClass::IN);
+    assert_eq!(question.q_type, QType::A);
+}
@@ -99,7 +99,7 @@ fn domain_name_eq() {
 fn label_eq() {
     let label: Label = "Example".parse().unwrap();
     assert_eq!(label, "example");
-    assert_eq!(label.as_ref(), "Example");
+    assert_eq!(label.as_bytes(), b"Example");
     assert_eq!(label, "example");
 }
 
@@ -0,0 +1,163 @@
+use bytes::Bytes;
+use dns_message_parser::rr::RR;
+use dns_message_parser::{DecodeError, DecodeOptions, DecodeWarning, Dns, Opcode, RCode};
+
+const LENIENT: DecodeOptions = DecodeOptions { lenient: true };
+
+fn decode_lenient(msg: &[u8]) -> (Dns, Vec<DecodeWarning>) {
+    let bytes = Bytes::copy_from_slice(msg);
+    Dns::decode_with_options(bytes, LENIENT).unwrap()
+}
+
+#[test]
+fn lenient_strict() {
+    let msg = b"\xdb\x1c\x85\xc0\x00\x00\x00\x00\x00\x00\x00\x00";
+    let bytes = Bytes::copy_from_slice(&msg[..]);
+    let result = Dns::decode_with_options(bytes, DecodeOptions::default());
+    assert_eq!(result, Err(DecodeError::ZNotZeroes(0x40)));
+}
+
+#[test]
+fn lenient_no_warnings() {
+    let msg = b"\xdb\x1c\x85\x80\x00\x01\x00\x01\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x0a\x00\
+    \x00\x0a";
+    let (dns, warnings) = decode_lenient(&msg[..]);
+    assert_eq!(dns, Dns::decode(Bytes::copy_from_slice(&msg[..])).unwrap());
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn lenient_flags() {
+    let msg = b"\xdb\x1c\x9d\xcc\x00\x00\x00\x00\x00\x00\x00\x00";
+    let (dns, warnings) = decode_lenient(&msg[..]);
+    assert_eq!(dns.flags.opcode, Opcode::Unknown(3));
+    assert_eq!(dns.flags.rcode, RCode::Unknown(12));
+    assert_eq!(dns.rcode(), Err(12));
+    assert!(dns.flags.qr);
+    assert!(dns.flags.aa);
+    assert!(dns.flags.ra);
+    let errors: Vec<(usize, DecodeError)> = warnings
+        .into_iter()
+        .map(|warning| (warning.offset, warning.error))
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            (2, DecodeError::Opcode(3)),
+            (2, DecodeError::ZNotZeroes(0x40)),
+            (2, DecodeError::RCode(12)),
+        ]
+    );
+}
+
+#[test]
+fn lenient_flags_encode() {
+    let msg = b"\xdb\x1c\x9d\xcc\x00\x00\x00\x00\x00\x00\x00\x00";
+    let (dns, _) = decode_lenient(&msg[..]);
+    assert_eq!(dns.flags.to_string(), "qr OPCODE3 aa rd ra RCODE12");
+    // The unknown opcode and RCODE are kept, but the Z bit is not.
+    assert_eq!(
+        dns.encode().unwrap().as_ref(),
+        b"\xdb\x1c\x9d\x8c\x00\x00\x00\x00\x00\x00\x00\x00"
+    );
+}
+
+#[test]
+fn lenient_unknown_class() {
+    let msg = b"\xdb\x1c\x85\x80\x00\x01\x00\x02\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x12\x34\x00\x00\x0e\x10\x00\x04\x0a\x00\
+    \x00\x0b\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x0a\x00\x00\x0a";
+    let (dns, warnings) = decode_lenient(&msg[..]);
+    assert_eq!(dns.questions.len(), 1);
+    assert_eq!(dns.answers.len(), 1);
+    assert_eq!(
+        dns.answers[0].to_string(),
+        "example.org. 3600 IN A 10.0.0.10"
+    );
+    assert_eq!(
+        warnings,
+        vec![DecodeWarning {
+            offset: 29,
+            error: DecodeError::Class(0x1234),
+            skipped: Some(Bytes::copy_from_slice(&msg[29..45])),
+        }]
+    );
+}
+
+#[test]
+fn lenient_unknown_question_class() {
+    let msg = b"\xdb\x1c\x85\x80\x00\x01\x00\x00\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x12\x34";
+    let (dns, warnings) = decode_lenient(&msg[..]);
+    assert!(dns.questions.is_empty());
+    assert_eq!(
+        warnings,
+        vec![DecodeWarning {
+            offset: 12,
+            error: DecodeError::QClass(0x1234),
+            skipped: Some(Bytes::copy_from_slice(&msg[12..])),
+        }]
+    );
+}
+
+#[test]
+fn lenient_opt_reserved_bits() {
+    let msg = b"\xdb\x1c\x01\x00\x00\x00\x00\x00\x00\x00\x00\x01\x00\x00\x29\x10\x00\x00\x00\x80\
+    \x01\x00\x00";
+    let (dns, warnings) = decode_lenient(&msg[..]);
+    match &dns.additionals[..] {
+        [RR::OPT(opt)] => {
+            assert!(opt.dnssec);
+            assert_eq!(opt.requestor_payload_size, 4096);
+        }
+        additionals => panic!("Expected OPT record: {:?}", additionals),
+    }
+    assert_eq!(
+        warnings,
+        vec![DecodeWarning {
+            offset: 12,
+            error: DecodeError::OPTZero(0x01),
+            skipped: None,
+        }]
+    );
+}
+
+#[test]
+fn lenient_truncated() {
+    let msg = b"\xdb\x1c\x85\x80\x00\x01\x00\x02\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x01\x00\x01\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x0a\x00\
+    \x00\x0a\xc0\x0c\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x0a";
+    let (dns, warnings) = decode_lenient(&msg[..]);
+    assert_eq!(dns.answers.len(), 1);
+    assert_eq!(
+        warnings,
+        vec![DecodeWarning {
+            offset: 45,
+            error: DecodeError::NotEnoughBytes(58, 61),
+            skipped: Some(Bytes::copy_from_slice(&msg[45..])),
+        }]
+    );
+}
+
+#[test]
+fn lenient_remaining_bytes() {
+    let msg = b"\xdb\x1c\x85\x80\x00\x00\x00\x00\x00\x00\x00\x00\xff\xff";
+    let (_, warnings) = decode_lenient(&msg[..]);
+    assert_eq!(
+        warnings,
+        vec![DecodeWarning {
+            offset: 12,
+            error: DecodeError::TooManyBytes(14, 12),
+            skipped: Some(Bytes::copy_from_slice(&msg[12..])),
+        }]
+    );
+}
+
+#[test]
+fn lenient_header_error() {
+    let msg = b"\xdb\x1c\x85\x80";
+    let bytes = Bytes::copy_from_slice(&msg[..]);
+    let result = Dns::decode_with_options(bytes, LENIENT);
+    assert_eq!(result, Err(DecodeError::NotEnoughBytes(4, 12)));
+}
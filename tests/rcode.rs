@@ -0,0 +1,191 @@
+use dns_message_parser::rr::{OPT, RR, TSIG};
+use dns_message_parser::{Dns, Flags, Opcode, RCode, EDNS_DEFAULT_PAYLOAD_SIZE};
+
+fn flags(rcode: RCode) -> Flags {
+    Flags {
+        qr: true,
+        opcode: Opcode::Query,
+        aa: false,
+        tc: false,
+        rd: true,
+        ra: true,
+        ad: false,
+        cd: false,
+        rcode,
+    }
+}
+
+fn dns(rcode: RCode, additionals: Vec<RR>) -> Dns {
+    Dns {
+        id: 0x1234,
+        flags: flags(rcode),
+        questions: Vec::new(),
+        answers: Vec::new(),
+        authorities: Vec::new(),
+        additionals,
+    }
+}
+
+fn opt(extend_rcode: u8) -> RR {
+    RR::OPT(OPT {
+        requestor_payload_size: 4096,
+        extend_rcode,
+        version: 0,
+        dnssec: true,
+        edns_options: Vec::new(),
+    })
+}
+
+fn tsig() -> RR {
+    RR::TSIG(TSIG {
+        domain_name: "key.example.org".parse().unwrap(),
+        algorithm_name: "hmac-sha256".parse().unwrap(),
+        time_signed: 1_700_000_000,
+        fudge: 300,
+        mac: vec![0; 32],
+        original_id: 0x1234,
+        error: 0,
+        other_data: Vec::new(),
+    })
+}
+
+fn extend_rcode(dns: &Dns) -> Option<u8> {
+    dns.additionals.iter().find_map(|rr| match rr {
+        RR::OPT(opt) => Some(opt.extend_rcode),
+        _ => None,
+    })
+}
+
+fn encode_decode(dns: &Dns) -> Dns {
+    let bytes = dns.encode().unwrap();
+    Dns::decode(bytes.freeze()).unwrap()
+}
+
+#[test]
+fn rcode_header() {
+    let dns = dns(RCode::NXDomain, Vec::new());
+    assert_eq!(dns.rcode(), Ok(RCode::NXDomain));
+}
+
+#[test]
+fn rcode_extended() {
+    let dns = dns(RCode::YXRRSet, vec![opt(1)]);
+    assert_eq!(dns.rcode(), Ok(RCode::BADCOOKIE));
+}
+
+#[test]
+fn rcode_unknown() {
+    let dns = dns(RCode::NoError, vec![opt(0xff)]);
+    assert_eq!(dns.rcode(), Err(0xff0));
+}
+
+#[test]
+fn set_rcode_create_opt() {
+    let mut dns = dns(RCode::NoError, Vec::new());
+    dns.set_rcode(RCode::BADCOOKIE);
+    assert_eq!(dns.flags.rcode, RCode::YXRRSet);
+    match dns.additionals.as_slice() {
+        [RR::OPT(opt)] => {
+            assert_eq!(opt.extend_rcode, 1);
+            assert_eq!(opt.requestor_payload_size, EDNS_DEFAULT_PAYLOAD_SIZE);
+        }
+        additionals => panic!("Expected one OPT record: {:?}", additionals),
+    }
+
+    let dns = encode_decode(&dns);
+    assert_eq!(dns.flags.rcode, RCode::YXRRSet);
+    assert_eq!(dns.rcode(), Ok(RCode::BADCOOKIE));
+}
+
+#[test]
+fn set_rcode_existing_opt() {
+    let mut dns = dns(RCode::NoError, vec![opt(0)]);
+    dns.set_rcode(RCode::BADVERS);
+    assert_eq!(dns.flags.rcode, RCode::NoError);
+    assert_eq!(dns.additionals, vec![opt(1)]);
+
+    dns.set_rcode(RCode::Refused);
+    assert_eq!(dns.flags.rcode, RCode::Refused);
+    assert_eq!(dns.additionals, vec![opt(0)]);
+    assert_eq!(dns.rcode(), Ok(RCode::Refused));
+}
+
+#[test]
+fn set_rcode_before_tsig() {
+    let mut dns = dns(RCode::NoError, vec![tsig()]);
+    dns.set_rcode(RCode::BADCOOKIE);
+    match dns.additionals.as_slice() {
+        [RR::OPT(opt), RR::TSIG(_)] => assert_eq!(opt.extend_rcode, 1),
+        additionals => panic!("Expected OPT and TSIG records: {:?}", additionals),
+    }
+}
+
+#[test]
+fn set_rcode_without_opt() {
+    let mut dns = dns(RCode::NoError, Vec::new());
+    dns.set_rcode(RCode::ServFail);
+    assert_eq!(dns.flags.rcode, RCode::ServFail);
+    assert!(dns.additionals.is_empty());
+}
+
+#[test]
+fn encode_extended_rcode_in_flags() {
+    let dns = encode_decode(&dns(RCode::BADCOOKIE, Vec::new()));
+    assert_eq!(dns.flags.rcode, RCode::YXRRSet);
+    assert_eq!(extend_rcode(&dns), Some(1));
+    assert_eq!(dns.rcode(), Ok(RCode::BADCOOKIE));
+    assert!(!dns.flags.cd);
+}
+
+#[test]
+fn encode_extended_rcode_in_flags_existing_opt() {
+    let dns = encode_decode(&dns(RCode::BADVERS, vec![opt(0)]));
+    assert_eq!(dns.flags.rcode, RCode::NoError);
+    assert_eq!(dns.additionals, vec![opt(1)]);
+    assert_eq!(dns.rcode(), Ok(RCode::BADVERS));
+}
+
+#[test]
+fn encode_extended_rcode_in_flags_before_tsig() {
+    let dns = encode_decode(&dns(RCode::BADCOOKIE, vec![tsig()]));
+    assert_eq!(dns.rcode(), Ok(RCode::BADCOOKIE));
+    assert!(matches!(
+        dns.additionals.as_slice(),
+        [RR::OPT(_), RR::TSIG(_)]
+    ));
+}
+
+#[test]
+fn encode_extended_rcode_with_limit() {
+    let (bytes, omitted) = dns(RCode::BADCOOKIE, Vec::new())
+        .encode_with_limit(512)
+        .unwrap();
+    assert!(omitted.is_empty());
+    let dns = Dns::decode(bytes.freeze()).unwrap();
+    assert_eq!(dns.rcode(), Ok(RCode::BADCOOKIE));
+}
+
+#[test]
+fn flags_encode_extended_rcode() {
+    let bytes = flags(RCode::BADCOOKIE).encode();
+    assert_eq!(bytes.as_ref(), b"\x81\x87");
+}
+
+#[test]
+fn rcode_unknown_value() {
+    let rcode = RCode::from(12);
+    assert_eq!(rcode, RCode::Unknown(12));
+    assert_eq!(u8::from(rcode), 12);
+    assert_eq!(rcode.to_string(), "RCODE12");
+    assert_eq!("RCODE12".parse(), Ok(rcode));
+    assert_eq!("rcode3".parse(), Ok(RCode::NXDomain));
+}
+
+#[test]
+fn opcode_unknown_value() {
+    let opcode = Opcode::from(3);
+    assert_eq!(opcode, Opcode::Unknown(3));
+    assert_eq!(u8::from(opcode), 3);
+    assert_eq!(opcode.to_string(), "OPCODE3");
+    assert_eq!("OPCODE3".parse(), Ok(opcode));
+}
@@ -0,0 +1,343 @@
+use bytes::Bytes;
+use dns_message_parser::{
+    rr::{RR, TSIG},
+    tsig::{TSIGAlgorithm, TSIGError, TSIGKey},
+    Dns, RCode,
+};
+use ring::hmac::{sign, Key, HMAC_SHA256};
+
+const REQUEST: &[u8] =
+    b"\x12\x34\x01\x00\x00\x01\x00\x00\x00\x00\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01";
+
+const SIGNED_REQUEST: &[u8] =
+    b"\x12\x34\x01\x00\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\x03\x6b\x65\x79\x07\x65\x78\x61\x6d\x70\x6c\
+    \x65\x03\x6f\x72\x67\x00\x00\xfa\x00\xff\x00\x00\x00\x00\x00\x3d\x0b\x68\x6d\x61\
+    \x63\x2d\x73\x68\x61\x32\x35\x36\x00\x00\x00\x65\x53\xf1\x00\x01\x2c\x00\x20\x12\
+    \x4e\x8a\x77\xca\xa7\x10\x32\x69\x9c\xac\x8d\x36\x8f\x6f\xc5\x7e\x7e\xc1\x2e\x55\
+    \x23\x75\x2e\x81\x30\x82\xbc\x59\x4b\x23\x5d\x12\x34\x00\x00\x00\x00";
+
+const REQUEST_MAC: &[u8] = b"\x12\x4e\x8a\x77\xca\xa7\x10\x32\x69\x9c\xac\x8d\x36\x8f\x6f\xc5\
+    \x7e\x7e\xc1\x2e\x55\x23\x75\x2e\x81\x30\x82\xbc\x59\x4b\x23\x5d";
+
+const SIGNED_RESPONSE: &[u8] =
+    b"\x12\x34\x81\x80\x00\x01\x00\x00\x00\x00\x00\x01\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\x03\x6b\x65\x79\x07\x65\x78\x61\x6d\x70\x6c\
+    \x65\x03\x6f\x72\x67\x00\x00\xfa\x00\xff\x00\x00\x00\x00\x00\x3d\x0b\x68\x6d\x61\
+    \x63\x2d\x73\x68\x61\x32\x35\x36\x00\x00\x00\x65\x53\xf1\x00\x01\x2c\x00\x20\xc8\
+    \x35\x20\xca\xd8\xce\x02\x2d\x60\xdf\xe1\xcc\xdc\x14\x32\xce\x7d\x3d\xa5\x87\xaf\
+    \x06\xbc\x7b\x1e\x69\x31\xb9\x2b\xb0\x15\x31\x12\x34\x00\x00\x00\x00";
+
+const TIME_SIGNED: u64 = 1_700_000_000;
+
+fn key() -> TSIGKey {
+    TSIGKey {
+        name: "key.example.org".parse().unwrap(),
+        algorithm: TSIGAlgorithm::HmacSha256,
+        secret: (0..32).collect(),
+    }
+}
+
+#[test]
+fn sign_request() {
+    let mut dns = Dns::decode(Bytes::from_static(REQUEST)).unwrap();
+    let mac = key().sign(&mut dns, TIME_SIGNED, 300, None).unwrap();
+    assert_eq!(mac, REQUEST_MAC);
+    assert_eq!(dns.encode().unwrap(), SIGNED_REQUEST);
+}
+
+#[test]
+fn sign_extended_rcode() {
+    let mut dns = Dns::decode(Bytes::from_static(SIGNED_RESPONSE)).unwrap();
+    dns.additionals.clear();
+    dns.flags.rcode = RCode::BADCOOKIE;
+    key()
+        .sign(&mut dns, TIME_SIGNED, 300, Some(REQUEST_MAC))
+        .unwrap();
+    let msg = dns.encode().unwrap();
+    let dns = key().verify(&msg, TIME_SIGNED, Some(REQUEST_MAC)).unwrap();
+    assert_eq!(dns.rcode(), Ok(RCode::BADCOOKIE));
+
+    let mut dns = Dns::decode(Bytes::from_static(SIGNED_REQUEST)).unwrap();
+    dns.set_rcode(RCode::BADCOOKIE);
+    assert!(matches!(
+        dns.additionals.as_slice(),
+        [RR::OPT(_), RR::TSIG(_)]
+    ));
+}
+
+#[test]
+fn sign_already_signed() {
+    let mut dns = Dns::decode(Bytes::from_static(SIGNED_REQUEST)).unwrap();
+    let result = key().sign(&mut dns, TIME_SIGNED, 300, None);
+    assert_eq!(result, Err(TSIGError::AlreadySigned));
+}
+
+#[test]
+fn verify_request() {
+    let dns = key()
+        .verify(SIGNED_REQUEST, TIME_SIGNED + 300, None)
+        .unwrap();
+    assert_eq!(dns.additionals.len(), 1);
+}
+
+#[test]
+fn verify_response() {
+    let result = key().verify(SIGNED_RESPONSE, TIME_SIGNED, Some(REQUEST_MAC));
+    assert!(result.is_ok());
+}
+
+#[test]
+fn verify_response_without_request_mac() {
+    let result = key().verify(SIGNED_RESPONSE, TIME_SIGNED, None);
+    assert_eq!(result, Err(TSIGError::BadSig));
+}
+
+#[test]
+fn verify_bad_sig() {
+    let mut msg = SIGNED_REQUEST.to_vec();
+    // Change the question type from SOA to NS.
+    msg[26] = 0x02;
+    let result = key().verify(&msg, TIME_SIGNED, None);
+    assert_eq!(result, Err(TSIGError::BadSig));
+}
+
+#[test]
+fn verify_bad_secret() {
+    let mut key = key();
+    key.secret = b"secret".to_vec();
+    let result = key.verify(SIGNED_REQUEST, TIME_SIGNED, None);
+    assert_eq!(result, Err(TSIGError::BadSig));
+}
+
+#[test]
+fn verify_bad_time() {
+    let result = key().verify(SIGNED_REQUEST, TIME_SIGNED - 301, None);
+    assert_eq!(
+        result,
+        Err(TSIGError::BadTime(TIME_SIGNED, 300, TIME_SIGNED - 301))
+    );
+}
+
+#[test]
+fn verify_bad_key() {
+    let mut key = key();
+    key.name = "other.example.org".parse().unwrap();
+    let result = key.verify(SIGNED_REQUEST, TIME_SIGNED, None);
+    assert_eq!(
+        result,
+        Err(TSIGError::BadKey("key.example.org".parse().unwrap()))
+    );
+}
+
+#[test]
+fn verify_bad_algorithm() {
+    let mut key = key();
+    key.algorithm = TSIGAlgorithm::HmacSha512;
+    let result = key.verify(SIGNED_REQUEST, TIME_SIGNED, None);
+    assert_eq!(
+        result,
+        Err(TSIGError::BadAlgorithm("hmac-sha256".parse().unwrap()))
+    );
+}
+
+#[test]
+fn verify_no_tsig() {
+    let result = key().verify(REQUEST, TIME_SIGNED, None);
+    assert_eq!(result, Err(TSIGError::NoTSIG));
+}
+
+#[test]
+fn sign_verify_hmac_sha384() {
+    let mut key = key();
+    key.algorithm = TSIGAlgorithm::HmacSha384;
+    let mut dns = Dns::decode(Bytes::from_static(REQUEST)).unwrap();
+    let mac = key.sign(&mut dns, TIME_SIGNED, 300, None).unwrap();
+    assert_eq!(mac.len(), 48);
+    let msg = dns.encode().unwrap();
+    assert!(key.verify(&msg, TIME_SIGNED, None).is_ok());
+}
+
+#[test]
+fn sign_verify_hmac_sha512_response() {
+    let mut key = key();
+    key.algorithm = TSIGAlgorithm::HmacSha512;
+    let mut dns = Dns::decode(Bytes::from_static(REQUEST)).unwrap();
+    let request_mac = key.sign(&mut dns, TIME_SIGNED, 300, None).unwrap();
+    assert_eq!(request_mac.len(), 64);
+    dns.additionals.clear();
+    dns.flags.qr = true;
+    key.sign(&mut dns, TIME_SIGNED, 300, Some(&request_mac))
+        .unwrap();
+    let msg = dns.encode().unwrap();
+    assert!(key.verify(&msg, TIME_SIGNED, Some(&request_mac)).is_ok());
+}
+
+/// The MAC of the second message of a multi-message response, which covers the MAC of
+/// `SIGNED_RESPONSE`, the response without the TSIG record and the TSIG timers.
+const SECOND_RESPONSE_MAC: &[u8] = b"\xd8\x00\xea\xb6\xbe\x1f\x5e\x00\x97\xd0\x1a\xb9\xde\xfc\
+    \x6e\xba\x42\xa3\xe5\x49\x74\xe8\x41\xa2\xf2\x1f\x72\xfd\x4d\xdf\xda\xf1";
+
+fn response() -> Dns {
+    let mut dns = Dns::decode(Bytes::from_static(REQUEST)).unwrap();
+    dns.flags.qr = true;
+    dns.flags.ra = true;
+    dns
+}
+
+#[test]
+fn stream_sign() {
+    let key = key();
+    let mut stream = key.stream(REQUEST_MAC);
+
+    let mut dns = response();
+    stream.sign(&mut dns, TIME_SIGNED, 300).unwrap();
+    assert_eq!(dns.encode().unwrap(), SIGNED_RESPONSE);
+
+    let mut dns = response();
+    let mac = stream.sign(&mut dns, TIME_SIGNED + 1, 300).unwrap();
+    assert_eq!(mac, SECOND_RESPONSE_MAC);
+    assert_eq!(stream.prior_mac(), SECOND_RESPONSE_MAC);
+}
+
+#[test]
+fn stream_sign_verify() {
+    let key = key();
+    let mut signer = key.stream(REQUEST_MAC);
+    let mut verifier = key.stream(REQUEST_MAC);
+    for time_signed in TIME_SIGNED..TIME_SIGNED + 3 {
+        let mut dns = response();
+        signer.sign(&mut dns, time_signed, 300).unwrap();
+        let msg = dns.encode().unwrap();
+        assert!(verifier.verify(&msg, time_signed).is_ok());
+        assert_eq!(verifier.prior_mac(), signer.prior_mac());
+    }
+}
+
+#[test]
+fn stream_verify_out_of_order() {
+    let key = key();
+    let mut signer = key.stream(REQUEST_MAC);
+    let mut dns = response();
+    signer.sign(&mut dns, TIME_SIGNED, 300).unwrap();
+    let mut dns = response();
+    signer.sign(&mut dns, TIME_SIGNED, 300).unwrap();
+    let msg = dns.encode().unwrap();
+
+    // The second message is only signed with the TSIG timers and the MAC of the first message.
+    let result = key.verify(&msg, TIME_SIGNED, Some(REQUEST_MAC));
+    assert_eq!(result, Err(TSIGError::BadSig));
+    let mut verifier = key.stream(REQUEST_MAC);
+    assert_eq!(verifier.verify(&msg, TIME_SIGNED), Err(TSIGError::BadSig));
+    assert_eq!(verifier.prior_mac(), REQUEST_MAC);
+
+    verifier.verify(SIGNED_RESPONSE, TIME_SIGNED).unwrap();
+    verifier.verify(&msg, TIME_SIGNED).unwrap();
+    // A replayed message does not cover the MAC of the previous message.
+    assert_eq!(verifier.verify(&msg, TIME_SIGNED), Err(TSIGError::BadSig));
+}
+
+/// Sign the message of a multi-message response, which follows the unsigned messages, see
+/// RFC 8945 Section 5.3.1.
+fn sign_after_unsigned(key: &TSIGKey, prior_mac: &[u8], unsigned: &[u8], dns: &mut Dns) {
+    let mut data = (prior_mac.len() as u16).to_be_bytes().to_vec();
+    data.extend_from_slice(prior_mac);
+    data.extend_from_slice(unsigned);
+    data.extend_from_slice(&dns.encode().unwrap());
+    data.extend_from_slice(&TIME_SIGNED.to_be_bytes()[2..]);
+    data.extend_from_slice(&300u16.to_be_bytes());
+    let mac = sign(&Key::new(HMAC_SHA256, &key.secret), &data);
+    let tsig = TSIG {
+        domain_name: key.name.clone(),
+        algorithm_name: key.algorithm.name(),
+        time_signed: TIME_SIGNED,
+        fudge: 300,
+        mac: mac.as_ref().to_vec(),
+        original_id: dns.id,
+        error: 0,
+        other_data: Vec::new(),
+    };
+    dns.additionals.push(RR::TSIG(tsig));
+}
+
+#[test]
+fn stream_verify_unsigned() {
+    let key = key();
+    let mut verifier = key.stream(REQUEST_MAC);
+    verifier.verify(SIGNED_RESPONSE, TIME_SIGNED).unwrap();
+    let prior_mac = verifier.prior_mac().to_vec();
+
+    let unsigned = response().encode().unwrap();
+    verifier.verify(&unsigned, TIME_SIGNED).unwrap();
+    verifier.verify(&unsigned, TIME_SIGNED).unwrap();
+    assert_eq!(verifier.unsigned_count(), 2);
+    assert_eq!(verifier.prior_mac(), prior_mac.as_slice());
+
+    let mut dns = response();
+    let unsigned = [unsigned.as_ref(), unsigned.as_ref()].concat();
+    sign_after_unsigned(&key, &prior_mac, &unsigned, &mut dns);
+    let msg = dns.encode().unwrap();
+    verifier.verify(&msg, TIME_SIGNED).unwrap();
+    assert_eq!(verifier.unsigned_count(), 0);
+    assert_ne!(verifier.prior_mac(), prior_mac.as_slice());
+}
+
+#[test]
+fn stream_verify_unsigned_not_covered() {
+    let key = key();
+    let mut signer = key.stream(REQUEST_MAC);
+    let mut verifier = key.stream(REQUEST_MAC);
+    let mut dns = response();
+    signer.sign(&mut dns, TIME_SIGNED, 300).unwrap();
+    verifier
+        .verify(&dns.encode().unwrap(), TIME_SIGNED)
+        .unwrap();
+
+    let unsigned = response().encode().unwrap();
+    verifier.verify(&unsigned, TIME_SIGNED).unwrap();
+
+    // The signer does not cover the unsigned message.
+    let mut dns = response();
+    signer.sign(&mut dns, TIME_SIGNED, 300).unwrap();
+    let result = verifier.verify(&dns.encode().unwrap(), TIME_SIGNED);
+    assert_eq!(result, Err(TSIGError::BadSig));
+    assert_eq!(verifier.unsigned_count(), 1);
+}
+
+#[test]
+fn stream_verify_first_unsigned() {
+    let key = key();
+    let mut verifier = key.stream(REQUEST_MAC);
+    let unsigned = response().encode().unwrap();
+    assert_eq!(
+        verifier.verify(&unsigned, TIME_SIGNED),
+        Err(TSIGError::NoTSIG)
+    );
+}
+
+#[test]
+fn stream_verify_too_many_unsigned() {
+    let key = key();
+    let mut verifier = key.stream(REQUEST_MAC);
+    verifier.verify(SIGNED_RESPONSE, TIME_SIGNED).unwrap();
+    let unsigned = response().encode().unwrap();
+    for _ in 0..99 {
+        verifier.verify(&unsigned, TIME_SIGNED).unwrap();
+    }
+    assert_eq!(
+        verifier.verify(&unsigned, TIME_SIGNED),
+        Err(TSIGError::TooManyUnsigned(100))
+    );
+    assert_eq!(verifier.unsigned_count(), 99);
+}
+
+#[test]
+fn key_debug_redacts_secret() {
+    let key = key();
+    let secret = format!("{:?}", key.secret);
+    let debug = format!("{:?}", key);
+    assert!(debug.contains("<redacted>"));
+    assert!(!debug.contains(&secret));
+    assert!(!format!("{:?}", key.stream(REQUEST_MAC)).contains(&secret));
+}
@@ -0,0 +1,250 @@
+use bytes::Bytes;
+use dns_message_parser::{
+    question::{QClass, QType, Question},
+    rr::{Class, Type, Unknown, A, MX, RR},
+    update::{Prerequisite, Update, UpdateError, UpdateMessage},
+    Dns, Flags, Opcode, RCode,
+};
+use std::convert::TryFrom;
+
+const UPDATE: &[u8] =
+    b"\x12\x34\x28\x00\x00\x01\x00\x04\x00\x05\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\x03\x77\x77\x77\x07\x65\x78\x61\x6d\x70\x6c\
+    \x65\x03\x6f\x72\x67\x00\x00\xff\x00\xff\x00\x00\x00\x00\x00\x00\x04\x6d\x61\x69\
+    \x6c\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\xfe\x00\x00\
+    \x00\x00\x00\x00\x03\x77\x77\x77\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\
+    \x00\x00\x1c\x00\xff\x00\x00\x00\x00\x00\x00\x04\x6d\x61\x69\x6c\x07\x65\x78\x61\
+    \x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\xff\x00\xfe\x00\x00\x00\x00\x00\x00\x03\
+    \x77\x77\x77\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\xff\
+    \x00\x00\x00\x00\x00\x00\x03\x77\x77\x77\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\
+    \x72\x67\x00\x00\x01\x00\xfe\x00\x00\x00\x00\x00\x04\x0a\x00\x00\x01\x03\x77\x77\
+    \x77\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\x01\x00\x01\x00\x00\
+    \x0e\x10\x00\x04\x0a\x00\x00\x02\x04\x6d\x61\x69\x6c\x07\x65\x78\x61\x6d\x70\x6c\
+    \x65\x03\x6f\x72\x67\x00\x00\xff\x00\xff\x00\x00\x00\x00\x00\x00\x04\x6d\x61\x69\
+    \x6c\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\x0f\x00\xfe\x00\x00\
+    \x00\x00\x00\x13\x00\x0a\x03\x77\x77\x77\x07\x65\x78\x61\x6d\x70\x6c\x65\x03\x6f\
+    \x72\x67\x00";
+
+fn www() -> RR {
+    RR::A(A {
+        domain_name: "www.example.org".parse().unwrap(),
+        ttl: 3600,
+        ipv4_addr: "10.0.0.2".parse().unwrap(),
+    })
+}
+
+fn update_message() -> UpdateMessage {
+    let www_example_org = "www.example.org".parse().unwrap();
+    let mail_example_org = "mail.example.org".parse().unwrap();
+    let mut update = UpdateMessage::new(0x1234, "example.org".parse().unwrap(), Class::IN);
+    update.prerequisites = vec![
+        Prerequisite::NameInUse(www_example_org),
+        Prerequisite::RRsetDoesNotExist("mail.example.org".parse().unwrap(), Type::A),
+        Prerequisite::RRsetExists("www.example.org".parse().unwrap(), Type::AAAA),
+        Prerequisite::NameNotInUse("mail.example.org".parse().unwrap()),
+    ];
+    update.updates = vec![
+        Update::DeleteRRset("www.example.org".parse().unwrap(), Type::A),
+        Update::DeleteRR(RR::A(A {
+            domain_name: "www.example.org".parse().unwrap(),
+            ttl: 0,
+            ipv4_addr: "10.0.0.1".parse().unwrap(),
+        })),
+        Update::Add(www()),
+        Update::DeleteAllRRsets(mail_example_org),
+        Update::DeleteRR(RR::MX(MX {
+            domain_name: "mail.example.org".parse().unwrap(),
+            ttl: 0,
+            class: Class::IN,
+            preference: 10,
+            exchange: "www.example.org".parse().unwrap(),
+        })),
+    ];
+    update
+}
+
+fn query() -> Dns {
+    let flags = Flags {
+        qr: false,
+        opcode: Opcode::Query,
+        aa: false,
+        tc: false,
+        rd: true,
+        ra: false,
+        ad: false,
+        cd: false,
+        rcode: RCode::NoError,
+    };
+    Dns {
+        id: 0x1234,
+        flags,
+        questions: vec![Question {
+            domain_name: "example.org".parse().unwrap(),
+            q_class: QClass::IN,
+            q_type: QType::SOA,
+        }],
+        answers: Vec::new(),
+        authorities: Vec::new(),
+        additionals: Vec::new(),
+    }
+}
+
+#[test]
+fn update_decode() {
+    let update = UpdateMessage::decode(Bytes::from_static(UPDATE)).unwrap();
+    assert_eq!(update, update_message());
+}
+
+#[test]
+fn update_encode_decode() {
+    let update = update_message();
+    let bytes = update.encode().unwrap();
+    let update_decoded = UpdateMessage::decode(bytes.freeze()).unwrap();
+    assert_eq!(update, update_decoded);
+}
+
+#[test]
+fn update_encode_dns() {
+    let dns = Dns::try_from(&update_message()).unwrap();
+    assert_eq!(dns.flags.opcode, Opcode::Update);
+    assert_eq!(dns.questions.len(), 1);
+    assert_eq!(dns.questions[0].q_type, QType::SOA);
+    assert_eq!(dns.answers.len(), 4);
+    assert_eq!(
+        dns.authorities[1],
+        RR::Unknown(Unknown {
+            domain_name: "www.example.org".parse().unwrap(),
+            ttl: 0,
+            class: Class::NONE,
+            type_code: 1,
+            rdata: vec![10, 0, 0, 1],
+        })
+    );
+    assert_eq!(dns.authorities[2], www());
+}
+
+#[test]
+fn update_prerequisite_value() {
+    let rr = RR::A(A {
+        domain_name: "www.example.org".parse().unwrap(),
+        ttl: 0,
+        ipv4_addr: "10.0.0.2".parse().unwrap(),
+    });
+    let mut dns = Dns::try_from(&update_message()).unwrap();
+    dns.answers = vec![rr.clone()];
+    let update = UpdateMessage::try_from(dns).unwrap();
+    assert_eq!(
+        update.prerequisites,
+        vec![Prerequisite::RRsetExistsValue(rr)]
+    );
+}
+
+#[test]
+fn update_prerequisite_ttl_error() {
+    let mut dns = Dns::try_from(&update_message()).unwrap();
+    dns.answers = vec![www()];
+    assert_eq!(
+        UpdateMessage::try_from(dns),
+        Err(UpdateError::Prerequisite(Box::new(www())))
+    );
+}
+
+#[test]
+fn update_opcode_error() {
+    assert_eq!(
+        UpdateMessage::try_from(query()),
+        Err(UpdateError::Opcode(Opcode::Query))
+    );
+}
+
+#[test]
+fn update_zone_count_error() {
+    let mut dns = query();
+    dns.flags.opcode = Opcode::Update;
+    dns.questions.clear();
+    assert_eq!(UpdateMessage::try_from(dns), Err(UpdateError::ZoneCount(0)));
+}
+
+#[test]
+fn update_zone_type_error() {
+    let mut dns = query();
+    dns.flags.opcode = Opcode::Update;
+    dns.questions[0].q_type = QType::A;
+    assert_eq!(
+        UpdateMessage::try_from(dns),
+        Err(UpdateError::ZoneType(QType::A))
+    );
+}
+
+#[test]
+fn update_prerequisite_error() {
+    let rr = RR::Unknown(Unknown {
+        domain_name: "www.example.org".parse().unwrap(),
+        ttl: 0,
+        class: Class::ANY,
+        type_code: 1,
+        rdata: vec![10, 0, 0, 1],
+    });
+    let mut dns = query();
+    dns.flags.opcode = Opcode::Update;
+    dns.answers.push(rr.clone());
+    assert_eq!(
+        UpdateMessage::try_from(dns),
+        Err(UpdateError::Prerequisite(Box::new(rr)))
+    );
+}
+
+#[test]
+fn update_update_error() {
+    let rr = RR::Unknown(Unknown {
+        domain_name: "www.example.org".parse().unwrap(),
+        ttl: 0,
+        class: Class::NONE,
+        type_code: 1,
+        rdata: Vec::new(),
+    });
+    let mut dns = query();
+    dns.flags.opcode = Opcode::Update;
+    dns.authorities.push(rr.clone());
+    assert_eq!(
+        UpdateMessage::try_from(dns),
+        Err(UpdateError::Update(Box::new(rr)))
+    );
+}
+
+#[test]
+fn update_decode_delete_rr_compressed() {
+    let msg = b"\x12\x34\x28\x00\x00\x01\x00\x00\x00\x01\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\x04\x6d\x61\x69\x6c\xc0\x0c\x00\x0f\x00\xfe\x00\
+    \x00\x00\x00\x00\x08\x00\x0a\x03\x77\x77\x77\xc0\x0c";
+    let update = UpdateMessage::decode(Bytes::copy_from_slice(&msg[..])).unwrap();
+    assert_eq!(
+        update.updates,
+        vec![Update::DeleteRR(RR::MX(MX {
+            domain_name: "mail.example.org".parse().unwrap(),
+            ttl: 0,
+            class: Class::IN,
+            preference: 10,
+            exchange: "www.example.org".parse().unwrap(),
+        }))]
+    );
+}
+
+#[test]
+fn query_decode_class_none() {
+    // The message of update_decode_delete_rr_compressed with the opcode QUERY.
+    let msg = b"\x12\x34\x00\x00\x00\x01\x00\x00\x00\x01\x00\x00\x07\x65\x78\x61\x6d\x70\x6c\x65\
+    \x03\x6f\x72\x67\x00\x00\x06\x00\x01\x04\x6d\x61\x69\x6c\xc0\x0c\x00\x0f\x00\xfe\x00\
+    \x00\x00\x00\x00\x08\x00\x0a\x03\x77\x77\x77\xc0\x0c";
+    let dns = Dns::decode(Bytes::copy_from_slice(&msg[..])).unwrap();
+    assert_eq!(
+        dns.authorities,
+        vec![RR::MX(MX {
+            domain_name: "mail.example.org".parse().unwrap(),
+            ttl: 0,
+            class: Class::NONE,
+            preference: 10,
+            exchange: "www.example.org".parse().unwrap(),
+        })]
+    );
+}
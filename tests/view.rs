@@ -0,0 +1,92 @@
+use bytes::Bytes;
+use dns_message_parser::view::DnsView;
+use dns_message_parser::{DecodeError, DomainName, LabelError};
+
+const NS_EXAMPLE_ORG_RESPONSE: &[u8] = b"\x03\x78\x85\x80\x00\x01\x00\x02\x00\x00\x00\x02\x07\x65\
+\x78\x61\x6d\x70\x6c\x65\x03\x6f\x72\x67\x00\x00\x02\x00\x01\xc0\x0c\x00\x02\x00\x01\x00\x00\x0e\
+\x10\x00\x06\x03\x6e\x73\x31\xc0\x0c\xc0\x0c\x00\x02\x00\x01\x00\x00\x0e\x10\x00\x06\x03\x6e\x73\
+\x32\xc0\x0c\xc0\x29\x00\x01\x00\x01\x00\x00\x0e\x10\x00\x04\x7f\x00\x00\x01\xc0\x3b\x00\x01\x00\
+\x01\x00\x00\x0e\x10\x00\x04\x0a\x00\x00\x01";
+
+#[test]
+fn view_sections() {
+    let bytes = Bytes::copy_from_slice(NS_EXAMPLE_ORG_RESPONSE);
+    let dns = DnsView::decode(&bytes).unwrap();
+    assert_eq!(dns.id(), 0x0378);
+    assert_eq!(dns.questions().len(), 1);
+    assert_eq!(dns.answers().len(), 2);
+    assert_eq!(dns.authorities().len(), 0);
+    assert_eq!(dns.additionals().len(), 2);
+
+    let question = dns.questions().next().unwrap();
+    assert_eq!(question.q_type_code(), 2);
+    assert_eq!(question.q_class_code(), 1);
+
+    let additional = dns.additionals().nth(1).unwrap();
+    assert_eq!(additional.domain_name().to_string(), "ns2.example.org.");
+    assert_eq!(additional.type_code(), 1);
+    assert_eq!(additional.class_code(), 1);
+    assert_eq!(additional.ttl(), 3600);
+    assert_eq!(additional.rdata(), b"\x0a\x00\x00\x01");
+}
+
+#[test]
+fn view_domain_name() {
+    let bytes = Bytes::copy_from_slice(NS_EXAMPLE_ORG_RESPONSE);
+    let dns = DnsView::decode(&bytes).unwrap();
+    let additional = dns.additionals().next().unwrap();
+    let domain_name = additional.domain_name();
+    let labels: Vec<&[u8]> = domain_name.labels().collect();
+    assert_eq!(labels, vec![&b"ns1"[..], &b"example"[..], &b"org"[..]]);
+    assert!(!domain_name.is_root());
+
+    let expected: DomainName = "NS1.Example.Org.".parse().unwrap();
+    assert!(domain_name == expected);
+    let other: DomainName = "ns1.example.org.example.org.".parse().unwrap();
+    assert!(domain_name != other);
+    assert_eq!(domain_name.to_domain_name(), Ok(expected));
+}
+
+#[test]
+fn view_to_dns() {
+    let bytes = Bytes::copy_from_slice(NS_EXAMPLE_ORG_RESPONSE);
+    let dns_view = DnsView::decode(&bytes).unwrap();
+    let dns = dns_view.to_dns().unwrap();
+    assert_eq!(dns.answers.len(), 2);
+    assert_eq!(dns.additionals.len(), 2);
+}
+
+#[test]
+fn view_not_enough_bytes() {
+    let bytes = Bytes::copy_from_slice(&NS_EXAMPLE_ORG_RESPONSE[..90]);
+    let result = DnsView::decode(&bytes);
+    assert_eq!(result.unwrap_err(), DecodeError::NotEnoughBytes(90, 91));
+}
+
+#[test]
+fn view_too_many_bytes() {
+    let mut msg = NS_EXAMPLE_ORG_RESPONSE.to_vec();
+    msg.push(0);
+    let bytes = Bytes::from(msg);
+    let result = DnsView::decode(&bytes);
+    assert_eq!(result.unwrap_err(), DecodeError::TooManyBytes(98, 97));
+}
+
+#[test]
+fn view_endless_recursion() {
+    let msg = b"\x00\x00\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\xc0\x0c\x00\x01\x00\x01";
+    let bytes = Bytes::copy_from_slice(&msg[..]);
+    let result = DnsView::decode(&bytes);
+    assert_eq!(result.unwrap_err(), DecodeError::MaxRecursion(17));
+}
+
+#[test]
+fn view_label_length() {
+    let msg = b"\x00\x00\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x40\x00\x01\x00\x01";
+    let bytes = Bytes::copy_from_slice(&msg[..]);
+    let result = DnsView::decode(&bytes);
+    assert_eq!(
+        result.unwrap_err(),
+        DecodeError::LabelError(LabelError::Length(64))
+    );
+}
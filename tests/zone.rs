@@ -0,0 +1,299 @@
+use dns_message_parser::rr::{Class, NonEmptyVec, Unknown, A, RR, TXT};
+use dns_message_parser::zone::{ZoneError, ZoneErrorKind, ZoneParser};
+use dns_message_parser::DomainName;
+use std::convert::TryFrom;
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+
+const ZONE: &str = r#"$ORIGIN example.org.
+$TTL 1h
+@       IN  SOA   ns1 hostmaster.example.org. (
+                  2021010101 ; serial
+                  2h         ; refresh
+                  1h         ; retry
+                  2w         ; expire
+                  300 )      ; minimum
+        IN  NS    ns1
+            MX    10 mail
+ns1     300 A     10.0.0.1
+mail    IN 600  A 10.0.0.2
+        AAAA      ::1
+txt     TXT       "hello world" "semi;colon" escaped\"quote \065\066
+generic CLASS1 TYPE65280 \# 3 ( 01
+                               0203 )
+$ORIGIN sub.example.org.
+www     CNAME     @
+"#;
+
+fn parse(zone: &str) -> Result<Vec<RR>, ZoneError> {
+    ZoneParser::new(None).parse(zone)
+}
+
+fn parse_error(zone: &str) -> (usize, usize, ZoneErrorKind) {
+    let error = parse(zone).unwrap_err();
+    (error.line, error.column, error.kind)
+}
+
+#[test]
+fn zone() {
+    let rrs = parse(ZONE).unwrap();
+    let rrs: Vec<String> = rrs.iter().map(|rr| rr.to_string()).collect();
+    assert_eq!(
+        rrs,
+        vec![
+            "example.org. 3600 IN SOA ns1.example.org. hostmaster.example.org. (2021010101 7200 3600 1209600 300)",
+            "example.org. 3600 IN NS ns1.example.org.",
+            "example.org. 3600 IN MX 10 mail.example.org.",
+            "ns1.example.org. 300 IN A 10.0.0.1",
+            "mail.example.org. 600 IN A 10.0.0.2",
+            "mail.example.org. 3600 IN AAAA ::1",
+            "txt.example.org. 3600 IN TXT \"hello world\" \"semi;colon\" \"escaped\\\"quote\" \"AB\"",
+            "generic.example.org. 3600 IN TYPE65280 \\# 3 010203",
+            "www.sub.example.org. 3600 IN CNAME sub.example.org.",
+        ]
+    );
+}
+
+#[test]
+fn zone_struct() {
+    let rrs = parse(ZONE).unwrap();
+    assert_eq!(
+        rrs[4],
+        RR::A(A {
+            domain_name: "mail.example.org".parse().unwrap(),
+            ttl: 600,
+            ipv4_addr: "10.0.0.2".parse().unwrap(),
+        })
+    );
+    let strings = vec![
+        "hello world".to_string(),
+        "semi;colon".to_string(),
+        "escaped\"quote".to_string(),
+        "AB".to_string(),
+    ];
+    assert_eq!(
+        rrs[6],
+        RR::TXT(TXT {
+            domain_name: "txt.example.org".parse().unwrap(),
+            ttl: 3600,
+            class: Class::IN,
+            strings: NonEmptyVec::try_from(strings).unwrap(),
+        })
+    );
+    assert_eq!(
+        rrs[7],
+        RR::Unknown(Unknown {
+            domain_name: "generic.example.org".parse().unwrap(),
+            ttl: 3600,
+            class: Class::IN,
+            type_code: 65280,
+            rdata: vec![1, 2, 3],
+        })
+    );
+}
+
+#[test]
+fn zone_generic_known_type() {
+    let rrs = parse("example.org. 60 IN A \\# 4 0a000001").unwrap();
+    assert_eq!(
+        rrs,
+        vec![RR::A(A {
+            domain_name: "example.org".parse().unwrap(),
+            ttl: 60,
+            ipv4_addr: "10.0.0.1".parse().unwrap(),
+        })]
+    );
+}
+
+#[test]
+fn zone_ttl_inherited() {
+    let rrs = parse("a.example.org. 60 IN A 10.0.0.1\nb.example.org. A 10.0.0.2").unwrap();
+    assert_eq!(rrs[1].to_string(), "b.example.org. 60 IN A 10.0.0.2");
+}
+
+#[test]
+fn zone_class_ttl_order() {
+    let rrs = parse("a.example.org. IN 60 A 10.0.0.1\nb.example.org. 60 IN A 10.0.0.2").unwrap();
+    assert_eq!(rrs.len(), 2);
+}
+
+#[test]
+fn zone_origin() {
+    let origin: DomainName = "example.org".parse().unwrap();
+    let rrs = ZoneParser::new(Some(origin))
+        .parse("www 60 A 10.0.0.1")
+        .unwrap();
+    assert_eq!(rrs[0].to_string(), "www.example.org. 60 IN A 10.0.0.1");
+}
+
+#[test]
+fn zone_include() {
+    let mut resolver = |file_name: &str| match file_name {
+        "sub.zone" => Ok("www A 10.0.0.3\n".to_string()),
+        "error.zone" => Ok("\nwww A 10.0.0.256\n".to_string()),
+        _ => Err(IoError::new(IoErrorKind::NotFound, "not found")),
+    };
+    let zone = "\
+$ORIGIN example.org.
+$TTL 60
+$INCLUDE sub.zone sub.example.org.
+mail A 10.0.0.2
+$INCLUDE \"sub.zone\"
+";
+    let mut parser = ZoneParser::new(None);
+    parser.set_include_resolver(&mut resolver);
+    let rrs: Vec<String> = parser
+        .parse(zone)
+        .unwrap()
+        .iter()
+        .map(|rr| rr.to_string())
+        .collect();
+    assert_eq!(
+        rrs,
+        vec![
+            "www.sub.example.org. 60 IN A 10.0.0.3",
+            "mail.example.org. 60 IN A 10.0.0.2",
+            "www.example.org. 60 IN A 10.0.0.3",
+        ]
+    );
+
+    let mut parser = ZoneParser::new(None);
+    parser.set_include_resolver(&mut resolver);
+    let error = parser
+        .parse("$ORIGIN example.org.\n$TTL 60\n$INCLUDE error.zone")
+        .unwrap_err();
+    assert_eq!(error.file, Some("error.zone".to_string()));
+    assert_eq!((error.line, error.column), (2, 7));
+    assert_eq!(
+        error.to_string(),
+        "error.zone:2:7: Could not parse IPv4 address: 10.0.0.256"
+    );
+
+    let mut parser = ZoneParser::new(None);
+    parser.set_include_resolver(&mut resolver);
+    let error = parser.parse("$INCLUDE missing.zone").unwrap_err();
+    assert_eq!(
+        error.kind,
+        ZoneErrorKind::Include("missing.zone".to_string(), "not found".to_string())
+    );
+}
+
+#[test]
+fn zone_include_recursive() {
+    let mut resolver = |_: &str| Ok("$INCLUDE loop.zone".to_string());
+    let mut parser = ZoneParser::new(None);
+    parser.set_include_resolver(&mut resolver);
+    let error = parser.parse("$INCLUDE loop.zone").unwrap_err();
+    assert_eq!(error.kind, ZoneErrorKind::IncludeDepth(16));
+}
+
+#[test]
+fn zone_include_resolver_error() {
+    assert_eq!(
+        parse_error("$INCLUDE sub.zone"),
+        (1, 1, ZoneErrorKind::IncludeResolver)
+    );
+}
+
+#[test]
+fn zone_directive_error() {
+    assert_eq!(
+        parse_error("$GENERATE 1-2 a A 10.0.0.$"),
+        (1, 1, ZoneErrorKind::Directive("$GENERATE".to_string()))
+    );
+}
+
+#[test]
+fn zone_origin_error() {
+    assert_eq!(
+        parse_error("www 60 A 10.0.0.1"),
+        (1, 1, ZoneErrorKind::Origin("www".to_string()))
+    );
+}
+
+#[test]
+fn zone_owner_error() {
+    assert_eq!(
+        parse_error("\n  60 A 10.0.0.1"),
+        (2, 3, ZoneErrorKind::Owner)
+    );
+}
+
+#[test]
+fn zone_ttl_error() {
+    assert_eq!(
+        parse_error("example.org. A 10.0.0.1"),
+        (1, 1, ZoneErrorKind::TTL)
+    );
+}
+
+#[test]
+fn zone_type_error() {
+    assert_eq!(
+        parse_error("example.org. 60 IN AA 10.0.0.1"),
+        (1, 20, ZoneErrorKind::Type("AA".to_string()))
+    );
+}
+
+#[test]
+fn zone_missing_error() {
+    assert_eq!(
+        parse_error("example.org. 60 IN MX 10"),
+        (1, 25, ZoneErrorKind::Missing("exchange"))
+    );
+}
+
+#[test]
+fn zone_unexpected_error() {
+    assert_eq!(
+        parse_error("example.org. 60 IN A 10.0.0.1 10.0.0.2"),
+        (1, 31, ZoneErrorKind::Unexpected("10.0.0.2".to_string()))
+    );
+}
+
+#[test]
+fn zone_parentheses_error() {
+    assert_eq!(
+        parse_error("example.org. 60 IN A ( 10.0.0.1\n"),
+        (1, 22, ZoneErrorKind::Parentheses)
+    );
+    assert_eq!(
+        parse_error("example.org. 60 IN A 10.0.0.1 )"),
+        (1, 31, ZoneErrorKind::Parentheses)
+    );
+}
+
+#[test]
+fn zone_unterminated_string_error() {
+    assert_eq!(
+        parse_error("example.org. 60 IN TXT \"text\n\""),
+        (1, 24, ZoneErrorKind::UnterminatedString)
+    );
+}
+
+#[test]
+fn zone_escape_error() {
+    assert_eq!(
+        parse_error("example.org. 60 IN TXT a\\256"),
+        (1, 24, ZoneErrorKind::Escape("\\256".to_string()))
+    );
+}
+
+#[test]
+fn zone_txt_utf8_error() {
+    assert_eq!(
+        parse_error("example.org. 60 IN TXT \"a\\255\""),
+        (
+            1,
+            24,
+            ZoneErrorKind::CharacterStringUtf8("a\\255".to_string())
+        )
+    );
+}
+
+#[test]
+fn zone_generic_length_error() {
+    assert_eq!(
+        parse_error("example.org. 60 IN TYPE65280 \\# 2 010203"),
+        (1, 30, ZoneErrorKind::RDataLength(2, 3))
+    );
+}